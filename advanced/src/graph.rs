@@ -1,9 +1,16 @@
+mod binary;
 mod directed_graph;
+pub mod grid;
+pub mod metrics;
+mod operations;
 pub mod processing;
+pub mod tree;
 mod undirected_graph;
 
+pub use binary::{BinaryGraph, BinaryWeight, CsrGraph, GraphKind};
 pub use directed_graph::{DirectedGraph, EdgeWeightedDigraph, FlowEdge, FlowNetwork};
-pub use undirected_graph::UndirectedGraph;
+pub use operations::GraphOperations;
+pub use undirected_graph::{EdgeWeightedGraph, UndirectedGraph};
 
 /// This trait gives some basic information on vertices
 pub trait VertexInfo {
@@ -28,6 +35,13 @@ pub trait Weight:
     fn max() -> Self;
 }
 
+/// This trait converts the weights to floating point numbers, possibly with
+/// a loss of precision, for the algorithms computing with real numbers
+/// (centralities, random walks, communities, ...)
+pub trait ToF64 {
+    fn to_f64(&self) -> f64;
+}
+
 macro_rules! impl_weight {
     ($TYPE:ty) => {
         impl Weight for $TYPE {
@@ -39,6 +53,11 @@ macro_rules! impl_weight {
                 0 as $TYPE
             }
         }
+        impl ToF64 for $TYPE {
+            fn to_f64(&self) -> f64 {
+                *self as f64
+            }
+        }
     };
 }
 
@@ -0,0 +1,486 @@
+#[cfg(test)]
+mod unit_test;
+use crate::graph::{
+    DirectedGraph, EdgeWeightedDigraph, EdgeWeightedGraph, FlowNetwork, UndirectedGraph,
+    VertexInfo, Weight,
+};
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+// first bytes of every file
+const MAGIC: &[u8; 8] = b"ALGODSGR";
+// version of the format, incremented at each incompatible change
+const VERSION: u32 = 1;
+// number of bytes of the header
+const HEADER_SIZE: usize = 32;
+// number of bytes read or written at once
+const CHUNK_SIZE: usize = 1 << 16;
+
+/// Enumeration of the graph types stored in the binary format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphKind {
+    Directed,
+    Undirected,
+    EdgeWeightedDirected,
+    EdgeWeightedUndirected,
+    Flow,
+}
+impl GraphKind {
+    fn code(&self) -> u8 {
+        match self {
+            Self::Directed => 0,
+            Self::Undirected => 1,
+            Self::EdgeWeightedDirected => 2,
+            Self::EdgeWeightedUndirected => 3,
+            Self::Flow => 4,
+        }
+    }
+    fn from_code(code: u8) -> Option<Self> {
+        match code {
+            0 => Some(Self::Directed),
+            1 => Some(Self::Undirected),
+            2 => Some(Self::EdgeWeightedDirected),
+            3 => Some(Self::EdgeWeightedUndirected),
+            4 => Some(Self::Flow),
+            _ => None,
+        }
+    }
+}
+
+/// This trait gives the encoding of the weights in the binary format:
+/// a code identifying the type and the value on 64 bits
+pub trait BinaryWeight: Weight {
+    /// Code of the type, 0 being reserved to unweighted graphs
+    const CODE: u8;
+    fn to_bits(self) -> u64;
+    fn from_bits(bits: u64) -> Self;
+}
+
+macro_rules! impl_binary_weight {
+    ($TYPE:ty, $CODE:expr, $WIDE:ty) => {
+        impl BinaryWeight for $TYPE {
+            const CODE: u8 = $CODE;
+
+            fn to_bits(self) -> u64 {
+                self as $WIDE as u64
+            }
+
+            fn from_bits(bits: u64) -> Self {
+                bits as $WIDE as $TYPE
+            }
+        }
+    };
+}
+
+impl_binary_weight!(u8, 1, u64);
+impl_binary_weight!(u16, 2, u64);
+impl_binary_weight!(u32, 3, u64);
+impl_binary_weight!(u64, 4, u64);
+impl_binary_weight!(usize, 5, u64);
+
+impl_binary_weight!(i8, 6, i64);
+impl_binary_weight!(i16, 7, i64);
+impl_binary_weight!(i32, 8, i64);
+impl_binary_weight!(i64, 9, i64);
+impl_binary_weight!(isize, 10, i64);
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+fn write_u64s<W: Write>(writer: &mut W, values: &[u64]) -> io::Result<()> {
+    let mut buffer = Vec::with_capacity(CHUNK_SIZE);
+    for chunk in values.chunks(CHUNK_SIZE / 8) {
+        buffer.clear();
+        for value in chunk {
+            buffer.extend_from_slice(&value.to_le_bytes());
+        }
+        writer.write_all(&buffer)?;
+    }
+    Ok(())
+}
+
+fn read_u64s<R: Read>(reader: &mut R, nb: usize) -> io::Result<Vec<u64>> {
+    // reads by chunks into a reused byte buffer, so that a corrupted count
+    // fails at the end of the data rather than at the allocation, the values
+    // being decoded in place: u64::from_le_bytes is a plain copy on little
+    // endian targets and a byte swap elsewhere
+    let mut values = Vec::with_capacity(std::cmp::min(nb, CHUNK_SIZE / 8));
+    let mut buffer = vec![0; CHUNK_SIZE];
+    while values.len() < nb {
+        let start = values.len();
+        let size = std::cmp::min(CHUNK_SIZE / 8, nb - start);
+        reader.read_exact(&mut buffer[..8 * size])?;
+        values.resize(start + size, 0);
+        for (value, bytes) in values[start..].iter_mut().zip(buffer.chunks_exact(8)) {
+            *value = u64::from_le_bytes(bytes.try_into().expect("8 bytes"));
+        }
+    }
+    Ok(values)
+}
+
+/// Graph stored in compressed sparse row (CSR) form, as in the binary
+/// format: the adjacent vertices of v are targets\[offsets\[v\]..offsets\[v + 1\]\],
+/// with the weights (and flows) of the edges at the same positions, as raw
+/// 64 bits values. Undirected edges are stored from both end points (once
+/// for self loops), flow networks only with their forward edges.
+///
+/// The file starts with a header of 32 bytes: the magic bytes `ALGODSGR`,
+/// the version (u32), the graph kind (u8), the weight type (u8, 0 when there
+/// is none), two zero bytes, the number of vertices and the number of stored
+/// edges (u64). Then come the offsets, the targets, the weights and the
+/// flows, all of them as little endian u64.
+///
+/// `CsrGraph` is the fast view of a loaded graph: reading it costs one pass
+/// over the bytes, and its neighbors are contiguous slices. The `to_*`
+/// conversions build the adjacency-list graphs with O(E) hash set (or map)
+/// insertions, which is much slower than the reading itself, so they are
+/// best kept for the graphs that must be modified or given to the
+/// algorithms needing those types.
+/// # Example
+/// ```
+/// use algods::graph::{CsrGraph, DirectedGraph, GraphKind};
+/// let mut graph = DirectedGraph::init(3);
+/// graph.add_edge(0, 1);
+/// graph.add_edge(0, 2);
+/// let csr = CsrGraph::from_directed(&graph);
+/// let mut bytes = Vec::new();
+/// csr.write(&mut bytes).unwrap();
+/// assert_eq!(bytes.len(), 32 + 8 * (4 + 2));
+/// let loaded = CsrGraph::read(&mut bytes.as_slice()).unwrap();
+/// assert_eq!(loaded.kind(), GraphKind::Directed);
+/// assert_eq!(loaded.neighbors(0), &[1, 2]);
+/// assert_eq!(loaded.to_directed().unwrap().nb_edges(), 2);
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct CsrGraph {
+    kind: GraphKind,
+    weight_code: u8,
+    offsets: Vec<u64>,
+    targets: Vec<u64>,
+    weights: Vec<u64>,
+    flows: Vec<u64>,
+}
+impl CsrGraph {
+    fn build<I>(kind: GraphKind, weight_code: u8, nb_vertices: usize, adjacency: I) -> Self
+    where
+        I: Fn(usize) -> Vec<(usize, u64, u64)>,
+    {
+        // adjacent vertices of each vertex as (target, weight, flow), sorted
+        let mut graph = Self {
+            kind,
+            weight_code,
+            offsets: vec![0],
+            targets: Vec::new(),
+            weights: Vec::new(),
+            flows: Vec::new(),
+        };
+        let weighted = weight_code != 0;
+        for v in 0..nb_vertices {
+            let mut edges = adjacency(v);
+            edges.sort_unstable();
+            for (w, weight, flow) in edges {
+                graph.targets.push(w as u64);
+                if weighted {
+                    graph.weights.push(weight);
+                }
+                if kind == GraphKind::Flow {
+                    graph.flows.push(flow);
+                }
+            }
+            graph.offsets.push(graph.targets.len() as u64);
+        }
+        graph
+    }
+    pub fn from_directed(graph: &DirectedGraph) -> Self {
+        Self::build(GraphKind::Directed, 0, graph.nb_vertices(), |v| {
+            let edges = graph.vertex_edges(&v);
+            edges.into_iter().map(|w| (*w, 0, 0)).collect()
+        })
+    }
+    pub fn from_undirected(graph: &UndirectedGraph) -> Self {
+        Self::build(GraphKind::Undirected, 0, graph.nb_vertices(), |v| {
+            let edges = VertexInfo::vertex_edges(graph, &v);
+            edges.into_iter().map(|w| (*w, 0, 0)).collect()
+        })
+    }
+    pub fn from_edge_weighted_digraph<T: BinaryWeight>(graph: &EdgeWeightedDigraph<T>) -> Self {
+        let kind = GraphKind::EdgeWeightedDirected;
+        Self::build(kind, T::CODE, graph.nb_vertices(), |v| {
+            let edges = graph.vertex_edges(&v).into_iter();
+            edges.map(|(w, weight)| (*w, weight.to_bits(), 0)).collect()
+        })
+    }
+    pub fn from_edge_weighted_graph<T: BinaryWeight>(graph: &EdgeWeightedGraph<T>) -> Self {
+        let kind = GraphKind::EdgeWeightedUndirected;
+        Self::build(kind, T::CODE, graph.nb_vertices(), |v| {
+            let edges = graph.vertex_edges(&v).into_iter();
+            edges.map(|(w, weight)| (*w, weight.to_bits(), 0)).collect()
+        })
+    }
+    pub fn from_flow_network<T: BinaryWeight>(network: &FlowNetwork<T>) -> Self {
+        let mut forward = vec![Vec::new(); network.nb_vertices()];
+        for edge in network.flow_edges() {
+            let (capacity, flow) = (edge.capacity().to_bits(), edge.flow().to_bits());
+            forward[*edge.from()].push((*edge.to(), capacity, flow));
+        }
+        Self::build(GraphKind::Flow, T::CODE, network.nb_vertices(), |v| {
+            forward[v].clone()
+        })
+    }
+    pub fn kind(&self) -> GraphKind {
+        self.kind
+    }
+    pub fn nb_vertices(&self) -> usize {
+        self.offsets.len() - 1
+    }
+    /// Gives the number of stored edges
+    pub fn nb_entries(&self) -> usize {
+        self.targets.len()
+    }
+    pub fn offsets(&self) -> &[u64] {
+        &self.offsets
+    }
+    pub fn targets(&self) -> &[u64] {
+        &self.targets
+    }
+    fn range(&self, v: usize) -> std::ops::Range<usize> {
+        self.offsets[v] as usize..self.offsets[v + 1] as usize
+    }
+    /// Gives the adjacent vertices of a vertex, sorted
+    pub fn neighbors(&self, v: usize) -> &[u64] {
+        &self.targets[self.range(v)]
+    }
+    /// Gives the weights (capacities for flow networks) of the edges
+    /// of a vertex, empty for unweighted graphs
+    pub fn weights(&self, v: usize) -> &[u64] {
+        if self.weights.is_empty() {
+            return &[];
+        }
+        &self.weights[self.range(v)]
+    }
+    /// Gives the flows of the edges of a vertex, empty but for flow networks
+    pub fn flows(&self, v: usize) -> &[u64] {
+        if self.flows.is_empty() {
+            return &[];
+        }
+        &self.flows[self.range(v)]
+    }
+    /// Writes the graph in the binary format
+    pub fn write<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let mut header = Vec::with_capacity(HEADER_SIZE);
+        header.extend_from_slice(MAGIC);
+        header.extend_from_slice(&VERSION.to_le_bytes());
+        header.extend_from_slice(&[self.kind.code(), self.weight_code, 0, 0]);
+        header.extend_from_slice(&(self.nb_vertices() as u64).to_le_bytes());
+        header.extend_from_slice(&(self.nb_entries() as u64).to_le_bytes());
+        writer.write_all(&header)?;
+        write_u64s(writer, &self.offsets)?;
+        write_u64s(writer, &self.targets)?;
+        write_u64s(writer, &self.weights)?;
+        write_u64s(writer, &self.flows)?;
+        writer.flush()
+    }
+    /// Reads a graph in the binary format, checking its consistency
+    pub fn read<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let mut header = [0; HEADER_SIZE];
+        reader.read_exact(&mut header)?;
+        if &header[..8] != MAGIC {
+            return Err(invalid("not a graph file"));
+        }
+        let version = u32::from_le_bytes(header[8..12].try_into().expect("4 bytes"));
+        if version != VERSION {
+            return Err(invalid(&format!("unsupported version {version}")));
+        }
+        let kind = GraphKind::from_code(header[12]).ok_or_else(|| invalid("unknown graph kind"))?;
+        let weight_code = header[13];
+        let weighted = matches!(
+            kind,
+            GraphKind::EdgeWeightedDirected | GraphKind::EdgeWeightedUndirected | GraphKind::Flow
+        );
+        if weighted == (weight_code == 0) {
+            return Err(invalid("inconsistent weight type"));
+        }
+        let count = |range: std::ops::Range<usize>| -> io::Result<usize> {
+            let value = u64::from_le_bytes(header[range].try_into().expect("8 bytes"));
+            usize::try_from(value).map_err(|_| invalid("too large graph"))
+        };
+        let (nb_vertices, nb_entries) = (count(16..24)?, count(24..32)?);
+        let offsets = read_u64s(reader, nb_vertices.saturating_add(1))?;
+        let targets = read_u64s(reader, nb_entries)?;
+        let weights = read_u64s(reader, if weighted { nb_entries } else { 0 })?;
+        let flows = read_u64s(
+            reader,
+            if kind == GraphKind::Flow {
+                nb_entries
+            } else {
+                0
+            },
+        )?;
+        let consistent = offsets[0] == 0
+            && offsets.windows(2).all(|pair| pair[0] <= pair[1])
+            && offsets[nb_vertices] == nb_entries as u64
+            && targets.iter().all(|w| *w < nb_vertices as u64);
+        if !consistent {
+            return Err(invalid("inconsistent adjacency arrays"));
+        }
+        Ok(Self {
+            kind,
+            weight_code,
+            offsets,
+            targets,
+            weights,
+            flows,
+        })
+    }
+    fn check(&self, kind: GraphKind, weight_code: u8) -> io::Result<()> {
+        if self.kind != kind {
+            return Err(invalid(&format!("the file holds a {:?} graph", self.kind)));
+        }
+        if self.weight_code != weight_code {
+            return Err(invalid("the file holds another weight type"));
+        }
+        Ok(())
+    }
+    fn edges(&self) -> impl Iterator<Item = (usize, usize, u64, u64)> + '_ {
+        (0..self.nb_vertices()).flat_map(move |v| {
+            self.range(v).map(move |i| {
+                let weight = self.weights.get(i).copied().unwrap_or(0);
+                let flow = self.flows.get(i).copied().unwrap_or(0);
+                (v, self.targets[i] as usize, weight, flow)
+            })
+        })
+    }
+    pub fn to_directed(&self) -> io::Result<DirectedGraph> {
+        self.check(GraphKind::Directed, 0)?;
+        let mut graph = DirectedGraph::init(self.nb_vertices());
+        for (v, w, _, _) in self.edges() {
+            graph.add_edge(v, w);
+        }
+        Ok(graph)
+    }
+    pub fn to_undirected(&self) -> io::Result<UndirectedGraph> {
+        self.check(GraphKind::Undirected, 0)?;
+        let mut graph = UndirectedGraph::init(self.nb_vertices());
+        for (v, w, _, _) in self.edges().filter(|(v, w, _, _)| v <= w) {
+            graph.add_edge(v, w);
+        }
+        Ok(graph)
+    }
+    pub fn to_edge_weighted_digraph<T: BinaryWeight>(&self) -> io::Result<EdgeWeightedDigraph<T>> {
+        self.check(GraphKind::EdgeWeightedDirected, T::CODE)?;
+        let mut graph = EdgeWeightedDigraph::init(self.nb_vertices());
+        for (v, w, weight, _) in self.edges() {
+            graph.add_edge(v, w, T::from_bits(weight));
+        }
+        Ok(graph)
+    }
+    pub fn to_edge_weighted_graph<T: BinaryWeight>(&self) -> io::Result<EdgeWeightedGraph<T>> {
+        self.check(GraphKind::EdgeWeightedUndirected, T::CODE)?;
+        let mut graph = EdgeWeightedGraph::init(self.nb_vertices());
+        for (v, w, weight, _) in self.edges().filter(|(v, w, _, _)| v <= w) {
+            graph.add_edge(v, w, T::from_bits(weight));
+        }
+        Ok(graph)
+    }
+    pub fn to_flow_network<T: BinaryWeight>(&self) -> io::Result<FlowNetwork<T>> {
+        self.check(GraphKind::Flow, T::CODE)?;
+        let mut network = FlowNetwork::init(self.nb_vertices());
+        for (v, w, capacity, flow) in self.edges() {
+            // the forward edge is added at the end of the list of v
+            let (position, nb_edges) = (network.vertex_edges(&v).len(), network.nb_edges());
+            network.add_edge(v, w, T::from_bits(capacity));
+            let flow = T::from_bits(flow);
+            if network.nb_edges() > nb_edges && flow > T::zero() {
+                network.push_flow(v, position, flow);
+            }
+        }
+        Ok(network)
+    }
+}
+
+/// This trait saves graphs to and loads them from files in the binary
+/// format of `CsrGraph`, which is much faster to reload than edge lists
+/// # Example
+/// ```
+/// use algods::graph::{BinaryGraph, EdgeWeightedGraph};
+/// let mut graph = EdgeWeightedGraph::init(3);
+/// graph.add_edge(0, 1, -4i32);
+/// graph.add_edge(1, 2, 7);
+/// let mut bytes = Vec::new();
+/// graph.write_binary(&mut bytes).unwrap();
+/// let loaded = EdgeWeightedGraph::<i32>::read_binary(&mut bytes.as_slice()).unwrap();
+/// assert_eq!(loaded.edges(), graph.edges());
+/// // the weights must have the same type
+/// assert!(EdgeWeightedGraph::<i64>::read_binary(&mut bytes.as_slice()).is_err());
+/// ```
+pub trait BinaryGraph: Sized {
+    /// Gives the graph in compressed sparse row form
+    fn to_csr(&self) -> CsrGraph;
+    /// Builds the graph from its compressed sparse row form, fails
+    /// when it holds another kind of graph or another weight type
+    fn from_csr(csr: &CsrGraph) -> io::Result<Self>;
+
+    /// Writes the graph in the binary format
+    fn write_binary<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.to_csr().write(writer)
+    }
+    /// Reads a graph in the binary format
+    fn read_binary<R: Read>(reader: &mut R) -> io::Result<Self> {
+        Self::from_csr(&CsrGraph::read(reader)?)
+    }
+    /// Saves the graph to a file in the binary format
+    fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.write_binary(&mut BufWriter::new(File::create(path)?))
+    }
+    /// Loads a graph from a file in the binary format
+    fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::read_binary(&mut BufReader::new(File::open(path)?))
+    }
+}
+
+impl BinaryGraph for DirectedGraph {
+    fn to_csr(&self) -> CsrGraph {
+        CsrGraph::from_directed(self)
+    }
+    fn from_csr(csr: &CsrGraph) -> io::Result<Self> {
+        csr.to_directed()
+    }
+}
+
+impl BinaryGraph for UndirectedGraph {
+    fn to_csr(&self) -> CsrGraph {
+        CsrGraph::from_undirected(self)
+    }
+    fn from_csr(csr: &CsrGraph) -> io::Result<Self> {
+        csr.to_undirected()
+    }
+}
+
+impl<T: BinaryWeight> BinaryGraph for EdgeWeightedDigraph<T> {
+    fn to_csr(&self) -> CsrGraph {
+        CsrGraph::from_edge_weighted_digraph(self)
+    }
+    fn from_csr(csr: &CsrGraph) -> io::Result<Self> {
+        csr.to_edge_weighted_digraph()
+    }
+}
+
+impl<T: BinaryWeight> BinaryGraph for EdgeWeightedGraph<T> {
+    fn to_csr(&self) -> CsrGraph {
+        CsrGraph::from_edge_weighted_graph(self)
+    }
+    fn from_csr(csr: &CsrGraph) -> io::Result<Self> {
+        csr.to_edge_weighted_graph()
+    }
+}
+
+impl<T: BinaryWeight> BinaryGraph for FlowNetwork<T> {
+    fn to_csr(&self) -> CsrGraph {
+        CsrGraph::from_flow_network(self)
+    }
+    fn from_csr(csr: &CsrGraph) -> io::Result<Self> {
+        csr.to_flow_network()
+    }
+}
@@ -0,0 +1,179 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{BinaryGraph, CsrGraph, GraphKind};
+    use crate::graph::processing::FordFulkerson;
+    use crate::graph::{
+        DirectedGraph, EdgeWeightedDigraph, EdgeWeightedGraph, FlowNetwork, GraphOperations,
+        UndirectedGraph,
+    };
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn random_edges(rng: &mut StdRng, nb: usize) -> Vec<(usize, usize, i64)> {
+        (0..rng.gen_range(0..3 * nb + 1))
+            .map(|_| {
+                (
+                    rng.gen_range(0..nb),
+                    rng.gen_range(0..nb),
+                    rng.gen_range(-1000..1000),
+                )
+            })
+            .collect()
+    }
+
+    fn round_trip<G: BinaryGraph>(graph: &G) -> G {
+        let mut bytes = Vec::new();
+        graph.write_binary(&mut bytes).unwrap();
+        G::read_binary(&mut bytes.as_slice()).unwrap()
+    }
+
+    #[test]
+    fn test_binary_layout() {
+        let mut graph = EdgeWeightedGraph::init(3);
+        graph.add_edge(0, 1, -4i32);
+        graph.add_edge(1, 2, 7);
+        let mut bytes = Vec::new();
+        graph.write_binary(&mut bytes).unwrap();
+        // header: magic, version 1, kind 3, weight type 8 (i32), padding
+        // and the numbers of vertices and of stored edges
+        let mut expected = b"ALGODSGR".to_vec();
+        expected.extend([1, 0, 0, 0, 3, 8, 0, 0]);
+        let offsets = [0u64, 1, 3, 4];
+        let targets = [1u64, 0, 2, 1];
+        let weights = [-4i64 as u64, -4i64 as u64, 7, 7];
+        for value in [3u64, 4]
+            .iter()
+            .chain(&offsets)
+            .chain(&targets)
+            .chain(&weights)
+        {
+            expected.extend(value.to_le_bytes());
+        }
+        assert_eq!(bytes, expected);
+        let csr = CsrGraph::read(&mut bytes.as_slice()).unwrap();
+        assert_eq!(csr.kind(), GraphKind::EdgeWeightedUndirected);
+        assert_eq!((csr.nb_vertices(), csr.nb_entries()), (3, 4));
+        assert_eq!(csr.neighbors(1), &[0, 2]);
+        assert_eq!(csr.weights(2), &[7]);
+    }
+
+    #[test]
+    fn test_binary_round_trip() {
+        let mut rng = StdRng::seed_from_u64(46);
+        for _ in 0..50 {
+            let nb = rng.gen_range(1..30);
+            let edges = random_edges(&mut rng, nb);
+            let unweighted = edges
+                .iter()
+                .map(|(u, v, _)| (*u, *v, ()))
+                .collect::<Vec<_>>();
+            let graph = DirectedGraph::from_edges(nb, &unweighted);
+            assert_eq!(round_trip(&graph).edges(), graph.edges());
+            let graph = UndirectedGraph::from_edges(nb, &unweighted);
+            let loaded = round_trip(&graph);
+            assert_eq!(loaded.edges(), graph.edges());
+            assert_eq!(loaded.nb_edges(), graph.nb_edges());
+            let graph = EdgeWeightedDigraph::from_edges(nb, &edges);
+            assert_eq!(round_trip(&graph).edges(), GraphOperations::edges(&graph));
+            let graph = EdgeWeightedGraph::from_edges(nb, &edges);
+            let loaded = round_trip(&graph);
+            assert_eq!(loaded.edges(), graph.edges());
+            assert_eq!(loaded.nb_edges(), graph.nb_edges());
+            let narrow = edges
+                .iter()
+                .map(|(u, v, w)| (*u, *v, *w as i16))
+                .collect::<Vec<_>>();
+            let graph = EdgeWeightedDigraph::from_edges(nb, &narrow);
+            assert_eq!(round_trip(&graph).edges(), GraphOperations::edges(&graph));
+        }
+    }
+
+    #[test]
+    fn test_binary_flow_network() {
+        // the flows are saved along with the capacities
+        let mut network = FlowNetwork::init(4);
+        network.add_edge(0, 1, 3u32);
+        network.add_edge(0, 2, 2);
+        network.add_edge(1, 3, 2);
+        network.add_edge(2, 3, 3);
+        network.add_edge(1, 1, 4);
+        let mut max_flow = FordFulkerson::new();
+        max_flow.find_flows(&mut network, 0, 3);
+        let mut loaded = round_trip(&network);
+        let edges = |network: &FlowNetwork<u32>| {
+            let mut edges = network
+                .flow_edges()
+                .into_iter()
+                .map(|e| (*e.from(), *e.to(), *e.flow(), *e.capacity()))
+                .collect::<Vec<_>>();
+            edges.sort_unstable();
+            edges
+        };
+        assert_eq!(edges(&loaded), edges(&network));
+        // no more flow can be pushed in the loaded network
+        let mut more = FordFulkerson::new();
+        more.find_flows(&mut loaded, 0, 3);
+        assert_eq!(more.max_flow(), Some(0));
+    }
+
+    #[test]
+    fn test_binary_file() {
+        let mut graph = EdgeWeightedGraph::init(5);
+        graph.add_edge(0, 4, 3u64);
+        graph.add_edge(2, 2, 1);
+        let path = std::env::temp_dir().join(format!("algods_{}.graph", std::process::id()));
+        graph.save(&path).unwrap();
+        let loaded = EdgeWeightedGraph::<u64>::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.edges(), graph.edges());
+        assert_eq!(loaded.nb_vertices(), 5);
+    }
+
+    #[test]
+    fn test_binary_several_chunks() {
+        // arrays longer than the read buffer
+        let nb = 20_000;
+        let mut graph = DirectedGraph::init(nb);
+        for v in 0..nb - 1 {
+            graph.add_edge(v, v + 1);
+        }
+        let mut bytes = Vec::new();
+        graph.write_binary(&mut bytes).unwrap();
+        let csr = CsrGraph::read(&mut bytes.as_slice()).unwrap();
+        assert_eq!(csr.offsets().len(), nb + 1);
+        assert_eq!(csr.offsets()[nb], (nb - 1) as u64);
+        assert_eq!(csr.neighbors(12_345), &[12_346]);
+        assert_eq!(csr, graph.to_csr());
+        let truncated = &bytes[..bytes.len() - 1];
+        assert!(CsrGraph::read(&mut &truncated[..]).is_err());
+    }
+
+    #[test]
+    fn test_binary_invalid() {
+        let mut graph = DirectedGraph::init(3);
+        graph.add_edge(0, 2);
+        let mut bytes = Vec::new();
+        graph.write_binary(&mut bytes).unwrap();
+        let csr = CsrGraph::read(&mut bytes.as_slice()).unwrap();
+        assert_eq!(csr, graph.to_csr());
+        assert_eq!(csr.kind(), GraphKind::Directed);
+        assert_eq!(csr.offsets(), &[0, 1, 1, 1]);
+        assert!(csr.weights(0).is_empty());
+        // another kind of graph
+        assert!(UndirectedGraph::read_binary(&mut bytes.as_slice()).is_err());
+        assert!(EdgeWeightedDigraph::<u8>::read_binary(&mut bytes.as_slice()).is_err());
+        // truncated data
+        let truncated = &bytes[..bytes.len() - 1];
+        assert!(DirectedGraph::read_binary(&mut &truncated[..]).is_err());
+        // corrupted magic, version and target
+        for (position, value) in [(0, b'X'), (8, 2), (bytes.len() - 8, 3)] {
+            let mut corrupted = bytes.clone();
+            corrupted[position] = value;
+            assert!(CsrGraph::read(&mut corrupted.as_slice()).is_err());
+        }
+        // a huge number of edges does not allocate it all
+        let mut corrupted = bytes.clone();
+        corrupted[24..32].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert!(CsrGraph::read(&mut corrupted.as_slice()).is_err());
+    }
+}
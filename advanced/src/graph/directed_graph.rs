@@ -2,7 +2,8 @@
 mod unit_test;
 use crate::graph::{VertexInfo, Weight};
 use crate::utils::read_lines;
-use std::collections::HashSet;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 
 #[derive(Eq, Hash, PartialEq, Copy, Clone)]
@@ -25,7 +26,10 @@ impl DirectedEdge {
         &self.from
     }
 }
-/// Implementation of an adjacency-list based unweighted directed graph
+/// Implementation of an adjacency-list based unweighted directed graph,
+/// whose vertices and edges can carry data (labels, timestamps, ...),
+/// `()` by default
+/// # Example
 /// ```
 /// use algods::graph::DirectedGraph;
 /// let mut graph = DirectedGraph::init(3);
@@ -35,15 +39,27 @@ impl DirectedEdge {
 /// assert_eq!(graph.nb_edges(), 2);
 /// graph.add_vertex();
 /// assert_eq!(graph.nb_vertices(), 4);
+/// // a graph whose vertices have names and edges have years
+/// let mut graph = DirectedGraph::<&str, u32>::with_vertices(vec!["a", "b", "c"]);
+/// graph.add_edge_with(0, 1, 2017);
+/// graph.add_edge_with(1, 2, 2023);
+/// assert_eq!(graph.vertex_data(&2), &"c");
+/// assert_eq!(graph.edge_data(&0, &1), Some(&2017));
+/// *graph.edge_data_mut(&1, &2).unwrap() += 1;
+/// // the weighted graph whose weights are the ages of the edges in 2024
+/// let weighted = graph.to_weighted(|year| 2024 - *year as i32);
+/// assert_eq!(weighted.vertex_edges(&1), vec![(&2, &0)]);
 /// ```
-pub struct DirectedGraph {
+pub struct DirectedGraph<V = (), E = ()> {
     // implements an adjacency-list graph
     // where vertices have indices 0, ..., nb_objects
-    // and each vertex is associated to the vertices it points to
-    data: Vec<HashSet<DirectedEdge>>,
+    // and each vertex is associated to the vertices it points to,
+    // along with the data of the edges
+    data: Vec<HashMap<usize, E>>,
     nb_edges: usize,
     nb_vertices: usize,
     in_edges: Vec<HashSet<usize>>,
+    vertex_data: Vec<V>,
 }
 impl Default for DirectedGraph {
     fn default() -> Self {
@@ -53,36 +69,11 @@ impl Default for DirectedGraph {
 impl DirectedGraph {
     /// Creates a new empty graph.
     pub fn new() -> Self {
-        Self {
-            data: Vec::new(),
-            nb_edges: 0,
-            nb_vertices: 0,
-            in_edges: Vec::new(),
-        }
+        Self::with_vertices(Vec::new())
     }
     /// Creates a new graph with unconnected `nb_objects` objects
     pub fn init(nb_objects: usize) -> Self {
-        let mut graph = Self::new();
-        graph.nb_vertices = nb_objects;
-        graph.data = Vec::with_capacity(nb_objects);
-        for _ in 0..nb_objects {
-            graph.data.push(HashSet::new());
-            graph.in_edges.push(HashSet::new());
-        }
-        graph
-    }
-    /// Creates a new graph which has the same vertices but edges reverted.
-    pub fn reverse(&self) -> Self {
-        // Gets the reverse graph
-        let nb_vertices = self.nb_vertices;
-        let mut rev_graph = Self::init(nb_vertices);
-        for v in 0..nb_vertices {
-            let adj_v = self.vertex_edges(&v);
-            for w in adj_v {
-                rev_graph.add_edge(*w, v);
-            }
-        }
-        rev_graph
+        Self::with_vertices(vec![(); nb_objects])
     }
     /// Creates a graph from a file
     pub fn from_file<P>(filename: P, sep: char, nb_vertices: usize) -> Self
@@ -118,7 +109,53 @@ impl DirectedGraph {
         }
         dg
     }
-
+}
+impl<V: Clone, E: Clone> DirectedGraph<V, E> {
+    /// Creates a new graph which has the same vertices but edges reverted.
+    pub fn reverse(&self) -> Self {
+        // Gets the reverse graph, with the same vertex and edge data
+        let mut rev_graph = Self::with_vertices(self.vertex_data.clone());
+        for (v, adj_v) in self.data.iter().enumerate() {
+            for (w, data) in adj_v {
+                rev_graph.add_edge_with(*w, v, data.clone());
+            }
+        }
+        rev_graph
+    }
+}
+impl<V, E: Default> DirectedGraph<V, E> {
+    /// Adds a new edge to the graph, with the default edge data
+    /// when it is not already in the graph
+    pub fn add_edge(&mut self, v: usize, w: usize) {
+        // adds an edge from v to w to the graph
+        // run time complexity O(1)
+        assert!(self.nb_vertices > std::cmp::max(v, w));
+        if let Entry::Vacant(edge) = self.data[v].entry(w) {
+            // v --> w is a new directed edge
+            edge.insert(E::default());
+            self.in_edges[w].insert(v);
+            self.nb_edges += 1;
+        }
+    }
+}
+impl<V: Default, E> DirectedGraph<V, E> {
+    /// Adds a new vertex to the graph, with the default vertex data
+    pub fn add_vertex(&mut self) {
+        self.add_vertex_with(V::default());
+    }
+}
+impl<V, E> DirectedGraph<V, E> {
+    /// Creates a new graph without edges, with one vertex per data
+    pub fn with_vertices(vertex_data: Vec<V>) -> Self {
+        let nb_vertices = vertex_data.len();
+        Self {
+            data: (0..nb_vertices).map(|_| HashMap::new()).collect(),
+            nb_edges: 0,
+            nb_vertices,
+            in_edges: (0..nb_vertices).map(|_| HashSet::new()).collect(),
+            vertex_data,
+        }
+    }
     /// Gives the number of edges
     pub fn nb_edges(&self) -> usize {
         // run time complexity O(1)
@@ -129,40 +166,58 @@ impl DirectedGraph {
         // run time complexity O(1)
         self.nb_vertices
     }
-    /// Adds a new edge o the graph
-    pub fn add_edge(&mut self, v: usize, w: usize) {
-        // adds an edge from v to w to the graph
+    /// Adds a new edge to the graph with its data,
+    /// replacing the data of the edge when it is already in the graph
+    pub fn add_edge_with(&mut self, v: usize, w: usize, data: E) {
         // run time complexity O(1)
-        assert!(self.nb_vertices >= std::cmp::max(v, w));
-        let edge = DirectedEdge::init(v, w);
-        let w_is_in = self.data[v].insert(edge);
-        self.in_edges[w].insert(v);
-        if w_is_in {
+        assert!(self.nb_vertices > std::cmp::max(v, w));
+        if self.data[v].insert(w, data).is_none() {
             // v --> w is a new directed edge
+            self.in_edges[w].insert(v);
             self.nb_edges += 1;
         }
     }
-    /// Adds a new vertex to the graph
-    pub fn add_vertex(&mut self) {
-        self.data.push(HashSet::<DirectedEdge>::new());
+    /// Adds a new vertex to the graph with its data, returns its index
+    pub fn add_vertex_with(&mut self, data: V) -> usize {
+        self.data.push(HashMap::new());
+        self.in_edges.push(HashSet::new());
+        self.vertex_data.push(data);
         self.nb_vertices += 1;
+        self.nb_vertices - 1
+    }
+    /// Gives the data of a vertex
+    pub fn vertex_data(&self, v: &usize) -> &V {
+        &self.vertex_data[*v]
+    }
+    /// Gives a mutable reference to the data of a vertex
+    pub fn vertex_data_mut(&mut self, v: &usize) -> &mut V {
+        &mut self.vertex_data[*v]
+    }
+    /// Gives the data of the edge from v to w, if it is in the graph
+    pub fn edge_data(&self, v: &usize, w: &usize) -> Option<&E> {
+        self.data[*v].get(w)
+    }
+    /// Gives a mutable reference to the data of the edge
+    /// from v to w, if it is in the graph
+    pub fn edge_data_mut(&mut self, v: &usize, w: &usize) -> Option<&mut E> {
+        self.data[*v].get_mut(w)
     }
     /// Returns an immutable reference to the set of edges
     pub fn vertex_edges(&self, v: &usize) -> Vec<&usize> {
         // gets all the vertices linked to a given vertex v,
         // that is the adjacent vertices of v
         // run time complexity O(1)
-        self.data[*v]
-            .iter()
-            .map(|edge| edge.to())
-            .collect::<Vec<&usize>>()
+        self.data[*v].keys().collect::<Vec<&usize>>()
     }
-    ///
-    pub fn out_edges(&self, v: &usize) -> &HashSet<DirectedEdge> {
-        // gets all the vertices linked to a given vertex v,
-        // that is the adjacent vertices of v
-        // run time complexity O(1)
-        &self.data[*v]
+    /// Gives the edges going out of a vertex
+    pub fn out_edges(&self, v: &usize) -> Vec<DirectedEdge> {
+        // gets all the edges from a given vertex v
+        // to the adjacent vertices of v
+        // run time complexity O(out degree of v)
+        self.data[*v]
+            .keys()
+            .map(|w| DirectedEdge::init(*v, *w))
+            .collect()
     }
     ///
     pub fn in_edges(&self, v: &usize) -> &HashSet<usize> {
@@ -176,15 +231,13 @@ impl DirectedGraph {
     /// Gives the number of vertices a vertex point to
     pub fn out_degree(&self, v: &usize) -> usize {
         // the number of vertices the vertex v points to
-        self.vertex_edges(v).len()
+        self.data[*v].len()
     }
     /// Gives the number of vertices pointing to a vertex
     pub fn in_degree(&self, v: &usize) -> usize {
         // gives the number of vertices pointing to vertex v
-        self.data
-            .iter()
-            .map(|adj| usize::from(adj.iter().any(|e| e.to() == v)))
-            .sum()
+        // run time complexity O(1)
+        self.in_edges[*v].len()
     }
     /// Gives the integer part of the average number of edges per vertex
     pub fn average_degree(&self) -> usize {
@@ -200,11 +253,23 @@ impl DirectedGraph {
         self.data
             .iter()
             .enumerate()
-            .map(|(v, e)| usize::from(e.contains(&DirectedEdge::init(v, v))))
+            .map(|(v, e)| usize::from(e.contains_key(&v)))
             .sum()
     }
+    /// Creates the weighted graph with the same vertices and edges,
+    /// the weight of each edge being projected from its data, in order
+    /// to run the algorithms on weighted graphs
+    pub fn to_weighted<T: Weight, F: Fn(&E) -> T>(&self, weight: F) -> EdgeWeightedDigraph<T> {
+        let mut graph = EdgeWeightedDigraph::init(self.nb_vertices);
+        for (v, adj_v) in self.data.iter().enumerate() {
+            for (w, data) in adj_v {
+                graph.add_edge(v, *w, weight(data));
+            }
+        }
+        graph
+    }
 }
-impl VertexInfo for DirectedGraph {
+impl<V, E> VertexInfo for DirectedGraph<V, E> {
     fn vertex_edges(&self, v: &usize) -> Vec<&usize> {
         // gets all the vertices linked to a given vertex v,
         // that is the adjacent vertices of v
@@ -422,6 +487,10 @@ where
     T: Weight,
 {
     data: Vec<Vec<FlowEdge<T>>>,
+    // for each edge, the position of its paired edge in the list of its
+    // destination, and whether or not it is an edge of the network
+    // (the paired edge being its backward residual edge)
+    pairs: Vec<Vec<(usize, bool)>>,
     nb_edges: usize,
     nb_vertices: usize,
 }
@@ -431,6 +500,7 @@ impl<T: Weight> FlowNetwork<T> {
     pub fn new() -> Self {
         Self {
             data: Vec::new(),
+            pairs: Vec::new(),
             nb_edges: 0,
             nb_vertices: 0,
         }
@@ -440,8 +510,10 @@ impl<T: Weight> FlowNetwork<T> {
         let mut graph = Self::new();
         graph.nb_vertices = nb_objects;
         graph.data = Vec::with_capacity(nb_objects);
+        graph.pairs = Vec::with_capacity(nb_objects);
         for _ in 0..nb_objects {
             graph.data.push(Vec::new());
+            graph.pairs.push(Vec::new());
         }
         graph
     }
@@ -460,20 +532,50 @@ impl<T: Weight> FlowNetwork<T> {
         // adds an edge from v to w to the graph
         // run time complexity O(1)
         assert!(self.nb_vertices >= std::cmp::max(from, to));
+        // the residual capacity of the backward edge is the flow
+        // of the forward edge, which can be cancelled
         let zero = Weight::zero();
         let forward_edge = FlowEdge::init(from, to, zero, cap);
-        let backward_edge = FlowEdge::init(to, from, zero, zero);
+        let backward_edge = FlowEdge::init(to, from, cap, cap);
         if !self.data[from].contains(&forward_edge) {
+            let forward_position = self.data[from].len();
+            let backward_position = self.data[to].len() + usize::from(from == to);
             self.data[from].push(forward_edge);
+            self.pairs[from].push((backward_position, true));
             self.data[to].push(backward_edge);
+            self.pairs[to].push((forward_position, false));
             self.nb_edges += 1;
         }
     }
     /// Adds a new vertex to the graph
     pub fn add_vertex(&mut self) {
         self.data.push(Vec::new());
+        self.pairs.push(Vec::new());
         self.nb_vertices += 1;
     }
+    /// Pushes `delta` units of flow along the edge at position `index`
+    /// in the list of `v`, updating its paired residual edge
+    pub fn push_flow(&mut self, v: usize, index: usize, delta: T) {
+        let to = *self.data[v][index].to();
+        self.data[v][index].add_residual_flow_to(&to, delta);
+        let paired = self.pairs[v][index].0;
+        self.data[to][paired].add_residual_flow_to(&to, delta);
+    }
+    /// Returns the edges of the network with their current flow,
+    /// backward residual edges being left out
+    pub fn flow_edges(&self) -> Vec<&FlowEdge<T>> {
+        self.data
+            .iter()
+            .zip(self.pairs.iter())
+            .flat_map(|(edges, pairs)| {
+                edges
+                    .iter()
+                    .zip(pairs.iter())
+                    .filter(|(_, (_, forward))| *forward)
+                    .map(|(edge, _)| edge)
+            })
+            .collect()
+    }
     /// Returns an immutable reference to the set of edges
     pub fn vertex_edges(&self, v: &usize) -> Vec<&FlowEdge<T>> {
         // gets all the vertices linked to a given vertex v,
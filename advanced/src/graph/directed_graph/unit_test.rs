@@ -2,7 +2,8 @@
 mod tests {
     use super::super::{DirectedGraph, EdgeWeightedDigraph};
     use crate::graph::processing::{
-        BreadthFirstSearch, DepthFirstSearch, StrongConnectedComponent, TopologicalSort,
+        BreadthFirstSearch, DepthFirstSearch, ShortestPath, ShortestPathAlgo,
+        StrongConnectedComponent, TopologicalSort,
     };
 
     #[test]
@@ -182,4 +183,60 @@ mod tests {
         assert!(scc.connected(11, 10).unwrap());
         assert!(!scc.connected(9, 8).unwrap());
     }
+
+    #[test]
+    fn test_directed_graph_with_data() {
+        #[derive(Debug, PartialEq, Clone)]
+        struct Road {
+            length: u32,
+            toll: bool,
+        }
+        let cities = vec!["Paris", "Lyon", "Marseille", "Nice"];
+        let mut graph = DirectedGraph::<&str, Road>::with_vertices(cities);
+        let road = |length, toll| Road { length, toll };
+        graph.add_edge_with(0, 1, road(465, true));
+        graph.add_edge_with(1, 2, road(315, true));
+        graph.add_edge_with(0, 2, road(900, false));
+        graph.add_edge_with(2, 3, road(200, false));
+        graph.add_edge_with(3, 2, road(200, false));
+        assert_eq!(graph.nb_edges(), 5);
+        assert_eq!(graph.edge_data(&1, &0), None);
+        assert_eq!(graph.edge_data(&0, &1), Some(&road(465, true)));
+        // replacing the data of an edge does not add an edge
+        graph.add_edge_with(0, 2, road(775, false));
+        assert_eq!(graph.nb_edges(), 5);
+        graph.edge_data_mut(&1, &2).unwrap().toll = false;
+        assert_eq!(graph.edge_data(&1, &2), Some(&road(315, false)));
+        assert_eq!(graph.add_vertex_with("Toulouse"), 4);
+        *graph.vertex_data_mut(&4) = "Bordeaux";
+        assert_eq!(graph.vertex_data(&4), &"Bordeaux");
+        assert_eq!(graph.nb_vertices(), 5);
+
+        // the data follows the reversed edges
+        let reverse = graph.reverse();
+        assert_eq!(reverse.edge_data(&2, &0), Some(&road(775, false)));
+        assert_eq!(reverse.vertex_data(&0), &"Paris");
+        assert_eq!(reverse.nb_edges(), 5);
+
+        // the unweighted algorithms run on the graph directly
+        let mut bfs = BreadthFirstSearch::init(graph.nb_vertices(), 0);
+        bfs.find_paths(&graph);
+        assert_eq!(bfs.path_to(3), Some(vec![3, 2, 0]));
+        assert_eq!(bfs.path_to(4), None);
+        let mut scc = StrongConnectedComponent::init(graph.nb_vertices());
+        scc.find_scc(&graph);
+        assert_eq!(scc.count(), 4);
+        assert!(scc.connected(2, 3).unwrap());
+
+        // and the weighted ones through a projection of the data
+        let weighted = graph.to_weighted(|r| r.length);
+        let mut sp = ShortestPath::init(0, ShortestPathAlgo::Dijkstra, weighted.nb_vertices());
+        sp.find_paths(&weighted);
+        assert_eq!(sp.dist_to(3), &975);
+        assert_eq!(sp.path_to(3), Some(vec![3, 2, 0]));
+        let tolls = graph.to_weighted(|r| if r.toll { 100 } else { 1 });
+        let mut sp = ShortestPath::init(0, ShortestPathAlgo::Dijkstra, tolls.nb_vertices());
+        sp.find_paths(&tolls);
+        assert_eq!(sp.dist_to(2), &1);
+    }
 }
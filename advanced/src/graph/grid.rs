@@ -0,0 +1,264 @@
+mod maze;
+#[cfg(test)]
+mod unit_test;
+use crate::graph::processing::BreadthFirstSearch;
+use crate::graph::VertexInfo;
+pub use maze::{generate_maze, MazeAlgo};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Enumeration of the moves allowed between the cells of a grid
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Moves to the cells above, below, on the left and on the right
+    #[default]
+    Four,
+    /// Moves to the diagonal cells too, without cutting the corner of an
+    /// obstacle (the two cells the diagonal goes between must be free)
+    Eight,
+}
+
+/// Implementation of a grid graph whose vertices are the cells of a
+/// rectangle, addressed by (row, column), the cell (row, col) being the
+/// vertex `row * nb_cols + col`. An obstacle cell has no edge, and the
+/// free cells are linked to their free neighbors.
+/// # Example
+/// ```
+/// use algods::graph::grid::{Connectivity, GridGraph};
+/// let mut grid = GridGraph::init(3, 4, Connectivity::Four);
+/// grid.set_obstacle((0, 1), true);
+/// grid.set_obstacle((1, 1), true);
+/// let path = grid.shortest_path((0, 0), (0, 2)).unwrap();
+/// assert_eq!(path, vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2), (1, 2), (0, 2)]);
+/// assert_eq!(grid.a_star((0, 0), (0, 2)).unwrap().len(), path.len());
+/// assert_eq!(grid.render(&path), "*#*.\n*#*.\n***.");
+/// ```
+pub struct GridGraph {
+    nb_rows: usize,
+    nb_cols: usize,
+    connectivity: Connectivity,
+    obstacle: Vec<bool>,
+    // free neighbors of each cell, empty for an obstacle
+    data: Vec<Vec<usize>>,
+}
+impl GridGraph {
+    /// Creates a grid without obstacles
+    pub fn init(nb_rows: usize, nb_cols: usize, connectivity: Connectivity) -> Self {
+        Self::with_obstacles(
+            nb_rows,
+            nb_cols,
+            connectivity,
+            vec![false; nb_rows * nb_cols],
+        )
+    }
+    /// Creates a grid from its rows of characters, '#' being an obstacle
+    /// and any other character a free cell, the rows being separated by
+    /// new lines and having the same number of characters
+    pub fn from_ascii(text: &str, connectivity: Connectivity) -> Self {
+        let rows = text.lines().collect::<Vec<&str>>();
+        let nb_cols = rows.first().map_or(0, |row| row.chars().count());
+        let mut obstacle = Vec::with_capacity(rows.len() * nb_cols);
+        for line in &rows {
+            assert_eq!(line.chars().count(), nb_cols, "rows of different lengths");
+            obstacle.extend(line.chars().map(|c| c == '#'));
+        }
+        Self::with_obstacles(rows.len(), nb_cols, connectivity, obstacle)
+    }
+    fn with_obstacles(
+        nb_rows: usize,
+        nb_cols: usize,
+        connectivity: Connectivity,
+        obstacle: Vec<bool>,
+    ) -> Self {
+        let mut grid = Self {
+            nb_rows,
+            nb_cols,
+            connectivity,
+            obstacle,
+            data: Vec::new(),
+        };
+        grid.data = (0..nb_rows * nb_cols)
+            .map(|v| grid.free_neighbors(v))
+            .collect();
+        grid
+    }
+    pub fn nb_rows(&self) -> usize {
+        self.nb_rows
+    }
+    pub fn nb_cols(&self) -> usize {
+        self.nb_cols
+    }
+    pub fn connectivity(&self) -> Connectivity {
+        self.connectivity
+    }
+    pub fn nb_vertices(&self) -> usize {
+        self.obstacle.len()
+    }
+    /// Gives the vertex of a cell
+    pub fn vertex(&self, (row, col): (usize, usize)) -> usize {
+        assert!(
+            row < self.nb_rows && col < self.nb_cols,
+            "cell out of the grid"
+        );
+        row * self.nb_cols + col
+    }
+    /// Gives the cell of a vertex
+    pub fn cell(&self, v: usize) -> (usize, usize) {
+        (v / self.nb_cols, v % self.nb_cols)
+    }
+    pub fn is_obstacle(&self, cell: (usize, usize)) -> bool {
+        self.obstacle[self.vertex(cell)]
+    }
+    /// Turns a cell into an obstacle or into a free cell
+    pub fn set_obstacle(&mut self, cell: (usize, usize), obstacle: bool) {
+        // only the edges of the cell and of its surrounding cells change
+        // run time complexity O(1)
+        let v = self.vertex(cell);
+        if self.obstacle[v] == obstacle {
+            return;
+        }
+        self.obstacle[v] = obstacle;
+        for w in self.surrounding(v).into_iter().chain([v]) {
+            self.data[w] = self.free_neighbors(w);
+        }
+    }
+    /// Gives the free cells a cell is linked to
+    pub fn neighbors(&self, cell: (usize, usize)) -> Vec<(usize, usize)> {
+        let v = self.vertex(cell);
+        self.data[v].iter().map(|w| self.cell(*w)).collect()
+    }
+    fn surrounding(&self, v: usize) -> Vec<usize> {
+        // cells around a cell, diagonal ones included
+        let (row, col) = self.cell(v);
+        let mut cells = Vec::with_capacity(8);
+        for r in row.saturating_sub(1)..std::cmp::min(row + 2, self.nb_rows) {
+            for c in col.saturating_sub(1)..std::cmp::min(col + 2, self.nb_cols) {
+                if (r, c) != (row, col) {
+                    cells.push(r * self.nb_cols + c);
+                }
+            }
+        }
+        cells
+    }
+    fn free_neighbors(&self, v: usize) -> Vec<usize> {
+        if self.obstacle[v] {
+            return Vec::new();
+        }
+        let (row, col) = self.cell(v);
+        self.surrounding(v)
+            .into_iter()
+            .filter(|w| {
+                let (r, c) = self.cell(*w);
+                if self.obstacle[*w] {
+                    return false;
+                }
+                if r == row || c == col {
+                    return true;
+                }
+                self.connectivity == Connectivity::Eight
+                    && !self.obstacle[r * self.nb_cols + col]
+                    && !self.obstacle[row * self.nb_cols + c]
+            })
+            .collect()
+    }
+    /// Gives a path with the fewest moves between two cells, found with a
+    /// breadth first search, `None` when there is none
+    pub fn shortest_path(
+        &self,
+        from: (usize, usize),
+        to: (usize, usize),
+    ) -> Option<Vec<(usize, usize)>> {
+        // run time complexity O(V + E)
+        let (source, target) = (self.vertex(from), self.vertex(to));
+        if self.obstacle[source] || self.obstacle[target] {
+            return None;
+        }
+        let mut bfs = BreadthFirstSearch::init(self.nb_vertices(), source);
+        bfs.find_paths(self);
+        let path = bfs.path_to(target)?;
+        Some(path.into_iter().rev().map(|v| self.cell(v)).collect())
+    }
+    fn heuristic(&self, v: usize, target: usize) -> usize {
+        // lower bound of the number of moves from v to target
+        let ((row, col), (r, c)) = (self.cell(v), self.cell(target));
+        let (dr, dc) = (row.abs_diff(r), col.abs_diff(c));
+        match self.connectivity {
+            Connectivity::Four => dr + dc,
+            Connectivity::Eight => std::cmp::max(dr, dc),
+        }
+    }
+    /// Gives a path with the fewest moves between two cells, found with
+    /// the A* algorithm guided by the Manhattan distance (Chebyshev distance
+    /// with 8-connectivity), `None` when there is none
+    pub fn a_star(&self, from: (usize, usize), to: (usize, usize)) -> Option<Vec<(usize, usize)>> {
+        // run time complexity O(E log(V)) in the worst case
+        let (source, target) = (self.vertex(from), self.vertex(to));
+        if self.obstacle[source] || self.obstacle[target] {
+            return None;
+        }
+        let mut dist = vec![usize::MAX; self.nb_vertices()];
+        let mut edge_to = vec![source; self.nb_vertices()];
+        let mut queue = BinaryHeap::new();
+        dist[source] = 0;
+        queue.push(Reverse((self.heuristic(source, target), 0, source)));
+        while let Some(Reverse((_, d, v))) = queue.pop() {
+            if v == target {
+                let mut path = vec![to];
+                let mut x = target;
+                while x != source {
+                    x = edge_to[x];
+                    path.push(self.cell(x));
+                }
+                path.reverse();
+                return Some(path);
+            }
+            if d > dist[v] {
+                // outdated entry
+                continue;
+            }
+            for w in &self.data[v] {
+                if d + 1 < dist[*w] {
+                    dist[*w] = d + 1;
+                    edge_to[*w] = v;
+                    queue.push(Reverse((d + 1 + self.heuristic(*w, target), d + 1, *w)));
+                }
+            }
+        }
+        None
+    }
+    /// Draws the grid with one character per cell, '#' for an obstacle,
+    /// '*' for a cell of the path and '.' for another free cell, the rows
+    /// being separated by new lines
+    pub fn render(&self, path: &[(usize, usize)]) -> String {
+        let mut on_path = vec![false; self.nb_vertices()];
+        for cell in path {
+            on_path[self.vertex(*cell)] = true;
+        }
+        (0..self.nb_rows)
+            .map(|row| {
+                (0..self.nb_cols)
+                    .map(|col| {
+                        let v = row * self.nb_cols + col;
+                        if self.obstacle[v] {
+                            '#'
+                        } else if on_path[v] {
+                            '*'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+impl VertexInfo for GridGraph {
+    fn vertex_edges(&self, v: &usize) -> Vec<&usize> {
+        // run time complexity O(1)
+        self.data[*v].iter().collect()
+    }
+    fn nb_vertices(&self) -> usize {
+        self.obstacle.len()
+    }
+}
@@ -0,0 +1,172 @@
+use super::{Connectivity, GridGraph};
+use crate::search::{UnionFind, UnionFindAlgorithm};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+/// Enumeration of the maze generation algorithms, each one building a
+/// random spanning tree of the cells
+#[derive(Default, Clone, Copy, Debug)]
+pub enum MazeAlgo {
+    /// Randomized depth first search, giving long winding corridors
+    #[default]
+    DepthFirst,
+    /// Randomized Kruskal algorithm, removing the walls in random order
+    /// unless they separate cells already linked
+    Kruskal,
+    /// Randomized Prim algorithm, growing the maze from a cell through
+    /// random walls of its frontier, giving many short dead ends
+    Prim,
+}
+
+/// Function that generates a perfect maze (exactly one path between two
+/// cells) of `nb_rows` x `nb_cols` cells, as a 4-connected grid of
+/// `2 nb_rows + 1` rows and `2 nb_cols + 1` columns where the cell
+/// (row, col) of the maze is the grid cell (2 row + 1, 2 col + 1), the
+/// walls being obstacles.
+/// # Example
+/// ```
+/// use algods::graph::grid::{generate_maze, MazeAlgo};
+/// let maze = generate_maze(3, 4, MazeAlgo::Kruskal, 7);
+/// assert_eq!((maze.nb_rows(), maze.nb_cols()), (7, 9));
+/// // the corners of the maze are linked by a path
+/// let path = maze.shortest_path((1, 1), (5, 7)).unwrap();
+/// assert_eq!(maze.a_star((1, 1), (5, 7)), Some(path.clone()));
+/// let drawing = maze.render(&path);
+/// // the maze is surrounded by walls and the path goes through free cells
+/// assert!(drawing.lines().next().unwrap().chars().all(|c| c == '#'));
+/// assert_eq!(drawing.lines().count(), 7);
+/// assert_eq!(drawing.matches('*').count(), path.len());
+/// ```
+pub fn generate_maze(nb_rows: usize, nb_cols: usize, algorithm: MazeAlgo, seed: u64) -> GridGraph {
+    // run time complexity O(RC log(RC)) at most, for R rows and C columns
+    let mut rng = StdRng::seed_from_u64(seed);
+    let nb = nb_rows * nb_cols;
+    let passages = match algorithm {
+        MazeAlgo::DepthFirst => depth_first(nb_rows, nb_cols, &mut rng),
+        MazeAlgo::Kruskal => kruskal(nb_rows, nb_cols, &mut rng),
+        MazeAlgo::Prim => prim(nb_rows, nb_cols, &mut rng),
+    };
+    debug_assert!(nb == 0 || passages.len() == nb - 1);
+    let (height, width) = (2 * nb_rows + 1, 2 * nb_cols + 1);
+    let mut obstacle = vec![true; height * width];
+    for v in 0..nb {
+        let (row, col) = (v / nb_cols, v % nb_cols);
+        obstacle[(2 * row + 1) * width + 2 * col + 1] = false;
+    }
+    for (v, w) in passages {
+        // the wall between two adjacent cells is in the middle
+        let (row, col) = (v / nb_cols + w / nb_cols + 1, v % nb_cols + w % nb_cols + 1);
+        obstacle[row * width + col] = false;
+    }
+    GridGraph::with_obstacles(height, width, Connectivity::Four, obstacle)
+}
+
+fn adjacent_cells(v: usize, nb_rows: usize, nb_cols: usize) -> Vec<usize> {
+    // cells above, below, on the left and on the right of a cell
+    let (row, col) = (v / nb_cols, v % nb_cols);
+    let mut cells = Vec::with_capacity(4);
+    if row > 0 {
+        cells.push(v - nb_cols);
+    }
+    if row + 1 < nb_rows {
+        cells.push(v + nb_cols);
+    }
+    if col > 0 {
+        cells.push(v - 1);
+    }
+    if col + 1 < nb_cols {
+        cells.push(v + 1);
+    }
+    cells
+}
+
+fn depth_first(nb_rows: usize, nb_cols: usize, rng: &mut StdRng) -> Vec<(usize, usize)> {
+    let nb = nb_rows * nb_cols;
+    let mut passages = Vec::with_capacity(nb.saturating_sub(1));
+    if nb == 0 {
+        return passages;
+    }
+    let mut marked = vec![false; nb];
+    let start = rng.gen_range(0..nb);
+    marked[start] = true;
+    let mut stack = vec![start];
+    while let Some(v) = stack.last().copied() {
+        let unmarked = adjacent_cells(v, nb_rows, nb_cols)
+            .into_iter()
+            .filter(|w| !marked[*w])
+            .collect::<Vec<usize>>();
+        match unmarked.choose(rng) {
+            Some(w) => {
+                marked[*w] = true;
+                passages.push((v, *w));
+                stack.push(*w);
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+    passages
+}
+
+fn walls(nb_rows: usize, nb_cols: usize) -> Vec<(usize, usize)> {
+    // pairs of adjacent cells, the smallest first
+    (0..nb_rows * nb_cols)
+        .flat_map(|v| {
+            adjacent_cells(v, nb_rows, nb_cols)
+                .into_iter()
+                .filter(move |w| *w > v)
+                .map(move |w| (v, w))
+        })
+        .collect()
+}
+
+fn kruskal(nb_rows: usize, nb_cols: usize, rng: &mut StdRng) -> Vec<(usize, usize)> {
+    let mut walls = walls(nb_rows, nb_cols);
+    walls.shuffle(rng);
+    let mut cells = UnionFind::with_capacity(
+        nb_rows * nb_cols,
+        UnionFindAlgorithm::WeightedQuickUnionPathComp,
+    );
+    walls
+        .into_iter()
+        .filter(|(v, w)| {
+            if cells.connected(*v, *w) {
+                return false;
+            }
+            cells.union(*v, *w);
+            true
+        })
+        .collect()
+}
+
+fn prim(nb_rows: usize, nb_cols: usize, rng: &mut StdRng) -> Vec<(usize, usize)> {
+    let nb = nb_rows * nb_cols;
+    let mut passages = Vec::with_capacity(nb.saturating_sub(1));
+    if nb == 0 {
+        return passages;
+    }
+    let mut in_maze = vec![false; nb];
+    let start = rng.gen_range(0..nb);
+    in_maze[start] = true;
+    // walls between a cell of the maze and a cell which may not be in it
+    let mut frontier = adjacent_cells(start, nb_rows, nb_cols)
+        .into_iter()
+        .map(|w| (start, w))
+        .collect::<Vec<(usize, usize)>>();
+    while !frontier.is_empty() {
+        let (v, w) = frontier.swap_remove(rng.gen_range(0..frontier.len()));
+        if in_maze[w] {
+            continue;
+        }
+        in_maze[w] = true;
+        passages.push((v, w));
+        for x in adjacent_cells(w, nb_rows, nb_cols) {
+            if !in_maze[x] {
+                frontier.push((w, x));
+            }
+        }
+    }
+    passages
+}
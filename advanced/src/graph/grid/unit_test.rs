@@ -0,0 +1,136 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{generate_maze, Connectivity, GridGraph, MazeAlgo};
+    use crate::graph::processing::BreadthFirstSearch;
+    use crate::graph::VertexInfo;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn test_grid_connectivity() {
+        let grid = GridGraph::init(3, 3, Connectivity::Four);
+        assert_eq!(grid.nb_vertices(), 9);
+        assert_eq!(grid.vertex((1, 2)), 5);
+        assert_eq!(grid.cell(7), (2, 1));
+        let mut neighbors = grid.neighbors((1, 1));
+        neighbors.sort_unstable();
+        assert_eq!(neighbors, vec![(0, 1), (1, 0), (1, 2), (2, 1)]);
+        assert_eq!(grid.neighbors((0, 0)).len(), 2);
+
+        let mut grid = GridGraph::init(3, 3, Connectivity::Eight);
+        assert_eq!(grid.neighbors((1, 1)).len(), 8);
+        assert_eq!(grid.neighbors((0, 0)).len(), 3);
+        // the diagonal moves cannot cut the corner of an obstacle
+        grid.set_obstacle((0, 1), true);
+        let mut neighbors = grid.neighbors((0, 0));
+        neighbors.sort_unstable();
+        assert_eq!(neighbors, vec![(1, 0)]);
+        assert!(grid.neighbors((0, 1)).is_empty());
+        assert_eq!(grid.neighbors((1, 1)).len(), 5);
+        assert_eq!(grid.shortest_path((0, 0), (0, 2)).unwrap().len(), 5);
+        grid.set_obstacle((0, 1), false);
+        assert_eq!(grid.neighbors((0, 0)).len(), 3);
+        assert_eq!(grid.shortest_path((0, 0), (0, 2)).unwrap().len(), 3);
+        assert_eq!(grid.shortest_path((0, 0), (2, 2)).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_grid_ascii() {
+        let text = "..#.\n..#.\n....";
+        let grid = GridGraph::from_ascii(text, Connectivity::Four);
+        assert_eq!((grid.nb_rows(), grid.nb_cols()), (3, 4));
+        assert!(grid.is_obstacle((1, 2)));
+        assert!(!grid.is_obstacle((2, 2)));
+        assert_eq!(grid.render(&[]), text);
+        let path = grid.shortest_path((0, 0), (0, 3)).unwrap();
+        assert_eq!(path.len(), 8);
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&(0, 3)));
+        assert_eq!(grid.render(&path).matches('*').count(), 8);
+        assert_eq!(grid.shortest_path((0, 0), (0, 2)), None);
+        assert_eq!(grid.a_star((0, 2), (0, 0)), None);
+        assert_eq!(grid.shortest_path((1, 1), (1, 1)), Some(vec![(1, 1)]));
+    }
+
+    fn is_path(grid: &GridGraph, path: &[(usize, usize)]) -> bool {
+        path.windows(2)
+            .all(|cells| grid.neighbors(cells[0]).contains(&cells[1]))
+    }
+
+    #[test]
+    fn test_a_star() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for connectivity in [Connectivity::Four, Connectivity::Eight] {
+            for _ in 0..30 {
+                let (nb_rows, nb_cols) = (rng.gen_range(1..12), rng.gen_range(1..12));
+                let mut grid = GridGraph::init(nb_rows, nb_cols, connectivity);
+                for row in 0..nb_rows {
+                    for col in 0..nb_cols {
+                        grid.set_obstacle((row, col), rng.gen_bool(0.3));
+                    }
+                }
+                // the grid built cell by cell has the same edges
+                let copy = GridGraph::from_ascii(&grid.render(&[]), connectivity);
+                for v in 0..grid.nb_vertices() {
+                    let mut expected = grid.vertex_edges(&v);
+                    let mut edges = copy.vertex_edges(&v);
+                    expected.sort_unstable();
+                    edges.sort_unstable();
+                    assert_eq!(edges, expected);
+                }
+                for _ in 0..10 {
+                    let from = (rng.gen_range(0..nb_rows), rng.gen_range(0..nb_cols));
+                    let to = (rng.gen_range(0..nb_rows), rng.gen_range(0..nb_cols));
+                    let bfs = grid.shortest_path(from, to);
+                    let a_star = grid.a_star(from, to);
+                    assert_eq!(bfs.as_ref().map(Vec::len), a_star.as_ref().map(Vec::len));
+                    if let (Some(bfs), Some(a_star)) = (bfs, a_star) {
+                        assert!(is_path(&grid, &bfs) && is_path(&grid, &a_star));
+                        assert_eq!((a_star[0], a_star[a_star.len() - 1]), (from, to));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_generate_maze() {
+        for algorithm in [MazeAlgo::DepthFirst, MazeAlgo::Kruskal, MazeAlgo::Prim] {
+            for (nb_rows, nb_cols) in [(1, 1), (1, 6), (5, 1), (4, 7), (10, 10)] {
+                let maze = generate_maze(nb_rows, nb_cols, algorithm, 3);
+                assert_eq!(maze.nb_rows(), 2 * nb_rows + 1);
+                assert_eq!(maze.nb_cols(), 2 * nb_cols + 1);
+                // a perfect maze is a tree on the free cells
+                let nb_cells = nb_rows * nb_cols;
+                let free = (0..maze.nb_vertices())
+                    .filter(|v| !maze.is_obstacle(maze.cell(*v)))
+                    .count();
+                assert_eq!(free, 2 * nb_cells - 1);
+                let nb_edges = (0..maze.nb_vertices())
+                    .map(|v| maze.vertex_edges(&v).len())
+                    .sum::<usize>()
+                    / 2;
+                assert_eq!(nb_edges, free - 1);
+                let mut bfs = BreadthFirstSearch::init(maze.nb_vertices(), maze.vertex((1, 1)));
+                bfs.find_paths(&maze);
+                let reached = (0..maze.nb_vertices())
+                    .filter(|v| bfs.path_to(*v).is_some())
+                    .count();
+                assert_eq!(reached, free);
+                let last = (2 * nb_rows - 1, 2 * nb_cols - 1);
+                let path = maze.shortest_path((1, 1), last).unwrap();
+                assert_eq!(maze.a_star((1, 1), last), Some(path.clone()));
+                let drawing = maze.render(&path);
+                assert_eq!(drawing.matches('*').count(), path.len());
+                assert_eq!(drawing.lines().next().unwrap(), "#".repeat(2 * nb_cols + 1));
+            }
+            // the seed gives the maze
+            let maze = generate_maze(6, 6, algorithm, 9).render(&[]);
+            assert_eq!(maze, generate_maze(6, 6, algorithm, 9).render(&[]));
+        }
+        assert_eq!(
+            generate_maze(0, 3, MazeAlgo::Prim, 0).render(&[]),
+            "#######"
+        );
+    }
+}
@@ -0,0 +1,295 @@
+mod centrality;
+mod distance;
+#[cfg(test)]
+mod unit_test;
+use crate::graph::{DirectedGraph, UndirectedGraph, VertexInfo};
+pub use centrality::{
+    betweenness_centrality, closeness_centrality, eigenvector_centrality, harmonic_centrality,
+    katz_centrality, weighted_betweenness_centrality, weighted_closeness_centrality,
+    weighted_harmonic_centrality, SourceSampling,
+};
+pub use distance::{
+    double_sweep_lower_bound, eccentricities, ifub_diameter, weighted_eccentricities,
+    Eccentricities,
+};
+
+/// This trait gives the degree information needed to compute
+/// structural metrics of a graph
+pub trait DegreeInfo: VertexInfo {
+    /// Whether or not the edges are oriented
+    fn is_directed(&self) -> bool;
+    /// Gives the number of edges pointing to a vertex
+    fn in_degree(&self, v: &usize) -> usize;
+    /// Gives the number of edges going out of a vertex
+    fn out_degree(&self, v: &usize) -> usize;
+}
+
+impl<V, E> DegreeInfo for UndirectedGraph<V, E> {
+    fn is_directed(&self) -> bool {
+        false
+    }
+    fn in_degree(&self, v: &usize) -> usize {
+        self.degree(v)
+    }
+    fn out_degree(&self, v: &usize) -> usize {
+        self.degree(v)
+    }
+}
+
+impl<V, E> DegreeInfo for DirectedGraph<V, E> {
+    fn is_directed(&self) -> bool {
+        true
+    }
+    fn in_degree(&self, v: &usize) -> usize {
+        self.in_degree(v)
+    }
+    fn out_degree(&self, v: &usize) -> usize {
+        self.out_degree(v)
+    }
+}
+
+fn simple_adjacency<G: VertexInfo>(graph: &G) -> Vec<Vec<usize>> {
+    // sorted adjacency lists of the underlying undirected graph,
+    // without self loops nor parallel edges
+    let nb = graph.nb_vertices();
+    let mut adjacency = vec![Vec::new(); nb];
+    for v in 0..nb {
+        for w in graph.vertex_edges(&v) {
+            if *w != v {
+                adjacency[v].push(*w);
+                adjacency[*w].push(v);
+            }
+        }
+    }
+    for adj in adjacency.iter_mut() {
+        adj.sort_unstable();
+        adj.dedup();
+    }
+    adjacency
+}
+
+fn nb_common(a: &[usize], b: &[usize]) -> usize {
+    // number of common elements of two sorted slices
+    let (mut i, mut j, mut count) = (0, 0, 0);
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+            std::cmp::Ordering::Equal => {
+                count += 1;
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    count
+}
+
+/// Gives the number of triangles each vertex belongs to,
+/// edge orientations being ignored
+pub fn vertex_triangles<G: VertexInfo>(graph: &G) -> Vec<usize> {
+    // run time complexity O(sum over the edges of the degrees of their end points)
+    let adjacency = simple_adjacency(graph);
+    let mut triangles = vec![0; adjacency.len()];
+    for (v, adj) in adjacency.iter().enumerate() {
+        for w in adj.iter().filter(|w| **w > v) {
+            // each triangle v < w < u is found once, from its edge v - w
+            let larger = adjacency[*w].partition_point(|u| *u <= *w);
+            for u in adj.iter().filter(|u| **u > *w) {
+                if adjacency[*w][larger..].binary_search(u).is_ok() {
+                    triangles[v] += 1;
+                    triangles[*w] += 1;
+                    triangles[*u] += 1;
+                }
+            }
+        }
+    }
+    triangles
+}
+
+/// Gives the number of triangles of a graph, edge orientations being ignored
+pub fn nb_triangles<G: VertexInfo>(graph: &G) -> usize {
+    vertex_triangles(graph).iter().sum::<usize>() / 3
+}
+
+/// Gives the local clustering coefficient of each vertex, that is the
+/// proportion of pairs of its neighbors which are linked, edge orientations
+/// being ignored. It is 0 for vertices with less than two neighbors.
+pub fn local_clustering<G: VertexInfo>(graph: &G) -> Vec<f64> {
+    let adjacency = simple_adjacency(graph);
+    adjacency
+        .iter()
+        .map(|adj| {
+            let degree = adj.len() as f64;
+            if adj.len() < 2 {
+                return 0.0;
+            }
+            let links: usize = adj.iter().map(|w| nb_common(adj, &adjacency[*w])).sum();
+            // each link between two neighbors is counted twice
+            links as f64 / (degree * (degree - 1.0))
+        })
+        .collect()
+}
+
+/// Gives the average of the local clustering coefficients of the vertices
+pub fn average_clustering<G: VertexInfo>(graph: &G) -> f64 {
+    let clustering = local_clustering(graph);
+    if clustering.is_empty() {
+        return 0.0;
+    }
+    clustering.iter().sum::<f64>() / clustering.len() as f64
+}
+
+/// Gives the global clustering coefficient (transitivity) of a graph, that is
+/// the proportion of paths of length two which are closed by an edge
+pub fn global_clustering<G: VertexInfo>(graph: &G) -> f64 {
+    let triangles = nb_triangles(graph);
+    let triads: usize = simple_adjacency(graph)
+        .iter()
+        .map(|adj| adj.len() * adj.len().saturating_sub(1) / 2)
+        .sum();
+    if triads == 0 {
+        return 0.0;
+    }
+    3.0 * triangles as f64 / triads as f64
+}
+
+/// Gives the number of vertices of each out degree (degree for undirected
+/// graphs), the histogram having length the maximum degree plus one
+pub fn degree_histogram<G: DegreeInfo>(graph: &G) -> Vec<usize> {
+    histogram((0..graph.nb_vertices()).map(|v| graph.out_degree(&v)))
+}
+
+/// Gives the number of vertices of each in degree (degree for undirected
+/// graphs), the histogram having length the maximum degree plus one
+pub fn in_degree_histogram<G: DegreeInfo>(graph: &G) -> Vec<usize> {
+    histogram((0..graph.nb_vertices()).map(|v| graph.in_degree(&v)))
+}
+
+fn histogram(degrees: impl Iterator<Item = usize>) -> Vec<usize> {
+    let mut histogram = Vec::new();
+    for degree in degrees {
+        if histogram.len() <= degree {
+            histogram.resize(degree + 1, 0);
+        }
+        histogram[degree] += 1;
+    }
+    histogram
+}
+
+/// Gives the average out degree (degree for undirected graphs) of the vertices
+pub fn mean_degree<G: DegreeInfo>(graph: &G) -> f64 {
+    let nb = graph.nb_vertices();
+    if nb == 0 {
+        return 0.0;
+    }
+    let total: usize = (0..nb).map(|v| graph.out_degree(&v)).sum();
+    total as f64 / nb as f64
+}
+
+/// Gives the proportion of pairs of distinct vertices which are linked by an
+/// edge (ordered pairs for directed graphs), self loops being ignored
+pub fn density<G: DegreeInfo>(graph: &G) -> f64 {
+    let nb = graph.nb_vertices();
+    if nb < 2 {
+        return 0.0;
+    }
+    let links: usize = (0..nb)
+        .map(|v| graph.vertex_edges(&v).iter().filter(|w| ***w != v).count())
+        .sum();
+    // undirected edges are seen from both of their end points
+    links as f64 / (nb * (nb - 1)) as f64
+}
+
+/// Gives the degree assortativity of a graph, that is the Pearson correlation
+/// between the degrees of the end points of the edges. For directed graphs,
+/// the out degree of the origins is correlated to the in degree of the
+/// destinations. Returns `None` when a degree is constant over the edges.
+pub fn degree_assortativity<G: DegreeInfo>(graph: &G) -> Option<f64> {
+    let (mut nb, mut sum_x, mut sum_y) = (0.0, 0.0, 0.0);
+    let (mut sum_xx, mut sum_yy, mut sum_xy) = (0.0, 0.0, 0.0);
+    for v in 0..graph.nb_vertices() {
+        let x = graph.out_degree(&v) as f64;
+        for w in graph.vertex_edges(&v) {
+            let y = graph.in_degree(w) as f64;
+            nb += 1.0;
+            sum_x += x;
+            sum_y += y;
+            sum_xx += x * x;
+            sum_yy += y * y;
+            sum_xy += x * y;
+        }
+    }
+    let cov = sum_xy / nb - (sum_x / nb) * (sum_y / nb);
+    let var_x = sum_xx / nb - (sum_x / nb).powi(2);
+    let var_y = sum_yy / nb - (sum_y / nb).powi(2);
+    if nb == 0.0 || var_x <= f64::EPSILON || var_y <= f64::EPSILON {
+        return None;
+    }
+    Some(cov / (var_x * var_y).sqrt())
+}
+
+/// Gives the core number of each vertex, that is the largest k such that the
+/// vertex belongs to the k-core of the graph (its maximal subgraph where all
+/// the vertices have degree at least k), edge orientations being ignored
+pub fn core_numbers<G: VertexInfo>(graph: &G) -> Vec<usize> {
+    // Batagelj and Zaversnik algorithm
+    // run time complexity O(V + E)
+    let adjacency = simple_adjacency(graph);
+    let nb = adjacency.len();
+    let mut degree = adjacency
+        .iter()
+        .map(|adj| adj.len())
+        .collect::<Vec<usize>>();
+    let max_degree = degree.iter().max().map_or(0, |d| *d);
+    // vertices sorted by degree, with the starting position of each degree
+    let mut start = vec![0; max_degree + 2];
+    for d in &degree {
+        start[*d + 1] += 1;
+    }
+    for d in 1..start.len() {
+        start[d] += start[d - 1];
+    }
+    let mut vertices = vec![0; nb];
+    let mut position = vec![0; nb];
+    let mut next = start.clone();
+    for v in 0..nb {
+        position[v] = next[degree[v]];
+        vertices[position[v]] = v;
+        next[degree[v]] += 1;
+    }
+    for i in 0..nb {
+        let v = vertices[i];
+        for w in &adjacency[v] {
+            if degree[*w] > degree[v] {
+                // moves w to the first position of its degree block,
+                // then shrinks the block
+                let dw = degree[*w];
+                let first = start[dw];
+                let u = vertices[first];
+                if u != *w {
+                    vertices.swap(position[u], position[*w]);
+                    position.swap(u, *w);
+                }
+                start[dw] += 1;
+                degree[*w] -= 1;
+            }
+        }
+    }
+    degree
+}
+
+/// Gives the vertices of the k-core of a graph, in increasing order
+pub fn k_core<G: VertexInfo>(graph: &G, k: usize) -> Vec<usize> {
+    core_numbers(graph)
+        .iter()
+        .enumerate()
+        .filter(|(_, core)| **core >= k)
+        .map(|(v, _)| v)
+        .collect()
+}
+
+/// Gives the largest k for which the k-core of a graph is not empty
+pub fn degeneracy<G: VertexInfo>(graph: &G) -> usize {
+    core_numbers(graph).into_iter().max().unwrap_or(0)
+}
@@ -0,0 +1,359 @@
+use crate::graph::metrics::DegreeInfo;
+use crate::graph::processing::{ShortestPath, ShortestPathAlgo};
+use crate::graph::{EdgeWeightedDigraph, ToF64, VertexInfo, Weight};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+
+/// Enumeration of the ways to choose the source vertices
+/// from which the shortest paths are computed
+pub enum SourceSampling {
+    /// All the vertices are sources, giving exact centralities
+    All,
+    /// Sources are drawn uniformly without replacement, giving an unbiased
+    /// estimation of the centralities for large graphs
+    Random { nb_sources: usize, seed: u64 },
+}
+impl SourceSampling {
+    fn sources(&self, nb_vertices: usize) -> Vec<usize> {
+        match self {
+            Self::All => (0..nb_vertices).collect(),
+            Self::Random { nb_sources, seed } => {
+                let mut rng = StdRng::seed_from_u64(*seed);
+                let amount = std::cmp::min(*nb_sources, nb_vertices);
+                rand::seq::index::sample(&mut rng, nb_vertices, amount).into_vec()
+            }
+        }
+    }
+}
+
+fn accumulate(
+    betweenness: &mut [f64],
+    source: usize,
+    mut order: Vec<usize>,
+    predecessors: &[Vec<usize>],
+    sigma: &[f64],
+) {
+    // accumulates the dependencies of the source on the other vertices,
+    // order being the vertices by non decreasing distance from the source
+    let mut delta = vec![0.0; betweenness.len()];
+    while let Some(w) = order.pop() {
+        for v in &predecessors[w] {
+            delta[*v] += sigma[*v] / sigma[w] * (1.0 + delta[w]);
+        }
+        if w != source {
+            betweenness[w] += delta[w];
+        }
+    }
+}
+
+fn rescale(betweenness: &mut [f64], nb_sources: usize, normalized: bool, is_directed: bool) {
+    let nb = betweenness.len() as f64;
+    // extrapolation of the sampled sources to all the vertices
+    let mut scale = if nb_sources > 0 {
+        nb / nb_sources as f64
+    } else {
+        0.0
+    };
+    if !is_directed {
+        // each shortest path is found from both of its end points
+        scale /= 2.0;
+    }
+    if normalized {
+        scale = if nb > 2.0 {
+            scale / ((nb - 1.0) * (nb - 2.0))
+        } else {
+            0.0
+        };
+        if !is_directed {
+            scale *= 2.0;
+        }
+    }
+    for b in betweenness.iter_mut() {
+        *b *= scale;
+    }
+}
+
+/// Computes the betweenness centrality of each vertex with Brandes' algorithm,
+/// that is the sum over the pairs of other vertices of the proportion of
+/// shortest paths between them going through the vertex. When normalized,
+/// the centralities are divided by the number of pairs of other vertices.
+/// # Example
+/// ```
+/// use algods::graph::UndirectedGraph;
+/// use algods::graph::metrics::{betweenness_centrality, SourceSampling};
+/// let mut graph = UndirectedGraph::init(3);
+/// graph.add_edge(0, 1);
+/// graph.add_edge(1, 2);
+/// let betweenness = betweenness_centrality(&graph, SourceSampling::All, false);
+/// assert_eq!(betweenness, vec![0.0, 1.0, 0.0]);
+/// ```
+pub fn betweenness_centrality<G: DegreeInfo>(
+    graph: &G,
+    sampling: SourceSampling,
+    normalized: bool,
+) -> Vec<f64> {
+    // run time complexity O(S(V + E)) where S is the number of sources
+    let nb = graph.nb_vertices();
+    let mut betweenness = vec![0.0; nb];
+    let sources = sampling.sources(nb);
+    for source in &sources {
+        let mut order = Vec::with_capacity(nb);
+        let mut predecessors = vec![Vec::new(); nb];
+        let mut sigma = vec![0.0; nb];
+        let mut dist = vec![usize::MAX; nb];
+        sigma[*source] = 1.0;
+        dist[*source] = 0;
+        let mut queue = VecDeque::from([*source]);
+        while let Some(v) = queue.pop_front() {
+            order.push(v);
+            for w in graph.vertex_edges(&v) {
+                if dist[*w] == usize::MAX {
+                    dist[*w] = dist[v] + 1;
+                    queue.push_back(*w);
+                }
+                if dist[*w] == dist[v] + 1 {
+                    sigma[*w] += sigma[v];
+                    predecessors[*w].push(v);
+                }
+            }
+        }
+        accumulate(&mut betweenness, *source, order, &predecessors, &sigma);
+    }
+    rescale(
+        &mut betweenness,
+        sources.len(),
+        normalized,
+        graph.is_directed(),
+    );
+    betweenness
+}
+
+/// Computes the betweenness centrality of each vertex of an edge weighted
+/// directed graph with positive weights, using Brandes' algorithm
+/// on top of Dijkstra's algorithm
+pub fn weighted_betweenness_centrality<T: Weight + ToF64>(
+    graph: &EdgeWeightedDigraph<T>,
+    sampling: SourceSampling,
+    normalized: bool,
+) -> Vec<f64> {
+    // run time complexity O(S(E + V log(V))) where S is the number of sources
+    let nb = graph.nb_vertices();
+    let mut betweenness = vec![0.0; nb];
+    let sources = sampling.sources(nb);
+    for source in &sources {
+        let mut order = Vec::with_capacity(nb);
+        let mut predecessors = vec![Vec::new(); nb];
+        let mut sigma = vec![0.0; nb];
+        let mut dist = vec![None; nb];
+        let mut settled = vec![false; nb];
+        sigma[*source] = 1.0;
+        dist[*source] = Some(T::zero());
+        let mut priority_queue = BinaryHeap::from([Reverse((T::zero(), *source))]);
+        while let Some(Reverse((distance, v))) = priority_queue.pop() {
+            if settled[v] {
+                continue;
+            }
+            settled[v] = true;
+            order.push(v);
+            for (w, weight) in graph.vertex_edges(&v) {
+                let candidate = distance + *weight;
+                match dist[*w] {
+                    Some(d) if candidate > d => {}
+                    Some(d) if candidate == d => {
+                        sigma[*w] += sigma[v];
+                        predecessors[*w].push(v);
+                    }
+                    _ => {
+                        dist[*w] = Some(candidate);
+                        sigma[*w] = sigma[v];
+                        predecessors[*w] = vec![v];
+                        priority_queue.push(Reverse((candidate, *w)));
+                    }
+                }
+            }
+        }
+        accumulate(&mut betweenness, *source, order, &predecessors, &sigma);
+    }
+    rescale(&mut betweenness, sources.len(), normalized, true);
+    betweenness
+}
+
+fn bfs_distances<G: VertexInfo>(graph: &G, source: usize) -> Vec<Option<usize>> {
+    let mut dist = vec![None; graph.nb_vertices()];
+    dist[source] = Some(0);
+    let mut queue = VecDeque::from([source]);
+    while let Some(v) = queue.pop_front() {
+        let next = dist[v].map(|d| d + 1);
+        for w in graph.vertex_edges(&v) {
+            if dist[*w].is_none() {
+                dist[*w] = next;
+                queue.push_back(*w);
+            }
+        }
+    }
+    dist
+}
+
+fn dijkstra_distances<T: Weight + ToF64>(
+    graph: &EdgeWeightedDigraph<T>,
+    source: usize,
+) -> Vec<Option<f64>> {
+    let nb = graph.nb_vertices();
+    let mut sp = ShortestPath::init(source, ShortestPathAlgo::Dijkstra, nb);
+    sp.find_paths(graph);
+    (0..nb)
+        .map(|v| {
+            let d = sp.dist_to(v);
+            if *d == <T as Weight>::max() {
+                None
+            } else {
+                Some(d.to_f64())
+            }
+        })
+        .collect()
+}
+
+fn closeness(distances: &[Option<f64>]) -> f64 {
+    // Wasserman and Faust closeness, scaled by the proportion
+    // of the vertices reachable from the source
+    let nb = distances.len();
+    let (reached, total) = distances
+        .iter()
+        .flatten()
+        .fold((0, 0.0), |(r, t), d| (r + 1, t + d));
+    if reached <= 1 || total == 0.0 || nb <= 1 {
+        return 0.0;
+    }
+    let others = (reached - 1) as f64;
+    others / total * others / (nb - 1) as f64
+}
+
+fn harmonic(distances: &[Option<f64>]) -> f64 {
+    distances
+        .iter()
+        .flatten()
+        .filter(|d| **d > 0.0)
+        .map(|d| 1.0 / d)
+        .sum()
+}
+
+/// Computes the closeness centrality of each vertex, that is the inverse of the
+/// average distance to the vertices it reaches, scaled by the proportion of
+/// vertices it reaches (Wasserman and Faust) to handle disconnected graphs
+pub fn closeness_centrality<G: VertexInfo>(graph: &G) -> Vec<f64> {
+    // run time complexity O(V(V + E))
+    (0..graph.nb_vertices())
+        .map(|v| closeness(&to_float(bfs_distances(graph, v))))
+        .collect()
+}
+
+/// Computes the closeness centrality of each vertex of an edge weighted
+/// directed graph with positive weights, using the distances to the vertices
+pub fn weighted_closeness_centrality<T: Weight + ToF64>(
+    graph: &EdgeWeightedDigraph<T>,
+) -> Vec<f64> {
+    (0..graph.nb_vertices())
+        .map(|v| closeness(&dijkstra_distances(graph, v)))
+        .collect()
+}
+
+/// Computes the harmonic centrality of each vertex, that is the sum of the
+/// inverses of the distances to the other vertices, unreachable vertices
+/// contributing zero
+pub fn harmonic_centrality<G: VertexInfo>(graph: &G) -> Vec<f64> {
+    (0..graph.nb_vertices())
+        .map(|v| harmonic(&to_float(bfs_distances(graph, v))))
+        .collect()
+}
+
+/// Computes the harmonic centrality of each vertex of an edge weighted
+/// directed graph with positive weights
+pub fn weighted_harmonic_centrality<T: Weight + ToF64>(graph: &EdgeWeightedDigraph<T>) -> Vec<f64> {
+    (0..graph.nb_vertices())
+        .map(|v| harmonic(&dijkstra_distances(graph, v)))
+        .collect()
+}
+
+fn to_float(distances: Vec<Option<usize>>) -> Vec<Option<f64>> {
+    distances.into_iter().map(|d| d.map(|d| d as f64)).collect()
+}
+
+fn power_iteration<G, F>(graph: &G, max_iter: usize, tolerance: f64, step: F) -> Option<Vec<f64>>
+where
+    G: VertexInfo,
+    F: Fn(&[f64], &mut [f64]),
+{
+    // iterates x <- step(x) until the L1 change is below nb_vertices * tolerance
+    let nb = graph.nb_vertices();
+    if nb == 0 {
+        return Some(Vec::new());
+    }
+    let mut x = vec![1.0 / nb as f64; nb];
+    for _ in 0..max_iter {
+        let mut next = vec![0.0; nb];
+        for (v, x_v) in x.iter().enumerate() {
+            for w in graph.vertex_edges(&v) {
+                next[*w] += x_v;
+            }
+        }
+        step(&x, &mut next);
+        let change: f64 = x.iter().zip(next.iter()).map(|(a, b)| (a - b).abs()).sum();
+        x = next;
+        if change < nb as f64 * tolerance {
+            return Some(x);
+        }
+    }
+    None
+}
+
+/// Computes the eigenvector centrality of each vertex by power iteration, the
+/// centrality of a vertex being proportional to the sum of the centralities of
+/// the vertices pointing to it. The result has unit euclidean norm, `None`
+/// is returned if the iteration does not converge in `max_iter` steps.
+pub fn eigenvector_centrality<G: VertexInfo>(
+    graph: &G,
+    max_iter: usize,
+    tolerance: f64,
+) -> Option<Vec<f64>> {
+    // iterates with A + I instead of A, which has the same eigenvectors
+    // but avoids oscillations on bipartite graphs
+    power_iteration(graph, max_iter, tolerance, |x, next| {
+        for (n, a) in next.iter_mut().zip(x.iter()) {
+            *n += a;
+        }
+        let norm = next.iter().map(|n| n * n).sum::<f64>().sqrt();
+        if norm > 0.0 {
+            for n in next.iter_mut() {
+                *n /= norm;
+            }
+        }
+    })
+}
+
+/// Computes the Katz centrality of each vertex, solution of
+/// x = alpha * A^T x + beta by fixed point iteration, where alpha should be
+/// smaller than the inverse of the largest eigenvalue of the adjacency matrix.
+/// The result has unit euclidean norm, `None` is returned if the iteration
+/// does not converge in `max_iter` steps.
+pub fn katz_centrality<G: VertexInfo>(
+    graph: &G,
+    alpha: f64,
+    beta: f64,
+    max_iter: usize,
+    tolerance: f64,
+) -> Option<Vec<f64>> {
+    let mut x = power_iteration(graph, max_iter, tolerance, |_, next| {
+        for n in next.iter_mut() {
+            *n = alpha * *n + beta;
+        }
+    })?;
+    let norm = x.iter().map(|a| a * a).sum::<f64>().sqrt();
+    if norm > 0.0 {
+        for a in x.iter_mut() {
+            *a /= norm;
+        }
+    }
+    Some(x)
+}
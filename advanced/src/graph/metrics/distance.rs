@@ -0,0 +1,248 @@
+use crate::graph::processing::{ShortestPath, ShortestPathAlgo};
+use crate::graph::{EdgeWeightedDigraph, VertexInfo, Weight};
+use std::collections::VecDeque;
+
+/// Eccentricity of each vertex of a graph, that is the largest distance from
+/// the vertex to another vertex, `None` when some vertex cannot be reached,
+/// from which the diameter, radius, center and periphery are derived
+#[derive(Debug)]
+pub struct Eccentricities<T> {
+    eccentricity: Vec<Option<T>>,
+}
+impl<T: Copy + Ord> Eccentricities<T> {
+    /// Gives the eccentricity of a vertex
+    pub fn eccentricity(&self, v: usize) -> Option<T> {
+        self.eccentricity[v]
+    }
+    pub fn eccentricities(&self) -> &[Option<T>] {
+        &self.eccentricity
+    }
+    /// Gives the largest eccentricity, `None` when a vertex
+    /// cannot be reached from another one or without vertices
+    pub fn diameter(&self) -> Option<T> {
+        largest(&self.eccentricity)
+    }
+    /// Gives the smallest eccentricity, `None` when a vertex
+    /// cannot be reached from another one or without vertices
+    pub fn radius(&self) -> Option<T> {
+        self.diameter()?;
+        self.eccentricity.iter().copied().min().flatten()
+    }
+    /// Gives the vertices whose eccentricity is the radius, sorted
+    pub fn center(&self) -> Vec<usize> {
+        self.vertices_at(self.radius())
+    }
+    /// Gives the vertices whose eccentricity is the diameter, sorted
+    pub fn periphery(&self) -> Vec<usize> {
+        self.vertices_at(self.diameter())
+    }
+    fn vertices_at(&self, eccentricity: Option<T>) -> Vec<usize> {
+        if eccentricity.is_none() {
+            return Vec::new();
+        }
+        (0..self.eccentricity.len())
+            .filter(|v| self.eccentricity[*v] == eccentricity)
+            .collect()
+    }
+}
+
+fn bfs_levels<G: VertexInfo>(graph: &G, source: usize) -> Vec<Option<usize>> {
+    // number of edges from the source to each vertex
+    let mut dist = vec![None; graph.nb_vertices()];
+    dist[source] = Some(0);
+    let mut queue = VecDeque::from([source]);
+    while let Some(v) = queue.pop_front() {
+        let next = dist[v].map(|d| d + 1);
+        for w in graph.vertex_edges(&v) {
+            if dist[*w].is_none() {
+                dist[*w] = next;
+                queue.push_back(*w);
+            }
+        }
+    }
+    dist
+}
+
+fn largest<T: Copy + Ord>(distances: &[Option<T>]) -> Option<T> {
+    // the largest distance, None when a vertex is not reached
+    distances
+        .iter()
+        .copied()
+        .try_fold(None, |m, d| d.map(|d| std::cmp::max(m, Some(d))))?
+}
+
+fn in_parallel<T, F>(nb_vertices: usize, nb_threads: usize, eccentricity: F) -> Vec<Option<T>>
+where
+    T: Send,
+    F: Fn(usize) -> Option<T> + Sync,
+{
+    // computes the eccentricity of the vertices split in consecutive
+    // chunks, one per thread
+    assert!(nb_threads > 0, "at least one thread is needed");
+    let chunk_size = std::cmp::max(1, nb_vertices.div_ceil(nb_threads));
+    let eccentricity = &eccentricity;
+    std::thread::scope(|scope| {
+        let handles = (0..nb_vertices)
+            .step_by(chunk_size)
+            .map(|first| {
+                let last = std::cmp::min(first + chunk_size, nb_vertices);
+                scope.spawn(move || (first..last).map(eccentricity).collect::<Vec<Option<T>>>())
+            })
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("an eccentricity thread panicked"))
+            .collect()
+    })
+}
+
+/// Computes the eccentricity of each vertex, in number of edges, with a
+/// breadth first search from each vertex, the vertices being shared
+/// among `nb_threads` threads.
+/// # Example
+/// ```
+/// use algods::graph::UndirectedGraph;
+/// use algods::graph::metrics::eccentricities;
+/// let mut graph = UndirectedGraph::init(5);
+/// graph.add_edge(0, 1);
+/// graph.add_edge(1, 2);
+/// graph.add_edge(2, 3);
+/// graph.add_edge(1, 4);
+/// let result = eccentricities(&graph, 2);
+/// assert_eq!(result.eccentricities(), &[Some(3), Some(2), Some(2), Some(3), Some(3)]);
+/// assert_eq!((result.diameter(), result.radius()), (Some(3), Some(2)));
+/// assert_eq!(result.center(), vec![1, 2]);
+/// assert_eq!(result.periphery(), vec![0, 3, 4]);
+/// ```
+pub fn eccentricities<G: VertexInfo + Sync>(graph: &G, nb_threads: usize) -> Eccentricities<usize> {
+    // run time complexity O(V(V + E)), divided by the number of threads
+    let eccentricity = in_parallel(graph.nb_vertices(), nb_threads, |v| {
+        largest(&bfs_levels(graph, v))
+    });
+    Eccentricities { eccentricity }
+}
+
+/// Computes the eccentricity of each vertex of an edge weighted directed
+/// graph with nonnegative weights, in total weight, with the Dijkstra
+/// algorithm from each vertex, the vertices being shared among
+/// `nb_threads` threads.
+/// # Example
+/// ```
+/// use algods::graph::EdgeWeightedDigraph;
+/// use algods::graph::metrics::weighted_eccentricities;
+/// let mut graph = EdgeWeightedDigraph::init(3);
+/// graph.add_edge(0, 1, 4);
+/// graph.add_edge(1, 2, 1);
+/// graph.add_edge(2, 0, 2);
+/// let result = weighted_eccentricities(&graph, 3);
+/// assert_eq!(result.eccentricities(), &[Some(5), Some(3), Some(6)]);
+/// assert_eq!(result.center(), vec![1]);
+/// assert_eq!(result.periphery(), vec![2]);
+/// graph.add_vertex();
+/// assert_eq!(weighted_eccentricities(&graph, 3).diameter(), None);
+/// ```
+pub fn weighted_eccentricities<T>(
+    graph: &EdgeWeightedDigraph<T>,
+    nb_threads: usize,
+) -> Eccentricities<T>
+where
+    T: Weight + Send + Sync,
+{
+    // run time complexity O(VE log(V)), divided by the number of threads
+    let nb = graph.nb_vertices();
+    let eccentricity = in_parallel(nb, nb_threads, |v| {
+        let mut sp = ShortestPath::init(v, ShortestPathAlgo::Dijkstra, nb);
+        sp.find_paths(graph);
+        let distances = (0..nb)
+            .map(|w| {
+                let d = *sp.dist_to(w);
+                (d != <T as Weight>::max()).then_some(d)
+            })
+            .collect::<Vec<Option<T>>>();
+        largest(&distances)
+    });
+    Eccentricities { eccentricity }
+}
+
+fn farthest<G: VertexInfo>(graph: &G, source: usize) -> (usize, Vec<Option<usize>>) {
+    // a vertex reached last by a breadth first search, with the levels
+    let levels = bfs_levels(graph, source);
+    let far = (0..levels.len())
+        .max_by_key(|v| levels[*v])
+        .expect("the source is a vertex");
+    (far, levels)
+}
+
+/// Function that gives a lower bound of the diameter of the connected
+/// component of a vertex in an undirected graph with two breadth first
+/// searches (double sweep): the eccentricity of a vertex farthest from
+/// the start, which is often the diameter itself.
+pub fn double_sweep_lower_bound<G: VertexInfo>(graph: &G, start: usize) -> usize {
+    // run time complexity O(V + E)
+    let (first, _) = farthest(graph, start);
+    let (last, levels) = farthest(graph, first);
+    levels[last].expect("the last vertex is reached")
+}
+
+/// Function that computes the diameter of an undirected graph with the
+/// iFUB algorithm (iterative fringe upper bound), `None` when the graph is
+/// not connected or has no vertex. The breadth first search starts from
+/// the middle of a double sweep path, and the eccentricities of the vertices
+/// are computed from the farthest ones until the lower bound they give
+/// reaches the upper bound twice the remaining distance, which takes a few
+/// searches on many large real world graphs, and V searches in the worst case.
+/// # Example
+/// ```
+/// use algods::graph::UndirectedGraph;
+/// use algods::graph::metrics::{double_sweep_lower_bound, ifub_diameter};
+/// let mut graph = UndirectedGraph::init(6);
+/// for v in 0..5 {
+///     graph.add_edge(v, v + 1);
+/// }
+/// graph.add_edge(1, 4);
+/// assert_eq!(double_sweep_lower_bound(&graph, 2), 3);
+/// assert_eq!(ifub_diameter(&graph), Some(3));
+/// ```
+pub fn ifub_diameter<G: VertexInfo>(graph: &G) -> Option<usize> {
+    let nb = graph.nb_vertices();
+    if nb == 0 {
+        return None;
+    }
+    let (first, levels) = farthest(graph, 0);
+    if levels.iter().any(|d| d.is_none()) {
+        return None;
+    }
+    // the middle of the path between the ends of the double sweep
+    let (last, levels) = farthest(graph, first);
+    let mut lower = levels[last].expect("the graph is connected");
+    let middle = lower / 2;
+    let mut u = last;
+    while levels[u] != Some(middle) {
+        u = graph
+            .vertex_edges(&u)
+            .into_iter()
+            .copied()
+            .find(|w| levels[*w].is_some_and(|d| d + 1 == levels[u].unwrap_or(0)))
+            .expect("a vertex closer to the first end");
+    }
+    let levels = bfs_levels(graph, u);
+    let mut i = largest(&levels).expect("the graph is connected");
+    lower = std::cmp::max(lower, i);
+    let mut fringes = vec![Vec::new(); i + 1];
+    for v in 0..nb {
+        fringes[levels[v].expect("the graph is connected")].push(v);
+    }
+    // the vertices farther than i from u are at most 2i apart
+    let mut upper = 2 * i;
+    while upper > lower && i > 0 {
+        let fringe = fringes[i]
+            .iter()
+            .map(|v| largest(&bfs_levels(graph, *v)).expect("the graph is connected"))
+            .max()
+            .unwrap_or(0);
+        lower = std::cmp::max(lower, fringe);
+        upper = 2 * (i - 1);
+        i -= 1;
+    }
+    Some(lower)
+}
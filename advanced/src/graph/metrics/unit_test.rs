@@ -0,0 +1,394 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{
+        average_clustering, betweenness_centrality, closeness_centrality, core_numbers, degeneracy,
+        degree_assortativity, degree_histogram, density, double_sweep_lower_bound, eccentricities,
+        eigenvector_centrality, global_clustering, harmonic_centrality, ifub_diameter,
+        in_degree_histogram, k_core, katz_centrality, local_clustering, mean_degree, nb_triangles,
+        vertex_triangles, weighted_betweenness_centrality, weighted_closeness_centrality,
+        weighted_eccentricities, weighted_harmonic_centrality, SourceSampling,
+    };
+    use crate::graph::{DirectedGraph, EdgeWeightedDigraph, UndirectedGraph};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn assert_close(a: &[f64], b: &[f64]) {
+        assert_eq!(a.len(), b.len());
+        for (x, y) in a.iter().zip(b.iter()) {
+            assert!((x - y).abs() < 1e-9, "{a:?} != {b:?}");
+        }
+    }
+
+    fn path(n: usize) -> UndirectedGraph {
+        let mut graph = UndirectedGraph::init(n);
+        for i in 1..n {
+            graph.add_edge(i - 1, i);
+        }
+        graph
+    }
+
+    fn paw() -> UndirectedGraph {
+        // triangle 0 - 1 - 2 with a pendant vertex 3 linked to 2
+        let mut graph = UndirectedGraph::init(4);
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        graph.add_edge(2, 0);
+        graph.add_edge(2, 3);
+        graph
+    }
+
+    #[test]
+    fn test_triangles_and_clustering() {
+        let graph = paw();
+        assert_eq!(nb_triangles(&graph), 1);
+        assert_eq!(vertex_triangles(&graph), vec![1, 1, 1, 0]);
+        let clustering = local_clustering(&graph);
+        assert_eq!(clustering[0], 1.0);
+        assert!((clustering[2] - 1.0 / 3.0).abs() < 1e-12);
+        assert_eq!(clustering[3], 0.0);
+        assert!((average_clustering(&graph) - 7.0 / 12.0).abs() < 1e-12);
+        assert!((global_clustering(&graph) - 0.6).abs() < 1e-12);
+
+        let mut complete = UndirectedGraph::init(5);
+        for i in 0..5 {
+            for j in 0..i {
+                complete.add_edge(i, j);
+            }
+        }
+        assert_eq!(nb_triangles(&complete), 10);
+        assert_eq!(global_clustering(&complete), 1.0);
+
+        let mut cycle = DirectedGraph::init(3);
+        cycle.add_edge(0, 1);
+        cycle.add_edge(1, 2);
+        cycle.add_edge(2, 0);
+        cycle.add_edge(0, 2);
+        assert_eq!(nb_triangles(&cycle), 1);
+        assert_eq!(average_clustering(&cycle), 1.0);
+    }
+
+    #[test]
+    fn test_degrees() {
+        let graph = paw();
+        assert_eq!(degree_histogram(&graph), vec![0, 1, 2, 1]);
+        assert_eq!(mean_degree(&graph), 2.0);
+        assert!((density(&graph) - 2.0 / 3.0).abs() < 1e-12);
+
+        let mut graph = DirectedGraph::init(4);
+        graph.add_edge(0, 1);
+        graph.add_edge(0, 2);
+        graph.add_edge(0, 3);
+        graph.add_edge(1, 2);
+        assert_eq!(degree_histogram(&graph), vec![2, 1, 0, 1]);
+        assert_eq!(in_degree_histogram(&graph), vec![1, 2, 1]);
+        assert_eq!(mean_degree(&graph), 1.0);
+        assert_eq!(density(&graph), 4.0 / 12.0);
+        assert_eq!(density(&DirectedGraph::init(1)), 0.0);
+    }
+
+    #[test]
+    fn test_degree_assortativity() {
+        let mut star = UndirectedGraph::init(4);
+        star.add_edge(0, 1);
+        star.add_edge(0, 2);
+        star.add_edge(0, 3);
+        assert!((degree_assortativity(&star).unwrap() + 1.0).abs() < 1e-12);
+
+        let mut cycle = UndirectedGraph::init(4);
+        for i in 0..4 {
+            cycle.add_edge(i, (i + 1) % 4);
+        }
+        assert_eq!(degree_assortativity(&cycle), None);
+        assert_eq!(degree_assortativity(&UndirectedGraph::init(2)), None);
+        assert!(degree_assortativity(&paw()).unwrap() < 0.0);
+    }
+
+    #[test]
+    fn test_k_core() {
+        let mut graph = paw();
+        graph.add_vertex();
+        assert_eq!(core_numbers(&graph), vec![2, 2, 2, 1, 0]);
+        assert_eq!(k_core(&graph, 2), vec![0, 1, 2]);
+        assert_eq!(k_core(&graph, 1), vec![0, 1, 2, 3]);
+        assert_eq!(degeneracy(&graph), 2);
+
+        // two K4 linked by a path of length 2
+        let mut graph = UndirectedGraph::init(9);
+        for i in 0..4 {
+            for j in 0..i {
+                graph.add_edge(i, j);
+                graph.add_edge(i + 5, j + 5);
+            }
+        }
+        graph.add_edge(3, 4);
+        graph.add_edge(4, 5);
+        assert_eq!(core_numbers(&graph), vec![3, 3, 3, 3, 2, 3, 3, 3, 3]);
+        assert_eq!(degeneracy(&graph), 3);
+    }
+
+    #[test]
+    fn test_betweenness_centrality() {
+        let graph = path(5);
+        let betweenness = betweenness_centrality(&graph, SourceSampling::All, false);
+        assert_close(&betweenness, &[0.0, 3.0, 4.0, 3.0, 0.0]);
+        let betweenness = betweenness_centrality(&graph, SourceSampling::All, true);
+        assert_close(&betweenness, &[0.0, 0.5, 4.0 / 6.0, 0.5, 0.0]);
+        let sampled = SourceSampling::Random {
+            nb_sources: 5,
+            seed: 42,
+        };
+        assert_close(&betweenness_centrality(&graph, sampled, true), &betweenness);
+        let sampled = SourceSampling::Random {
+            nb_sources: 2,
+            seed: 42,
+        };
+        assert_eq!(betweenness_centrality(&graph, sampled, false).len(), 5);
+
+        // two shortest paths 0 -> 1 -> 3 and 0 -> 2 -> 3
+        let mut graph = DirectedGraph::init(4);
+        let mut weighted = EdgeWeightedDigraph::init(4);
+        for (v, w) in [(0, 1), (0, 2), (1, 3), (2, 3)] {
+            graph.add_edge(v, w);
+            weighted.add_edge(v, w, 1);
+        }
+        let expected = [0.0, 0.5, 0.5, 0.0];
+        assert_close(
+            &betweenness_centrality(&graph, SourceSampling::All, false),
+            &expected,
+        );
+        assert_close(
+            &weighted_betweenness_centrality(&weighted, SourceSampling::All, false),
+            &expected,
+        );
+        // the direct edge is shorter than the paths through 1 and 2
+        weighted.add_edge(0, 3, 1);
+        assert_close(
+            &weighted_betweenness_centrality(&weighted, SourceSampling::All, false),
+            &[0.0; 4],
+        );
+
+        // 0 -> 1 -> 2 is as short as 0 -> 2
+        let mut weighted = EdgeWeightedDigraph::init(3);
+        weighted.add_edge(0, 1, 1);
+        weighted.add_edge(1, 2, 1);
+        weighted.add_edge(0, 2, 2);
+        assert_close(
+            &weighted_betweenness_centrality(&weighted, SourceSampling::All, true),
+            &[0.0, 0.25, 0.0],
+        );
+    }
+
+    #[test]
+    fn test_closeness_and_harmonic_centrality() {
+        let graph = path(5);
+        assert_close(
+            &closeness_centrality(&graph),
+            &[0.4, 4.0 / 7.0, 4.0 / 6.0, 4.0 / 7.0, 0.4],
+        );
+        let harmonic = harmonic_centrality(&graph);
+        assert!((harmonic[0] - (1.0 + 1.0 / 2.0 + 1.0 / 3.0 + 1.0 / 4.0)).abs() < 1e-9);
+        assert!((harmonic[2] - 3.0).abs() < 1e-9);
+
+        let mut weighted = EdgeWeightedDigraph::init(3);
+        weighted.add_edge(0, 1, 2);
+        weighted.add_edge(1, 2, 2);
+        // 0 reaches 1 and 2 at distances 2 and 4, 1 reaches half of the graph
+        assert_close(
+            &weighted_closeness_centrality(&weighted),
+            &[2.0 / 6.0, 0.5 * 0.5, 0.0],
+        );
+        assert_close(&weighted_harmonic_centrality(&weighted), &[0.75, 0.5, 0.0]);
+    }
+
+    #[test]
+    fn test_eigenvector_and_katz_centrality() {
+        let mut star = UndirectedGraph::init(4);
+        star.add_edge(0, 1);
+        star.add_edge(0, 2);
+        star.add_edge(0, 3);
+        let centrality = eigenvector_centrality(&star, 1000, 1e-12).unwrap();
+        let leaf = 1.0 / 6.0_f64.sqrt();
+        assert_close(&centrality, &[0.5_f64.sqrt(), leaf, leaf, leaf]);
+        assert_eq!(eigenvector_centrality(&star, 1, 1e-12), None);
+
+        let katz = katz_centrality(&UndirectedGraph::init(4), 0.1, 1.0, 100, 1e-9).unwrap();
+        assert_close(&katz, &[0.5; 4]);
+        let katz = katz_centrality(&star, 0.1, 1.0, 1000, 1e-12).unwrap();
+        assert!(katz[0] > katz[1]);
+        assert!((katz[1] - katz[3]).abs() < 1e-9);
+        // alpha larger than the inverse of the spectral radius sqrt(3)
+        assert_eq!(katz_centrality(&star, 1.0, 1.0, 1000, 1e-9), None);
+    }
+
+    fn floyd_warshall(nb: usize, edges: &[(usize, usize, u64)]) -> Vec<Vec<Option<u64>>> {
+        let mut dist = vec![vec![None; nb]; nb];
+        for (v, row) in dist.iter_mut().enumerate() {
+            row[v] = Some(0);
+        }
+        for (u, v, w) in edges {
+            dist[*u][*v] = std::cmp::min(dist[*u][*v].or(Some(*w)), Some(*w));
+        }
+        for k in 0..nb {
+            for u in 0..nb {
+                for v in 0..nb {
+                    if let (Some(a), Some(b)) = (dist[u][k], dist[k][v]) {
+                        if dist[u][v].is_none_or(|d| a + b < d) {
+                            dist[u][v] = Some(a + b);
+                        }
+                    }
+                }
+            }
+        }
+        dist
+    }
+
+    fn brute_force_eccentricities(dist: &[Vec<Option<u64>>]) -> Vec<Option<u64>> {
+        dist.iter()
+            .map(|row| row.iter().try_fold(0, |m, d| d.map(|d| m.max(d))))
+            .collect()
+    }
+
+    #[test]
+    fn test_lollipop_eccentricities() {
+        // the cycle 0 - 1 - 2 - 3 - 4 - 0 with the tail 4 - 5 - 6
+        let mut graph = UndirectedGraph::init(7);
+        for (u, v) in [(0, 1), (1, 2), (2, 3), (3, 4), (4, 0), (4, 5), (5, 6)] {
+            graph.add_edge(u, v);
+        }
+        let result = eccentricities(&graph, 3);
+        let expected = [3, 4, 4, 3, 2, 3, 4].map(Some);
+        assert_eq!(result.eccentricities(), &expected);
+        assert_eq!((result.radius(), result.diameter()), (Some(2), Some(4)));
+        assert_eq!(result.center(), vec![4]);
+        assert_eq!(result.periphery(), vec![1, 2, 6]);
+        assert_eq!(ifub_diameter(&graph), Some(4));
+        assert_eq!(double_sweep_lower_bound(&graph, 4), 4);
+
+        // the shortcut 0 -> 2 is longer than the path through 1
+        let mut graph = EdgeWeightedDigraph::init(3);
+        graph.add_edge(0, 1, 2u32);
+        graph.add_edge(1, 2, 3);
+        graph.add_edge(2, 0, 1);
+        graph.add_edge(0, 2, 10);
+        let result = weighted_eccentricities(&graph, 2);
+        assert_eq!(result.eccentricities(), &[Some(5), Some(4), Some(3)]);
+        assert_eq!((result.center(), result.periphery()), (vec![2], vec![0]));
+    }
+
+    #[test]
+    fn test_eccentricities() {
+        let mut rng = StdRng::seed_from_u64(4);
+        for _ in 0..40 {
+            let nb = rng.gen_range(1..12);
+            let mut undirected = UndirectedGraph::init(nb);
+            let mut directed = DirectedGraph::init(nb);
+            let (mut both_ways, mut one_way) = (Vec::new(), Vec::new());
+            for _ in 0..rng.gen_range(0..3 * nb) {
+                let (u, v) = (rng.gen_range(0..nb), rng.gen_range(0..nb));
+                undirected.add_edge(u, v);
+                directed.add_edge(u, v);
+                both_ways.extend([(u, v, 1), (v, u, 1)]);
+                one_way.push((u, v, 1));
+            }
+            for (edges, result) in [
+                (both_ways, eccentricities(&undirected, 3)),
+                (one_way, eccentricities(&directed, 2)),
+            ] {
+                let expected = brute_force_eccentricities(&floyd_warshall(nb, &edges));
+                let found = result
+                    .eccentricities()
+                    .iter()
+                    .map(|e| e.map(|e| e as u64))
+                    .collect::<Vec<Option<u64>>>();
+                assert_eq!(found, expected);
+                let connected = expected.iter().all(|e| e.is_some());
+                let diameter = expected.iter().flatten().max().copied();
+                let radius = expected.iter().flatten().min().copied();
+                assert_eq!(
+                    result.diameter().map(|d| d as u64),
+                    diameter.filter(|_| connected)
+                );
+                assert_eq!(
+                    result.radius().map(|r| r as u64),
+                    radius.filter(|_| connected)
+                );
+                let center = (0..nb)
+                    .filter(|v| expected[*v] == radius)
+                    .collect::<Vec<_>>();
+                let periphery = (0..nb)
+                    .filter(|v| expected[*v] == diameter)
+                    .collect::<Vec<_>>();
+                if connected {
+                    assert_eq!(result.center(), center);
+                    assert_eq!(result.periphery(), periphery);
+                } else {
+                    assert!(result.center().is_empty() && result.periphery().is_empty());
+                }
+            }
+        }
+        // the result does not depend on the number of threads
+        let graph = path(9);
+        let expected = eccentricities(&graph, 1);
+        for nb_threads in [2, 4, 9, 20] {
+            let result = eccentricities(&graph, nb_threads);
+            assert_eq!(result.eccentricities(), expected.eccentricities());
+        }
+        assert_eq!(expected.center(), vec![4]);
+        assert_eq!(expected.periphery(), vec![0, 8]);
+        let empty = eccentricities(&UndirectedGraph::init(0), 2);
+        assert_eq!((empty.diameter(), empty.radius()), (None, None));
+    }
+
+    #[test]
+    fn test_weighted_eccentricities() {
+        let mut rng = StdRng::seed_from_u64(8);
+        for _ in 0..40 {
+            let nb = rng.gen_range(1..10);
+            let mut graph = EdgeWeightedDigraph::<u64>::init(nb);
+            let mut edges = Vec::new();
+            for _ in 0..rng.gen_range(0..4 * nb) {
+                let (u, v, w) = (
+                    rng.gen_range(0..nb),
+                    rng.gen_range(0..nb),
+                    rng.gen_range(0..20),
+                );
+                graph.add_edge(u, v, w);
+                edges.push((u, v, w));
+            }
+            let expected = brute_force_eccentricities(&floyd_warshall(nb, &edges));
+            for nb_threads in [1, 3] {
+                let result = weighted_eccentricities(&graph, nb_threads);
+                assert_eq!(result.eccentricities(), &expected[..]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ifub_diameter() {
+        let mut rng = StdRng::seed_from_u64(15);
+        for _ in 0..60 {
+            let nb = rng.gen_range(1..40);
+            // a random tree with a few more edges
+            let mut graph = UndirectedGraph::init(nb);
+            for v in 1..nb {
+                graph.add_edge(v, rng.gen_range(0..v));
+            }
+            for _ in 0..rng.gen_range(0..nb) {
+                graph.add_edge(rng.gen_range(0..nb), rng.gen_range(0..nb));
+            }
+            let diameter = eccentricities(&graph, 4).diameter();
+            assert_eq!(ifub_diameter(&graph), diameter);
+            let diameter = diameter.unwrap();
+            let start = rng.gen_range(0..nb);
+            let bound = double_sweep_lower_bound(&graph, start);
+            assert!(bound <= diameter && 2 * bound >= diameter);
+        }
+        assert_eq!(ifub_diameter(&path(10)), Some(9));
+        assert_eq!(double_sweep_lower_bound(&path(10), 4), 9);
+        let mut graph = path(5);
+        graph.add_vertex();
+        assert_eq!(ifub_diameter(&graph), None);
+        assert_eq!(double_sweep_lower_bound(&graph, 1), 4);
+        assert_eq!(ifub_diameter(&UndirectedGraph::init(0)), None);
+    }
+}
@@ -0,0 +1,328 @@
+#[cfg(test)]
+mod unit_test;
+use crate::graph::{
+    DirectedGraph, EdgeWeightedDigraph, EdgeWeightedGraph, UndirectedGraph, VertexInfo, Weight,
+};
+use std::collections::HashSet;
+use std::hash::Hash;
+
+// edges as (origin, destination, label) triples
+type LabeledEdges<L> = Vec<(usize, usize, L)>;
+
+/// This trait derives new graphs from existing ones. The edges are seen as
+/// (origin, destination, label) triples, the label being the weight for edge
+/// weighted graphs and the edge data otherwise, and the edges of undirected
+/// graphs are given once with origin <= destination. Two edges are the same
+/// when they have the same end points and the same label. The data of the
+/// vertices follows them in the derived graphs, except for the vertices of a
+/// cartesian product, which get the default data. The graphs with edge data
+/// have no parallel edges, so an edge of both graphs with different data
+/// appears once in their union, with the data of the second graph.
+/// # Example
+/// ```
+/// use algods::graph::{GraphOperations, UndirectedGraph};
+/// let mut graph = UndirectedGraph::init(4);
+/// graph.add_edge(0, 1);
+/// graph.add_edge(1, 2);
+/// graph.add_edge(2, 3);
+/// // vertex i of the subgraph is the vertex [1, 2, 3][i] of the graph
+/// let path = graph.induced_subgraph(&[1, 2, 3]);
+/// assert_eq!(path.edges(), vec![(0, 1, ()), (1, 2, ())]);
+/// assert_eq!(graph.complement().nb_edges(), 3);
+/// let (line, edges) = graph.line_graph();
+/// assert_eq!(line.nb_edges(), 2);
+/// assert_eq!(edges[0], (0, 1, ()));
+/// // the data of the vertices and edges is carried along
+/// let mut roads = UndirectedGraph::<&str, u32>::with_vertices(vec!["a", "b", "c"]);
+/// roads.add_edge_with(0, 1, 5);
+/// roads.add_edge_with(1, 2, 7);
+/// let sub = roads.induced_subgraph(&[2, 1]);
+/// assert_eq!(sub.vertex_data(&0), &"c");
+/// assert_eq!(sub.edges(), vec![(0, 1, 7)]);
+/// ```
+pub trait GraphOperations: Sized {
+    /// Label of the edges, the weight or the edge data
+    type Label: Clone + Eq + Hash;
+    /// Graph of the same orientation without labels
+    type Unlabeled: GraphOperations<Label = ()>;
+    /// Whether or not the edges are oriented
+    const DIRECTED: bool;
+    /// Gives the number of vertices
+    fn order(&self) -> usize;
+    /// Gives the edges, sorted by origin, destination then label
+    fn edges(&self) -> Vec<(usize, usize, Self::Label)>;
+    /// Builds a graph from its number of vertices and edges
+    fn from_edges(nb_vertices: usize, edges: &[(usize, usize, Self::Label)]) -> Self;
+    /// Gives to the vertex v the data of the vertex w of another graph,
+    /// nothing to do for graphs without vertex data
+    fn copy_vertex_data(&mut self, _v: usize, _other: &Self, _w: usize) {}
+
+    /// Gives the subgraph made of some (distinct) vertices and the edges
+    /// between them, the vertex i of the subgraph being vertices\[i\]
+    fn induced_subgraph(&self, vertices: &[usize]) -> Self {
+        let mut index = vec![None; self.order()];
+        for (i, v) in vertices.iter().enumerate() {
+            assert!(index[*v].is_none(), "vertex {v} is given twice");
+            index[*v] = Some(i);
+        }
+        let edges = self
+            .edges()
+            .into_iter()
+            .filter_map(|(u, v, label)| Some((index[u]?, index[v]?, label)))
+            .collect::<Vec<_>>();
+        let mut graph = Self::from_edges(vertices.len(), &edges);
+        for (i, v) in vertices.iter().enumerate() {
+            graph.copy_vertex_data(i, self, *v);
+        }
+        graph
+    }
+    /// Gives the graph with the same vertices and the edges
+    /// satisfying a predicate
+    fn filter_edges<F>(&self, keep: F) -> Self
+    where
+        F: Fn(usize, usize, &Self::Label) -> bool,
+    {
+        let mut edges = self.edges();
+        edges.retain(|(u, v, label)| keep(*u, *v, label));
+        same_vertices(Self::from_edges(self.order(), &edges), self)
+    }
+    /// Gives the graph with the edges of both graphs, on the vertices of
+    /// the largest one, the common vertices having the data of the first one
+    fn union(&self, other: &Self) -> Self {
+        let mut edges = self.edges();
+        edges.extend(other.edges());
+        let mut graph = Self::from_edges(std::cmp::max(self.order(), other.order()), &edges);
+        for v in self.order()..other.order() {
+            graph.copy_vertex_data(v, other, v);
+        }
+        same_vertices(graph, self)
+    }
+    /// Gives the graph with the vertices of the first graph
+    /// and the edges present in both graphs
+    fn intersection(&self, other: &Self) -> Self {
+        let others = other.edges().into_iter().collect::<HashSet<_>>();
+        let mut edges = self.edges();
+        edges.retain(|edge| others.contains(edge));
+        same_vertices(Self::from_edges(self.order(), &edges), self)
+    }
+    /// Gives the graph with the vertices of the first graph
+    /// and its edges which are not in the second one
+    fn difference(&self, other: &Self) -> Self {
+        let others = other.edges().into_iter().collect::<HashSet<_>>();
+        let mut edges = self.edges();
+        edges.retain(|edge| !others.contains(edge));
+        same_vertices(Self::from_edges(self.order(), &edges), self)
+    }
+    /// Gives the graph on the same vertices where two distinct vertices are
+    /// linked (with a given label) if and only if they are not in the graph
+    fn complement_with(&self, label: Self::Label) -> Self {
+        // run time complexity O(V^2)
+        let nb = self.order();
+        let linked = self
+            .edges()
+            .into_iter()
+            .map(|(u, v, _)| (u, v))
+            .collect::<HashSet<_>>();
+        let mut edges = Vec::new();
+        for u in 0..nb {
+            let first = if Self::DIRECTED { 0 } else { u + 1 };
+            for v in (first..nb).filter(|v| *v != u) {
+                if !linked.contains(&(u, v)) {
+                    edges.push((u, v, label.clone()));
+                }
+            }
+        }
+        same_vertices(Self::from_edges(nb, &edges), self)
+    }
+    /// Gives the line graph, whose vertex i is the i-th edge of `edges()`
+    /// (also returned). Two edges are linked when they share an end point,
+    /// or for directed graphs, when the first one ends where the second
+    /// one starts.
+    fn line_graph(&self) -> (Self::Unlabeled, LabeledEdges<Self::Label>) {
+        let edges = self.edges();
+        // edges incident to each vertex, leaving it for directed graphs
+        let mut incident = vec![Vec::new(); self.order()];
+        for (i, (u, v, _)) in edges.iter().enumerate() {
+            incident[*u].push(i);
+            if !Self::DIRECTED && u != v {
+                incident[*v].push(i);
+            }
+        }
+        let mut links = Vec::new();
+        if Self::DIRECTED {
+            for (i, (_, v, _)) in edges.iter().enumerate() {
+                links.extend(incident[*v].iter().map(|j| (i, *j, ())));
+            }
+        } else {
+            for adjacent in &incident {
+                for (k, i) in adjacent.iter().enumerate() {
+                    links.extend(adjacent[k + 1..].iter().map(|j| (*i, *j, ())));
+                }
+            }
+        }
+        (Self::Unlabeled::from_edges(edges.len(), &links), edges)
+    }
+    /// Gives the Cartesian product of two graphs, whose vertex
+    /// u * other.order() + v stands for the pair (u, v). Its edges link
+    /// (u, v) to (u', v) for each edge u - u' of the first graph,
+    /// and (u, v) to (u, v') for each edge v - v' of the second one.
+    fn cartesian_product(&self, other: &Self) -> Self {
+        let (n, m) = (self.order(), other.order());
+        let mut edges = Vec::new();
+        for (u, w, label) in self.edges() {
+            edges.extend((0..m).map(|v| (u * m + v, w * m + v, label.clone())));
+        }
+        for (v, w, label) in other.edges() {
+            edges.extend((0..n).map(|u| (u * m + v, u * m + w, label.clone())));
+        }
+        Self::from_edges(n * m, &edges)
+    }
+}
+
+fn same_vertices<G: GraphOperations>(mut graph: G, other: &G) -> G {
+    // the vertices keep the data they have in another graph
+    for v in 0..std::cmp::min(graph.order(), other.order()) {
+        graph.copy_vertex_data(v, other, v);
+    }
+    graph
+}
+
+fn sorted<L: Ord>(mut edges: Vec<(usize, usize, L)>) -> Vec<(usize, usize, L)> {
+    edges.sort_unstable();
+    edges
+}
+
+impl<V, E> GraphOperations for DirectedGraph<V, E>
+where
+    V: Clone + Default,
+    E: Clone + Ord + Hash,
+{
+    type Label = E;
+    type Unlabeled = DirectedGraph;
+    const DIRECTED: bool = true;
+    fn order(&self) -> usize {
+        self.nb_vertices()
+    }
+    fn edges(&self) -> Vec<(usize, usize, E)> {
+        sorted(
+            (0..self.nb_vertices())
+                .flat_map(|u| {
+                    self.vertex_edges(&u).into_iter().map(move |v| {
+                        let data = self.edge_data(&u, v).expect("v is an out neighbor");
+                        (u, *v, data.clone())
+                    })
+                })
+                .collect(),
+        )
+    }
+    fn from_edges(nb_vertices: usize, edges: &[(usize, usize, E)]) -> Self {
+        let mut graph = Self::with_vertices(vec![V::default(); nb_vertices]);
+        for (u, v, data) in edges {
+            graph.add_edge_with(*u, *v, data.clone());
+        }
+        graph
+    }
+    fn copy_vertex_data(&mut self, v: usize, other: &Self, w: usize) {
+        *self.vertex_data_mut(&v) = other.vertex_data(&w).clone();
+    }
+}
+impl<V: Clone + Default, E: Clone + Ord + Hash + Default> DirectedGraph<V, E> {
+    /// Gives the graph on the same vertices where an edge goes from a vertex
+    /// to another distinct one if and only if it is not in the graph,
+    /// with the default edge data
+    pub fn complement(&self) -> Self {
+        self.complement_with(E::default())
+    }
+}
+
+impl<V, E> GraphOperations for UndirectedGraph<V, E>
+where
+    V: Clone + Default,
+    E: Clone + Ord + Hash,
+{
+    type Label = E;
+    type Unlabeled = UndirectedGraph;
+    const DIRECTED: bool = false;
+    fn order(&self) -> usize {
+        self.nb_vertices()
+    }
+    fn edges(&self) -> Vec<(usize, usize, E)> {
+        sorted(
+            (0..self.nb_vertices())
+                .flat_map(|u| {
+                    VertexInfo::vertex_edges(self, &u)
+                        .into_iter()
+                        .filter(move |v| **v >= u)
+                        .map(move |v| {
+                            let data = self.edge_data(&u, v).expect("v is adjacent to u");
+                            (u, *v, data.clone())
+                        })
+                })
+                .collect(),
+        )
+    }
+    fn from_edges(nb_vertices: usize, edges: &[(usize, usize, E)]) -> Self {
+        let mut graph = Self::with_vertices(vec![V::default(); nb_vertices]);
+        for (u, v, data) in edges {
+            graph.add_edge_with(*u, *v, data.clone());
+        }
+        graph
+    }
+    fn copy_vertex_data(&mut self, v: usize, other: &Self, w: usize) {
+        *self.vertex_data_mut(&v) = other.vertex_data(&w).clone();
+    }
+}
+impl<V: Clone + Default, E: Clone + Ord + Hash + Default> UndirectedGraph<V, E> {
+    /// Gives the graph on the same vertices where two distinct vertices
+    /// are linked if and only if they are not linked in the graph,
+    /// with the default edge data
+    pub fn complement(&self) -> Self {
+        self.complement_with(E::default())
+    }
+}
+
+impl<T: Weight> GraphOperations for EdgeWeightedDigraph<T> {
+    type Label = T;
+    type Unlabeled = DirectedGraph;
+    const DIRECTED: bool = true;
+    fn order(&self) -> usize {
+        self.nb_vertices()
+    }
+    fn edges(&self) -> Vec<(usize, usize, T)> {
+        sorted(
+            (0..self.nb_vertices())
+                .flat_map(|u| {
+                    self.vertex_edges(&u)
+                        .into_iter()
+                        .map(move |(v, w)| (u, *v, *w))
+                })
+                .collect(),
+        )
+    }
+    fn from_edges(nb_vertices: usize, edges: &[(usize, usize, T)]) -> Self {
+        let mut graph = Self::init(nb_vertices);
+        for (u, v, w) in edges {
+            graph.add_edge(*u, *v, *w);
+        }
+        graph
+    }
+}
+
+impl<T: Weight> GraphOperations for EdgeWeightedGraph<T> {
+    type Label = T;
+    type Unlabeled = UndirectedGraph;
+    const DIRECTED: bool = false;
+    fn order(&self) -> usize {
+        self.nb_vertices()
+    }
+    fn edges(&self) -> Vec<(usize, usize, T)> {
+        EdgeWeightedGraph::edges(self)
+    }
+    fn from_edges(nb_vertices: usize, edges: &[(usize, usize, T)]) -> Self {
+        let mut graph = Self::init(nb_vertices);
+        for (u, v, w) in edges {
+            graph.add_edge(*u, *v, *w);
+        }
+        graph
+    }
+}
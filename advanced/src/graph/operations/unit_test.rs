@@ -0,0 +1,163 @@
+#[cfg(test)]
+mod tests {
+    use super::super::GraphOperations;
+    use crate::graph::{DirectedGraph, EdgeWeightedDigraph, EdgeWeightedGraph, UndirectedGraph};
+
+    fn cycle(n: usize) -> UndirectedGraph {
+        let mut graph = UndirectedGraph::init(n);
+        for v in 0..n {
+            graph.add_edge(v, (v + 1) % n);
+        }
+        graph
+    }
+
+    #[test]
+    fn test_undirected_operations() {
+        let square = cycle(4);
+        assert_eq!(
+            square.edges(),
+            vec![(0, 1, ()), (0, 3, ()), (1, 2, ()), (2, 3, ())]
+        );
+        let path = square.induced_subgraph(&[3, 0, 1]);
+        assert_eq!(path.edges(), vec![(0, 1, ()), (1, 2, ())]);
+        let filtered = square.filter_edges(|u, v, _| u + v != 3);
+        assert_eq!(filtered.edges(), vec![(0, 1, ()), (2, 3, ())]);
+
+        let complement = square.complement();
+        assert_eq!(complement.edges(), vec![(0, 2, ()), (1, 3, ())]);
+        let complete = square.union(&complement);
+        assert_eq!(complete.nb_edges(), 6);
+        assert_eq!(complete.intersection(&square).edges(), square.edges());
+        assert_eq!(complete.difference(&square).edges(), complement.edges());
+        assert_eq!(square.difference(&complete).nb_edges(), 0);
+
+        // the line graph of a cycle is a cycle, that of a star is complete
+        let (line, edges) = cycle(5).line_graph();
+        assert_eq!(edges.len(), 5);
+        assert_eq!(line.nb_edges(), 5);
+        assert!((0..5).all(|v| line.degree(&v) == 2));
+        let mut star = UndirectedGraph::init(5);
+        for v in 1..5 {
+            star.add_edge(0, v);
+        }
+        assert_eq!(star.line_graph().0.nb_edges(), 6);
+
+        // the product of two paths is a grid
+        let mut segment = UndirectedGraph::init(2);
+        segment.add_edge(0, 1);
+        let mut path = UndirectedGraph::init(3);
+        path.add_edge(0, 1);
+        path.add_edge(1, 2);
+        let grid = segment.cartesian_product(&path);
+        assert_eq!(grid.nb_vertices(), 6);
+        assert_eq!(
+            grid.edges(),
+            vec![
+                (0, 1, ()),
+                (0, 3, ()),
+                (1, 2, ()),
+                (1, 4, ()),
+                (2, 5, ()),
+                (3, 4, ()),
+                (4, 5, ())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_directed_operations() {
+        let mut graph = DirectedGraph::init(3);
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        let complement = graph.complement();
+        assert_eq!(
+            complement.edges(),
+            vec![(0, 2, ()), (1, 0, ()), (2, 0, ()), (2, 1, ())]
+        );
+        assert_eq!(graph.union(&complement).nb_edges(), 6);
+        // 0 -> 1 is followed by 1 -> 2 only
+        let (line, edges) = graph.line_graph();
+        assert_eq!(edges, vec![(0, 1, ()), (1, 2, ())]);
+        assert_eq!(line.edges(), vec![(0, 1, ())]);
+        let sub = graph.induced_subgraph(&[2, 1]);
+        assert_eq!(sub.edges(), vec![(1, 0, ())]);
+        assert_eq!(sub.in_degree(&0), 1);
+        let product = graph.cartesian_product(&graph);
+        assert_eq!(product.nb_vertices(), 9);
+        assert_eq!(product.nb_edges(), 12);
+    }
+
+    #[test]
+    fn test_operations_with_data() {
+        let mut graph = DirectedGraph::<char, u8>::with_vertices(vec!['a', 'b', 'c']);
+        graph.add_edge_with(0, 1, 3);
+        graph.add_edge_with(1, 2, 8);
+        let sub = graph.induced_subgraph(&[2, 1]);
+        assert_eq!(sub.edges(), vec![(1, 0, 8)]);
+        assert_eq!((sub.vertex_data(&0), sub.vertex_data(&1)), (&'c', &'b'));
+        let filtered = graph.filter_edges(|_, _, data| *data > 5);
+        assert_eq!(filtered.edges(), vec![(1, 2, 8)]);
+        assert_eq!(filtered.vertex_data(&2), &'c');
+        let complement = graph.complement();
+        assert_eq!(complement.edge_data(&2, &0), Some(&0));
+        assert_eq!(complement.vertex_data(&1), &'b');
+
+        let mut other = DirectedGraph::<char, u8>::with_vertices(vec!['x'; 4]);
+        other.add_edge_with(0, 1, 3);
+        other.add_edge_with(1, 2, 9);
+        assert_eq!(graph.intersection(&other).edges(), vec![(0, 1, 3)]);
+        assert_eq!(graph.difference(&other).edges(), vec![(1, 2, 8)]);
+        // the edge 1 -> 2 of both graphs gets the data of the second one
+        let union = graph.union(&other);
+        assert_eq!(union.edges(), vec![(0, 1, 3), (1, 2, 9)]);
+        let names = (0..4).map(|v| *union.vertex_data(&v)).collect::<String>();
+        assert_eq!(names, "abcx");
+
+        let mut roads = UndirectedGraph::<&str, &str>::with_vertices(vec!["p", "q", "r"]);
+        roads.add_edge_with(2, 0, "rail");
+        roads.add_edge_with(0, 1, "road");
+        assert_eq!(roads.edges(), vec![(0, 1, "road"), (0, 2, "rail")]);
+        let sub = roads.induced_subgraph(&[2, 0]);
+        assert_eq!(sub.edge_data(&1, &0), Some(&"rail"));
+        assert_eq!(sub.vertex_data(&0), &"r");
+        let (line, edges) = roads.line_graph();
+        assert_eq!(edges[1], (0, 2, "rail"));
+        assert_eq!(line.nb_edges(), 1);
+        // the vertices of a product get the default data
+        let product = roads.cartesian_product(&roads);
+        assert_eq!(product.vertex_data(&4), &"");
+        assert_eq!(product.edge_data(&0, &1), Some(&"road"));
+    }
+
+    #[test]
+    fn test_weighted_operations() {
+        let mut graph = EdgeWeightedGraph::init(3);
+        graph.add_edge(0, 1, 4);
+        graph.add_edge(1, 2, 7);
+        let mut other = EdgeWeightedGraph::init(3);
+        other.add_edge(1, 0, 4);
+        other.add_edge(1, 2, 5);
+        assert_eq!(graph.intersection(&other).edges(), vec![(0, 1, 4)]);
+        assert_eq!(graph.difference(&other).edges(), vec![(1, 2, 7)]);
+        // parallel edges with different weights are kept
+        assert_eq!(graph.union(&other).nb_edges(), 3);
+        assert_eq!(
+            graph.filter_edges(|_, _, w| *w > 5).edges(),
+            vec![(1, 2, 7)]
+        );
+        assert_eq!(graph.complement_with(1).edges(), vec![(0, 2, 1)]);
+        let (line, edges) = graph.line_graph();
+        assert_eq!(edges, vec![(0, 1, 4), (1, 2, 7)]);
+        assert_eq!(line.nb_edges(), 1);
+
+        let mut digraph = EdgeWeightedDigraph::init(3);
+        digraph.add_edge(0, 1, 2);
+        digraph.add_edge(2, 0, 3);
+        let sub = digraph.induced_subgraph(&[2, 0]);
+        assert_eq!(sub.edges(), vec![(0, 1, 3)]);
+        let product = digraph.cartesian_product(&sub);
+        assert_eq!(product.nb_vertices(), 6);
+        assert!(product.edges().contains(&(1, 3, 2)));
+        assert!(product.edges().contains(&(4, 5, 3)));
+    }
+}
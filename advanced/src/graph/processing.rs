@@ -1,9 +1,44 @@
+mod arborescence;
+mod clique;
+mod coloring;
+mod community;
 mod connection;
+mod dominators;
+mod matching;
 mod maxflow_mincut;
+mod random_walk;
+mod reachability;
+pub mod scheduling;
 mod search;
 mod sort;
+mod tsp;
+mod two_sat;
 
-pub use connection::ConnectedComponent;
+pub use arborescence::{minimum_arborescence, Arborescence};
+pub use clique::{is_clique, maximum_clique, MaximalCliques};
+pub use coloring::{
+    dsatur, exact_coloring, greedy_coloring, is_proper_coloring, largest_first_order,
+    smallest_last_order, Coloring, ColoringAlgo,
+};
+pub use community::{label_propagation, louvain, modularity, Communities, WeightedNeighbors};
 pub use connection::StrongConnectedComponent;
-pub use search::{bfs, dfs, BreadthFirstSearch, DepthFirstSearch, ShortestPath, ShortestPathAlgo};
+pub use connection::{ConnectedComponent, DynamicConnectivity};
+pub use dominators::{DominatorAlgo, Dominators};
+pub use matching::{
+    hospitals_residents, hungarian, is_stable, stable_marriage, Assignment, Cost, Objective,
+    Optimality, StableMatching,
+};
+pub use maxflow_mincut::{
+    karger_stein, stoer_wagner, Circulation, FordFulkerson, GomoryHuTree, Infeasibility,
+};
+pub use random_walk::{RandomWalker, WalkStrategy};
+pub use reachability::{ReachabilityIndex, TransitiveClosure};
+pub use search::{
+    bfs, depth_first_visit, dfs, yen, Bfs, BreadthFirstSearch, DepthFirstSearch, Dfs,
+    KShortestPaths, ShortestPath, ShortestPathAlgo, Visitor,
+};
 pub use sort::TopologicalSort;
+pub use tsp::{
+    greedy_tour, held_karp, mst_tour, nearest_neighbor_tour, or_opt, two_opt, DistanceMatrix, Tour,
+};
+pub use two_sat::TwoSat;
@@ -0,0 +1,161 @@
+#[cfg(test)]
+mod unit_test;
+use crate::graph::{EdgeWeightedDigraph, Weight};
+
+/// Spanning tree of a directed graph whose edges point away from its root,
+/// found by `minimum_arborescence`
+#[derive(Debug)]
+pub struct Arborescence<T> {
+    root: usize,
+    // edge entering each vertex, as (origin, weight)
+    parent: Vec<Option<(usize, T)>>,
+    weight: T,
+}
+impl<T: Weight> Arborescence<T> {
+    pub fn root(&self) -> usize {
+        self.root
+    }
+    /// Gives the origin of the edge entering a vertex, `None` for the root
+    pub fn parent(&self, v: usize) -> Option<usize> {
+        self.parent[v].map(|(u, _)| u)
+    }
+    /// Gives the edges of the arborescence, by increasing destination
+    pub fn edges(&self) -> Vec<(usize, usize, T)> {
+        self.parent
+            .iter()
+            .enumerate()
+            .filter_map(|(v, edge)| edge.map(|(u, w)| (u, v, w)))
+            .collect()
+    }
+    /// Gives the total weight of the edges
+    pub fn weight(&self) -> T {
+        self.weight
+    }
+}
+
+/// Function that finds a minimum weight spanning arborescence rooted at a
+/// vertex, with the Chu-Liu/Edmonds algorithm: each vertex takes its
+/// cheapest entering edge, and the cycles these edges make are contracted
+/// (with the entering weights reduced by the weight of the edge they would
+/// replace) until there is none. Returns `None` when some vertex is not
+/// reachable from the root. Weights may be negative.
+/// # Example
+/// ```
+/// use algods::graph::EdgeWeightedDigraph;
+/// use algods::graph::processing::minimum_arborescence;
+/// let mut graph = EdgeWeightedDigraph::init(4);
+/// graph.add_edge(0, 1, 10);
+/// graph.add_edge(0, 2, 6);
+/// graph.add_edge(1, 2, 1);
+/// graph.add_edge(2, 1, 2);
+/// graph.add_edge(2, 3, 3);
+/// let arborescence = minimum_arborescence(&graph, 0).unwrap();
+/// assert_eq!(arborescence.weight(), 11);
+/// assert_eq!(arborescence.edges(), vec![(2, 1, 2), (0, 2, 6), (2, 3, 3)]);
+/// // the vertex 0 cannot be reached from the vertex 3
+/// assert!(minimum_arborescence(&graph, 3).is_none());
+/// ```
+pub fn minimum_arborescence<T: Weight>(
+    graph: &EdgeWeightedDigraph<T>,
+    root: usize,
+) -> Option<Arborescence<T>> {
+    // run time complexity O(VE)
+    let nb = graph.nb_vertices();
+    assert!(root < nb);
+    let edges = (0..nb)
+        .flat_map(|u| {
+            graph
+                .vertex_edges(&u)
+                .into_iter()
+                .map(move |(v, w)| (u, *v, *w))
+        })
+        .collect::<Vec<(usize, usize, T)>>();
+    let mut parent = vec![None; nb];
+    let mut weight = T::zero();
+    for e in contract(nb, root, &edges)? {
+        let (u, v, w) = edges[e];
+        parent[v] = Some((u, w));
+        weight = weight + w;
+    }
+    Some(Arborescence {
+        root,
+        parent,
+        weight,
+    })
+}
+
+fn contract<T: Weight>(nb: usize, root: usize, edges: &[(usize, usize, T)]) -> Option<Vec<usize>> {
+    // indices of the edges of a minimum arborescence, one entering each
+    // vertex except the root, among edges between the vertices 0, ..., nb - 1
+    let mut cheapest: Vec<Option<usize>> = vec![None; nb];
+    for (e, (u, v, w)) in edges.iter().enumerate() {
+        if u != v && *v != root && cheapest[*v].is_none_or(|c| *w < edges[c].2) {
+            cheapest[*v] = Some(e);
+        }
+    }
+    if (0..nb).any(|v| v != root && cheapest[v].is_none()) {
+        return None;
+    }
+    // finds the cycles made by the cheapest entering edges
+    let mut cycle = vec![None; nb];
+    let mut nb_cycles = 0;
+    let mut visited_from = vec![None; nb];
+    for start in 0..nb {
+        let mut v = start;
+        while v != root && visited_from[v].is_none() {
+            visited_from[v] = Some(start);
+            v = edges[cheapest[v]?].0;
+        }
+        if v != root && visited_from[v] == Some(start) && cycle[v].is_none() {
+            // v is on a cycle found from start
+            while cycle[v].is_none() {
+                cycle[v] = Some(nb_cycles);
+                v = edges[cheapest[v]?].0;
+            }
+            nb_cycles += 1;
+        }
+    }
+    if nb_cycles == 0 {
+        return Some((0..nb).filter_map(|v| cheapest[v]).collect());
+    }
+    // each cycle becomes a single vertex, the other vertices being numbered after
+    let mut nb_nodes = nb_cycles;
+    let node = cycle
+        .iter()
+        .map(|c| {
+            c.unwrap_or_else(|| {
+                nb_nodes += 1;
+                nb_nodes - 1
+            })
+        })
+        .collect::<Vec<usize>>();
+    // edges between distinct nodes, an edge entering a cycle at v being
+    // reduced by the weight of the cycle edge entering v
+    let mut kept = Vec::new();
+    let mut contracted = Vec::new();
+    for (e, (u, v, w)) in edges.iter().enumerate() {
+        if node[*u] != node[*v] {
+            let w = match cycle[*v] {
+                Some(_) => *w - edges[cheapest[*v]?].2,
+                None => *w,
+            };
+            kept.push(e);
+            contracted.push((node[*u], node[*v], w));
+        }
+    }
+    let chosen = contract(nb_nodes, node[root], &contracted)?
+        .into_iter()
+        .map(|e| kept[e])
+        .collect::<Vec<usize>>();
+    // expands each cycle, keeping all its edges but
+    // the one entering the vertex where the cycle is entered
+    let mut entered = vec![false; nb];
+    for e in &chosen {
+        entered[edges[*e].1] = true;
+    }
+    let mut result = chosen;
+    for v in (0..nb).filter(|v| cycle[*v].is_some() && !entered[*v]) {
+        result.push(cheapest[v]?);
+    }
+    Some(result)
+}
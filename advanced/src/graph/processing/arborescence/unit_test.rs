@@ -0,0 +1,121 @@
+#[cfg(test)]
+mod tests {
+    use super::super::minimum_arborescence;
+    use crate::graph::EdgeWeightedDigraph;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn brute_force(nb: usize, root: usize, edges: &[(usize, usize, i32)]) -> Option<i32> {
+        // tries every choice of an edge entering each vertex but the root
+        let entering = (0..nb)
+            .map(|v| {
+                edges
+                    .iter()
+                    .filter(|(u, w, _)| *w == v && *u != v)
+                    .copied()
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let mut best = None;
+        let mut choice = vec![0; nb];
+        loop {
+            let parent = (0..nb)
+                .map(|v| (v != root).then(|| entering[v].get(choice[v]).copied()))
+                .collect::<Vec<_>>();
+            if parent.iter().all(|p| !matches!(p, Some(None))) {
+                // each vertex must reach the root by following its parents
+                let valid = (0..nb).all(|v| {
+                    let mut current = v;
+                    for _ in 0..nb {
+                        match parent[current] {
+                            Some(Some((u, _, _))) => current = u,
+                            _ => break,
+                        }
+                    }
+                    current == root
+                });
+                if valid {
+                    let weight = parent.iter().flatten().flatten().map(|e| e.2).sum::<i32>();
+                    if best.is_none_or(|b| weight < b) {
+                        best = Some(weight);
+                    }
+                }
+            }
+            // next choice
+            let mut v = 0;
+            while v < nb {
+                choice[v] += 1;
+                if choice[v] < std::cmp::max(entering[v].len(), 1) {
+                    break;
+                }
+                choice[v] = 0;
+                v += 1;
+            }
+            if v == nb {
+                return best;
+            }
+        }
+    }
+
+    #[test]
+    fn test_arborescence_random() {
+        let mut rng = StdRng::seed_from_u64(43);
+        for _ in 0..300 {
+            let nb = rng.gen_range(1..7);
+            let mut graph = EdgeWeightedDigraph::init(nb);
+            let mut edges = Vec::new();
+            for _ in 0..rng.gen_range(0..12) {
+                let edge = (
+                    rng.gen_range(0..nb),
+                    rng.gen_range(0..nb),
+                    rng.gen_range(-5..10),
+                );
+                graph.add_edge(edge.0, edge.1, edge.2);
+                edges.push(edge);
+            }
+            let root = rng.gen_range(0..nb);
+            let expected = brute_force(nb, root, &edges);
+            let arborescence = minimum_arborescence(&graph, root);
+            assert_eq!(arborescence.as_ref().map(|a| a.weight()), expected);
+            if let Some(arborescence) = arborescence {
+                assert_eq!(arborescence.root(), root);
+                assert_eq!(arborescence.edges().len(), nb - 1);
+                for (u, v, w) in arborescence.edges() {
+                    assert_eq!(arborescence.parent(v), Some(u));
+                    assert!(edges.contains(&(u, v, w)));
+                }
+                assert_eq!(
+                    arborescence.edges().iter().map(|e| e.2).sum::<i32>(),
+                    arborescence.weight()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_arborescence_unsigned() {
+        // nested cycles with unsigned weights
+        let mut graph = EdgeWeightedDigraph::init(5);
+        for (u, v, w) in [
+            (0, 1, 9u32),
+            (1, 2, 1),
+            (2, 1, 1),
+            (2, 3, 1),
+            (3, 2, 2),
+            (3, 4, 1),
+            (4, 3, 1),
+            (0, 4, 20),
+        ] {
+            graph.add_edge(u, v, w);
+        }
+        let arborescence = minimum_arborescence(&graph, 0).unwrap();
+        assert_eq!(arborescence.weight(), 12);
+        // the only minimum arborescence follows the chain from 0
+        assert_eq!(
+            arborescence.edges(),
+            vec![(0, 1, 9), (1, 2, 1), (2, 3, 1), (3, 4, 1)]
+        );
+        assert_eq!(arborescence.parent(1), Some(0));
+        assert_eq!(arborescence.parent(0), None);
+    }
+}
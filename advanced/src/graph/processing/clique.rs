@@ -0,0 +1,229 @@
+#[cfg(test)]
+mod unit_test;
+use crate::graph::processing::smallest_last_order;
+use crate::graph::VertexInfo;
+use bitvec::prelude::{BitVec, Lsb0};
+
+type VertexSet = BitVec<usize, Lsb0>;
+
+fn adjacency<G: VertexInfo>(graph: &G) -> Vec<VertexSet> {
+    // neighborhoods as bit sets, self loops being ignored
+    let nb = graph.nb_vertices();
+    (0..nb)
+        .map(|v| {
+            let mut set = VertexSet::repeat(false, nb);
+            for w in graph.vertex_edges(&v).into_iter().filter(|w| **w != v) {
+                set.set(*w, true);
+            }
+            set
+        })
+        .collect()
+}
+
+fn intersection(set: &VertexSet, other: &VertexSet) -> VertexSet {
+    let mut result = set.clone();
+    *result.as_mut_bitslice() &= other.as_bitslice();
+    result
+}
+
+/// Function that checks that the given (distinct) vertices
+/// are pairwise adjacent
+pub fn is_clique<G: VertexInfo>(graph: &G, vertices: &[usize]) -> bool {
+    vertices.iter().enumerate().all(|(i, v)| {
+        let adjacent = graph.vertex_edges(v);
+        vertices[i + 1..]
+            .iter()
+            .all(|w| w != v && adjacent.contains(&w))
+    })
+}
+
+// search node of the Bron-Kerbosch algorithm: the clique being grown, the
+// vertices which can extend it and have not been tried yet, the vertices
+// which could extend it but were already tried, and the candidates left
+struct Frame {
+    clique: Vec<usize>,
+    candidates: VertexSet,
+    excluded: VertexSet,
+    branches: Vec<usize>,
+}
+
+/// Lazy enumeration of the maximal cliques of an undirected graph with the
+/// Bron-Kerbosch algorithm, using Tomita's pivoting and the degeneracy order
+/// of the vertices at the top level (Eppstein, Löffler and Strash). Each
+/// maximal clique is yielded once, sorted, with an explicit stack so that
+/// the enumeration can be stopped at any time. Self loops are ignored.
+/// # Example
+/// ```
+/// use algods::graph::UndirectedGraph;
+/// use algods::graph::processing::MaximalCliques;
+/// let mut graph = UndirectedGraph::init(5);
+/// graph.add_edge(0, 1);
+/// graph.add_edge(1, 2);
+/// graph.add_edge(2, 0);
+/// graph.add_edge(2, 3);
+/// let mut cliques = MaximalCliques::new(&graph).collect::<Vec<Vec<usize>>>();
+/// cliques.sort();
+/// assert_eq!(cliques, vec![vec![0, 1, 2], vec![2, 3], vec![4]]);
+/// ```
+pub struct MaximalCliques {
+    adjacent: Vec<VertexSet>,
+    // vertices in degeneracy order, each one having few later neighbors
+    order: Vec<usize>,
+    // position in order of the next top level vertex
+    next: usize,
+    stack: Vec<Frame>,
+}
+impl MaximalCliques {
+    pub fn new<G: VertexInfo>(graph: &G) -> Self {
+        let mut order = smallest_last_order(graph);
+        order.reverse();
+        Self {
+            adjacent: adjacency(graph),
+            order,
+            next: 0,
+            stack: Vec::new(),
+        }
+    }
+    fn frame(&self, clique: Vec<usize>, candidates: VertexSet, excluded: VertexSet) -> Frame {
+        // only the candidates which are not adjacent to the pivot need to be
+        // tried, the pivot being the vertex with the most adjacent candidates
+        let pivot = candidates
+            .iter_ones()
+            .chain(excluded.iter_ones())
+            .max_by_key(|u| intersection(&candidates, &self.adjacent[*u]).count_ones());
+        let branches = match pivot {
+            Some(u) => candidates
+                .iter_ones()
+                .filter(|v| !self.adjacent[u][*v])
+                .collect(),
+            None => Vec::new(),
+        };
+        Frame {
+            clique,
+            candidates,
+            excluded,
+            branches,
+        }
+    }
+}
+impl Iterator for MaximalCliques {
+    type Item = Vec<usize>;
+    fn next(&mut self) -> Option<Vec<usize>> {
+        loop {
+            let Some(frame) = self.stack.last_mut() else {
+                // starts the search of the cliques whose earliest
+                // vertex in degeneracy order is the next vertex
+                let v = *self.order.get(self.next)?;
+                let nb = self.order.len();
+                let (mut later, mut earlier) =
+                    (VertexSet::repeat(false, nb), VertexSet::repeat(false, nb));
+                for (position, w) in self.order.iter().enumerate() {
+                    if self.adjacent[v][*w] {
+                        if position > self.next {
+                            later.set(*w, true);
+                        } else {
+                            earlier.set(*w, true);
+                        }
+                    }
+                }
+                self.next += 1;
+                if later.not_any() && earlier.not_any() {
+                    return Some(vec![v]);
+                }
+                let frame = self.frame(vec![v], later, earlier);
+                self.stack.push(frame);
+                continue;
+            };
+            let Some(v) = frame.branches.pop() else {
+                self.stack.pop();
+                continue;
+            };
+            let candidates = intersection(&frame.candidates, &self.adjacent[v]);
+            let excluded = intersection(&frame.excluded, &self.adjacent[v]);
+            let mut clique = frame.clique.clone();
+            clique.push(v);
+            frame.candidates.set(v, false);
+            frame.excluded.set(v, true);
+            if candidates.not_any() {
+                if excluded.not_any() {
+                    clique.sort_unstable();
+                    return Some(clique);
+                }
+            } else {
+                let frame = self.frame(clique, candidates, excluded);
+                self.stack.push(frame);
+            }
+        }
+    }
+}
+
+/// Function that finds a clique with the largest number of vertices by
+/// branch and bound, the size of the cliques which can still be found being
+/// bounded by a greedy coloring of the candidates (Tomita's MCQ algorithm).
+/// Self loops are ignored and the search is exponential in the worst case.
+/// # Example
+/// ```
+/// use algods::graph::UndirectedGraph;
+/// use algods::graph::processing::{is_clique, maximum_clique};
+/// let mut graph = UndirectedGraph::init(5);
+/// for (v, w) in [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3), (3, 4)] {
+///     graph.add_edge(v, w);
+/// }
+/// let clique = maximum_clique(&graph);
+/// assert_eq!(clique, vec![0, 1, 2, 3]);
+/// assert!(is_clique(&graph, &clique));
+/// ```
+pub fn maximum_clique<G: VertexInfo>(graph: &G) -> Vec<usize> {
+    let adjacent = adjacency(graph);
+    let nb = adjacent.len();
+    let mut best = Vec::new();
+    let mut clique = Vec::new();
+    expand(
+        &adjacent,
+        &mut clique,
+        VertexSet::repeat(true, nb),
+        &mut best,
+    );
+    best.sort_unstable();
+    best
+}
+
+fn expand(
+    adjacent: &[VertexSet],
+    clique: &mut Vec<usize>,
+    mut candidates: VertexSet,
+    best: &mut Vec<usize>,
+) {
+    // colors the candidates greedily: the vertices of a clique have distinct
+    // colors, so at most color + 1 of the candidates up to a vertex (in
+    // coloring order) can extend the clique
+    let mut colored = Vec::with_capacity(candidates.count_ones());
+    let mut uncolored = candidates.clone();
+    let mut color = 0;
+    while uncolored.any() {
+        let mut available = uncolored.clone();
+        while let Some(v) = available.first_one() {
+            available.set(v, false);
+            let non_adjacent = !adjacent[v].clone();
+            *available.as_mut_bitslice() &= non_adjacent.as_bitslice();
+            uncolored.set(v, false);
+            colored.push((v, color));
+        }
+        color += 1;
+    }
+    // tries the vertices with the largest colors first
+    while let Some((v, color)) = colored.pop() {
+        if clique.len() + color < best.len() {
+            return;
+        }
+        clique.push(v);
+        let next = intersection(&candidates, &adjacent[v]);
+        if next.any() {
+            expand(adjacent, clique, next, best);
+        } else if clique.len() > best.len() {
+            *best = clique.clone();
+        }
+        clique.pop();
+        candidates.set(v, false);
+    }
+}
@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{is_clique, maximum_clique, MaximalCliques};
+    use crate::graph::UndirectedGraph;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn brute_force(graph: &UndirectedGraph) -> Vec<Vec<usize>> {
+        // the cliques to which no vertex can be added
+        let nb = graph.nb_vertices();
+        let cliques = (1..1usize << nb)
+            .map(|mask| (0..nb).filter(|v| mask & (1 << v) != 0).collect::<Vec<_>>())
+            .filter(|vertices| is_clique(graph, vertices))
+            .collect::<Vec<_>>();
+        cliques
+            .iter()
+            .filter(|clique| {
+                (0..nb).filter(|v| !clique.contains(v)).all(|v| {
+                    let mut larger = clique.to_vec();
+                    larger.push(v);
+                    !is_clique(graph, &larger)
+                })
+            })
+            .cloned()
+            .collect()
+    }
+
+    fn random_graph(rng: &mut StdRng, nb: usize, density: f64) -> UndirectedGraph {
+        let mut graph = UndirectedGraph::init(nb);
+        for v in 0..nb {
+            for w in v..nb {
+                if rng.gen_bool(density) {
+                    graph.add_edge(v, w);
+                }
+            }
+        }
+        graph
+    }
+
+    #[test]
+    fn test_cliques() {
+        // a square 0 - 1 - 2 - 3 with the roof 4 on its side 0 - 1
+        let mut graph = UndirectedGraph::init(5);
+        for (v, w) in [(0, 1), (1, 2), (2, 3), (3, 0), (0, 4), (1, 4)] {
+            graph.add_edge(v, w);
+        }
+        let mut cliques = MaximalCliques::new(&graph).collect::<Vec<Vec<usize>>>();
+        cliques.iter_mut().for_each(|clique| clique.sort_unstable());
+        cliques.sort();
+        assert_eq!(
+            cliques,
+            vec![vec![0, 1, 4], vec![0, 3], vec![1, 2], vec![2, 3]]
+        );
+        let mut maximum = maximum_clique(&graph);
+        maximum.sort_unstable();
+        assert_eq!(maximum, vec![0, 1, 4]);
+        assert!(!is_clique(&graph, &[0, 1, 2]));
+    }
+
+    #[test]
+    fn test_cliques_random() {
+        let mut rng = StdRng::seed_from_u64(41);
+        for _ in 0..200 {
+            let nb = rng.gen_range(0..11);
+            let density = rng.gen_range(0.1..0.9);
+            let graph = random_graph(&mut rng, nb, density);
+            let mut expected = brute_force(&graph);
+            expected.sort();
+            let mut cliques = MaximalCliques::new(&graph).collect::<Vec<Vec<usize>>>();
+            cliques.sort();
+            assert_eq!(cliques, expected);
+            let maximum = maximum_clique(&graph);
+            assert!(is_clique(&graph, &maximum));
+            assert_eq!(
+                maximum.len(),
+                expected.iter().map(|c| c.len()).max().unwrap_or(0)
+            );
+        }
+    }
+
+    #[test]
+    fn test_cliques_lazy() {
+        // the complete graph has a single maximal clique
+        let mut graph = UndirectedGraph::init(60);
+        for v in 0..60 {
+            for w in v + 1..60 {
+                graph.add_edge(v, w);
+            }
+        }
+        let mut cliques = MaximalCliques::new(&graph);
+        assert_eq!(cliques.next(), Some((0..60).collect()));
+        assert_eq!(cliques.next(), None);
+        assert_eq!(maximum_clique(&graph).len(), 60);
+        // a large sparse graph made of disjoint triangles
+        let mut graph = UndirectedGraph::init(3000);
+        for t in 0..1000 {
+            graph.add_edge(3 * t, 3 * t + 1);
+            graph.add_edge(3 * t + 1, 3 * t + 2);
+            graph.add_edge(3 * t + 2, 3 * t);
+        }
+        assert_eq!(MaximalCliques::new(&graph).take(10).count(), 10);
+        assert!(MaximalCliques::new(&graph).all(|clique| clique.len() == 3));
+        assert_eq!(maximum_clique(&graph).len(), 3);
+    }
+}
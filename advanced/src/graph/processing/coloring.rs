@@ -0,0 +1,240 @@
+#[cfg(test)]
+mod unit_test;
+use crate::graph::VertexInfo;
+use std::collections::HashSet;
+
+/// Enumeration of the vertex coloring algorithms.
+/// Self loops are ignored by all of them.
+#[derive(Default)]
+pub enum ColoringAlgo {
+    /// Greedy coloring of the vertices in their natural order
+    Greedy,
+    /// Greedy coloring of the vertices by decreasing degree
+    LargestFirst,
+    /// Greedy coloring of the vertices in smallest last (degeneracy) order
+    SmallestLast,
+    /// Brélaz's heuristic, coloring first the vertex with the most distinct
+    /// colors among its neighbors
+    #[default]
+    DSatur,
+    /// Exact backtracking search of a coloring with the chromatic number of
+    /// colors, only tractable on small graphs
+    Exact,
+}
+
+/// Implementation of vertex coloring of undirected graphs, where two
+/// adjacent vertices never have the same color.
+/// # Example
+/// ```
+/// use algods::graph::UndirectedGraph;
+/// use algods::graph::processing::{is_proper_coloring, Coloring, ColoringAlgo};
+/// let mut graph = UndirectedGraph::init(4);
+/// graph.add_edge(0, 1);
+/// graph.add_edge(1, 2);
+/// graph.add_edge(2, 0);
+/// graph.add_edge(2, 3);
+/// let mut coloring = Coloring::init(ColoringAlgo::Exact, graph.nb_vertices());
+/// coloring.find_coloring(&graph);
+/// assert_eq!(coloring.nb_colors(), 3);
+/// assert!(is_proper_coloring(&graph, coloring.colors()));
+/// ```
+pub struct Coloring {
+    // the algorithm used to color the graph
+    algo: ColoringAlgo,
+    // the color of each vertex, colors are 0, 1, ..., nb_colors - 1
+    color: Vec<usize>,
+    // number of colors used
+    nb_colors: usize,
+}
+impl Coloring {
+    pub fn init(algorithm: ColoringAlgo, nb_vertices: usize) -> Self {
+        Self {
+            algo: algorithm,
+            color: vec![0; nb_vertices],
+            nb_colors: 0,
+        }
+    }
+    pub fn find_coloring<G: VertexInfo>(&mut self, graph: &G) {
+        assert_eq!(self.color.len(), graph.nb_vertices());
+        self.color = match self.algo {
+            ColoringAlgo::Greedy => {
+                greedy_coloring(graph, &(0..graph.nb_vertices()).collect::<Vec<usize>>())
+            }
+            ColoringAlgo::LargestFirst => greedy_coloring(graph, &largest_first_order(graph)),
+            ColoringAlgo::SmallestLast => greedy_coloring(graph, &smallest_last_order(graph)),
+            ColoringAlgo::DSatur => dsatur(graph),
+            ColoringAlgo::Exact => exact_coloring(graph),
+        };
+        self.nb_colors = self.color.iter().max().map_or(0, |c| c + 1);
+    }
+    pub fn color(&self, v: usize) -> usize {
+        self.color[v]
+    }
+    pub fn colors(&self) -> &[usize] {
+        &self.color
+    }
+    pub fn nb_colors(&self) -> usize {
+        self.nb_colors
+    }
+}
+
+fn neighbors<G: VertexInfo>(graph: &G, v: usize) -> impl Iterator<Item = usize> + '_ {
+    // adjacent vertices of v, without v itself
+    graph
+        .vertex_edges(&v)
+        .into_iter()
+        .copied()
+        .filter(move |w| *w != v)
+}
+
+/// Function that checks that no edge joins two vertices of the same color,
+/// self loops being ignored
+pub fn is_proper_coloring<G: VertexInfo>(graph: &G, colors: &[usize]) -> bool {
+    let nb = graph.nb_vertices();
+    colors.len() == nb && (0..nb).all(|v| neighbors(graph, v).all(|w| colors[v] != colors[w]))
+}
+
+/// Function that colors the vertices one after another in a given order,
+/// with the smallest color not used by their already colored neighbors
+pub fn greedy_coloring<G: VertexInfo>(graph: &G, order: &[usize]) -> Vec<usize> {
+    // run time complexity O(V + E)
+    let nb = graph.nb_vertices();
+    let mut color = vec![usize::MAX; nb];
+    // used[c] == v indicates that color c is used by a neighbor of v
+    let mut used = vec![usize::MAX; nb + 1];
+    for v in order {
+        for w in neighbors(graph, *v) {
+            if color[w] != usize::MAX {
+                used[color[w]] = *v;
+            }
+        }
+        color[*v] = (0..).find(|c| used[*c] != *v).expect("a color is free");
+    }
+    color
+}
+
+/// Function that orders the vertices by decreasing degree
+pub fn largest_first_order<G: VertexInfo>(graph: &G) -> Vec<usize> {
+    let mut order = (0..graph.nb_vertices()).collect::<Vec<usize>>();
+    order.sort_by_key(|v| std::cmp::Reverse(neighbors(graph, *v).count()));
+    order
+}
+
+/// Function that orders the vertices such that each vertex has the smallest
+/// degree in the subgraph induced by itself and the vertices before it
+pub fn smallest_last_order<G: VertexInfo>(graph: &G) -> Vec<usize> {
+    // run time complexity O(V + E) with a bucket queue on degrees
+    let nb = graph.nb_vertices();
+    let mut degree = (0..nb)
+        .map(|v| neighbors(graph, v).count())
+        .collect::<Vec<usize>>();
+    let mut buckets = vec![Vec::new(); nb];
+    for v in 0..nb {
+        buckets[degree[v]].push(v);
+    }
+    let mut removed = vec![false; nb];
+    let mut order = Vec::with_capacity(nb);
+    let mut min_degree = 0;
+    while order.len() < nb {
+        // buckets may contain outdated entries which are skipped
+        match buckets[min_degree].pop() {
+            Some(v) if !removed[v] && degree[v] == min_degree => {
+                removed[v] = true;
+                order.push(v);
+                for w in neighbors(graph, v) {
+                    if !removed[w] {
+                        degree[w] -= 1;
+                        buckets[degree[w]].push(w);
+                        min_degree = std::cmp::min(min_degree, degree[w]);
+                    }
+                }
+            }
+            Some(_) => {}
+            None => min_degree += 1,
+        }
+    }
+    order.reverse();
+    order
+}
+
+/// Function that colors a graph with Brélaz's DSatur heuristic
+pub fn dsatur<G: VertexInfo>(graph: &G) -> Vec<usize> {
+    // run time complexity O(V^2 + E)
+    let nb = graph.nb_vertices();
+    let mut color = vec![usize::MAX; nb];
+    // distinct colors among the neighbors of each vertex
+    let mut neighbor_colors = vec![HashSet::<usize>::new(); nb];
+    let degree = (0..nb)
+        .map(|v| neighbors(graph, v).count())
+        .collect::<Vec<usize>>();
+    for _ in 0..nb {
+        let v = (0..nb)
+            .filter(|v| color[*v] == usize::MAX)
+            .max_by_key(|v| (neighbor_colors[*v].len(), degree[*v], std::cmp::Reverse(*v)))
+            .expect("a vertex is not colored yet");
+        color[v] = (0..)
+            .find(|c| !neighbor_colors[v].contains(c))
+            .expect("a color is free");
+        for w in neighbors(graph, v) {
+            neighbor_colors[w].insert(color[v]);
+        }
+    }
+    color
+}
+
+/// Function that finds a coloring with the minimum number of colors
+/// (the chromatic number) by backtracking, only tractable on small graphs
+pub fn exact_coloring<G: VertexInfo>(graph: &G) -> Vec<usize> {
+    let nb = graph.nb_vertices();
+    let mut best = dsatur(graph);
+    let mut nb_colors = best.iter().max().map_or(0, |c| c + 1);
+    let order = largest_first_order(graph);
+    let adjacent = order
+        .iter()
+        .map(|v| neighbors(graph, *v).collect::<Vec<usize>>())
+        .collect::<Vec<Vec<usize>>>();
+    // look for colorings with less and less colors until none is found
+    while nb_colors > 1 {
+        let mut color = vec![usize::MAX; nb];
+        if !color_with(&order, &adjacent, &mut color, 0, 0, nb_colors - 1) {
+            break;
+        }
+        best = color;
+        nb_colors -= 1;
+    }
+    best
+}
+
+fn color_with(
+    order: &[usize],
+    adjacent: &[Vec<usize>],
+    color: &mut [usize],
+    position: usize,
+    nb_used: usize,
+    nb_colors: usize,
+) -> bool {
+    // tries to color the vertices from the given position in the order
+    // with at most nb_colors colors, nb_used colors being used before
+    if position == order.len() {
+        return true;
+    }
+    let v = order[position];
+    // a new color is only tried once to avoid exploring permutations of colors
+    for c in 0..std::cmp::min(nb_used + 1, nb_colors) {
+        if adjacent[position].iter().all(|w| color[*w] != c) {
+            color[v] = c;
+            if color_with(
+                order,
+                adjacent,
+                color,
+                position + 1,
+                std::cmp::max(nb_used, c + 1),
+                nb_colors,
+            ) {
+                return true;
+            }
+        }
+    }
+    color[v] = usize::MAX;
+    false
+}
@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{
+        greedy_coloring, is_proper_coloring, smallest_last_order, Coloring, ColoringAlgo,
+    };
+    use crate::graph::UndirectedGraph;
+
+    fn petersen() -> UndirectedGraph {
+        let mut graph = UndirectedGraph::init(10);
+        for i in 0..5 {
+            graph.add_edge(i, (i + 1) % 5);
+            graph.add_edge(i, i + 5);
+            graph.add_edge(i + 5, (i + 2) % 5 + 5);
+        }
+        graph
+    }
+
+    fn crown(n: usize) -> UndirectedGraph {
+        // u_i = 2i and v_i = 2i + 1 are linked when i != j
+        let mut graph = UndirectedGraph::init(2 * n);
+        for i in 0..n {
+            for j in 0..n {
+                if i != j {
+                    graph.add_edge(2 * i, 2 * j + 1);
+                }
+            }
+        }
+        graph
+    }
+
+    fn nb_colors(graph: &UndirectedGraph, algo: ColoringAlgo) -> usize {
+        let mut coloring = Coloring::init(algo, graph.nb_vertices());
+        coloring.find_coloring(graph);
+        assert!(is_proper_coloring(graph, coloring.colors()));
+        coloring.nb_colors()
+    }
+
+    #[test]
+    fn test_greedy_coloring() {
+        // the natural order is the worst one for crown graphs
+        let graph = crown(4);
+        assert_eq!(nb_colors(&graph, ColoringAlgo::Greedy), 4);
+        assert_eq!(nb_colors(&graph, ColoringAlgo::SmallestLast), 2);
+        assert!(nb_colors(&petersen(), ColoringAlgo::LargestFirst) <= 4);
+
+        let order = smallest_last_order(&petersen());
+        let mut sorted = order.clone();
+        sorted.sort();
+        assert_eq!(sorted, (0..10).collect::<Vec<usize>>());
+        let colors = greedy_coloring(&petersen(), &order);
+        assert!(is_proper_coloring(&petersen(), &colors));
+    }
+
+    #[test]
+    fn test_dsatur() {
+        // DSatur is exact on bipartite graphs
+        assert_eq!(nb_colors(&crown(5), ColoringAlgo::DSatur), 2);
+        assert!(nb_colors(&petersen(), ColoringAlgo::default()) <= 4);
+    }
+
+    #[test]
+    fn test_exact_coloring() {
+        assert_eq!(nb_colors(&petersen(), ColoringAlgo::Exact), 3);
+        assert_eq!(nb_colors(&crown(4), ColoringAlgo::Exact), 2);
+
+        let mut complete = UndirectedGraph::init(5);
+        for i in 0..5 {
+            for j in 0..i {
+                complete.add_edge(i, j);
+            }
+        }
+        assert_eq!(nb_colors(&complete, ColoringAlgo::Exact), 5);
+        assert_eq!(nb_colors(&UndirectedGraph::init(3), ColoringAlgo::Exact), 1);
+        assert_eq!(nb_colors(&UndirectedGraph::new(), ColoringAlgo::Exact), 0);
+    }
+
+    #[test]
+    fn test_is_proper_coloring() {
+        let mut graph = UndirectedGraph::init(3);
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        assert!(is_proper_coloring(&graph, &[0, 1, 0]));
+        assert!(!is_proper_coloring(&graph, &[0, 0, 1]));
+        assert!(!is_proper_coloring(&graph, &[0, 1]));
+    }
+}
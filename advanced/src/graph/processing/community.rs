@@ -0,0 +1,303 @@
+#[cfg(test)]
+mod unit_test;
+use crate::graph::{EdgeWeightedGraph, ToF64, UndirectedGraph, Weight};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use std::collections::HashMap;
+
+// maximum number of sweeps over the vertices of the label propagation
+const MAX_ITER: usize = 1000;
+
+/// This trait gives the adjacent vertices of a vertex in an
+/// undirected graph, along with the weights of the edges as floats
+pub trait WeightedNeighbors {
+    fn weighted_neighbors(&self, v: usize) -> Vec<(usize, f64)>;
+    fn nb_vertices(&self) -> usize;
+}
+impl<V, E> WeightedNeighbors for UndirectedGraph<V, E> {
+    fn weighted_neighbors(&self, v: usize) -> Vec<(usize, f64)> {
+        // all the edges have unit weights
+        crate::graph::VertexInfo::vertex_edges(self, &v)
+            .into_iter()
+            .map(|w| (*w, 1.0))
+            .collect()
+    }
+    fn nb_vertices(&self) -> usize {
+        self.nb_vertices()
+    }
+}
+impl<T: Weight + ToF64> WeightedNeighbors for EdgeWeightedGraph<T> {
+    fn weighted_neighbors(&self, v: usize) -> Vec<(usize, f64)> {
+        self.vertex_edges(&v)
+            .into_iter()
+            .map(|(w, weight)| (*w, weight.to_f64()))
+            .collect()
+    }
+    fn nb_vertices(&self) -> usize {
+        self.nb_vertices()
+    }
+}
+
+/// Partition of the vertices of a graph into communities
+#[derive(Debug)]
+pub struct Communities {
+    // community of each vertex, communities are 0, ..., nb_communities - 1
+    // numbered in the order of their smallest vertex
+    community: Vec<usize>,
+    nb_communities: usize,
+    // modularity of the partition
+    modularity: f64,
+}
+impl Communities {
+    fn from_labels<G: WeightedNeighbors>(graph: &G, labels: &[usize]) -> Self {
+        let mut renumber = HashMap::new();
+        let community = labels
+            .iter()
+            .map(|label| {
+                let next = renumber.len();
+                *renumber.entry(*label).or_insert(next)
+            })
+            .collect::<Vec<usize>>();
+        Self {
+            modularity: modularity(graph, &community),
+            nb_communities: renumber.len(),
+            community,
+        }
+    }
+    pub fn community(&self, v: usize) -> usize {
+        self.community[v]
+    }
+    pub fn communities(&self) -> &[usize] {
+        &self.community
+    }
+    pub fn count(&self) -> usize {
+        self.nb_communities
+    }
+    pub fn modularity(&self) -> f64 {
+        self.modularity
+    }
+}
+
+/// Computes the modularity of a partition of the vertices of an undirected
+/// graph, that is the fraction of the edge weights inside the communities
+/// minus its expected value when edges are randomly rewired.
+pub fn modularity<G: WeightedNeighbors>(graph: &G, community: &[usize]) -> f64 {
+    let nb = graph.nb_vertices();
+    assert_eq!(nb, community.len());
+    let mut internal = HashMap::<usize, f64>::new();
+    let mut total = HashMap::<usize, f64>::new();
+    let mut two_m = 0.0;
+    for v in 0..nb {
+        for (w, weight) in graph.weighted_neighbors(v) {
+            // self loops are counted twice, as both of their end points
+            let weight = if w == v { 2.0 * weight } else { weight };
+            two_m += weight;
+            *total.entry(community[v]).or_insert(0.0) += weight;
+            if community[v] == community[w] {
+                *internal.entry(community[v]).or_insert(0.0) += weight;
+            }
+        }
+    }
+    if two_m == 0.0 {
+        return 0.0;
+    }
+    total
+        .iter()
+        .map(|(c, tot)| internal.get(c).unwrap_or(&0.0) / two_m - (tot / two_m).powi(2))
+        .sum()
+}
+
+/// Finds communities with the (asynchronous) label propagation algorithm:
+/// each vertex takes in turn the label with the largest total edge weight among
+/// its neighbors, ties being broken at random, until labels do not change.
+/// # Example
+/// ```
+/// use algods::graph::UndirectedGraph;
+/// use algods::graph::processing::label_propagation;
+/// let mut graph = UndirectedGraph::init(6);
+/// for (v, w) in [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (2, 3)] {
+///     graph.add_edge(v, w);
+/// }
+/// let communities = label_propagation(&graph, 0);
+/// assert_eq!(communities.count(), 2);
+/// assert_eq!(communities.communities(), &[0, 0, 0, 1, 1, 1]);
+/// ```
+pub fn label_propagation<G: WeightedNeighbors>(graph: &G, seed: u64) -> Communities {
+    let nb = graph.nb_vertices();
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut labels = (0..nb).collect::<Vec<usize>>();
+    let mut order = (0..nb).collect::<Vec<usize>>();
+    let neighbors = order
+        .iter()
+        .map(|v| graph.weighted_neighbors(*v))
+        .collect::<Vec<Vec<(usize, f64)>>>();
+    let best_labels = |labels: &[usize], v: usize| {
+        let mut weights = HashMap::<usize, f64>::new();
+        for (w, weight) in neighbors[v].iter().filter(|(w, _)| *w != v) {
+            *weights.entry(labels[*w]).or_insert(0.0) += weight;
+        }
+        let max = weights.values().cloned().fold(f64::MIN, f64::max);
+        let mut best = weights
+            .into_iter()
+            .filter(|(_, weight)| *weight == max)
+            .map(|(label, _)| label)
+            .collect::<Vec<usize>>();
+        best.sort_unstable();
+        best
+    };
+    for _ in 0..MAX_ITER {
+        order.shuffle(&mut rng);
+        for v in &order {
+            if let Some(label) = best_labels(&labels, *v).choose(&mut rng) {
+                labels[*v] = *label;
+            }
+        }
+        // stops when every label is one of the most frequent around its vertex
+        if (0..nb).all(|v| {
+            let best = best_labels(&labels, v);
+            best.is_empty() || best.contains(&labels[v])
+        }) {
+            break;
+        }
+    }
+    Communities::from_labels(graph, &labels)
+}
+
+// Weighted graph on which the Louvain method moves vertices,
+// self loops being kept aside
+struct LevelGraph {
+    neighbors: Vec<Vec<(usize, f64)>>,
+    self_loops: Vec<f64>,
+}
+impl LevelGraph {
+    fn strength(&self, v: usize) -> f64 {
+        self.neighbors[v].iter().map(|(_, w)| w).sum::<f64>() + 2.0 * self.self_loops[v]
+    }
+
+    fn move_vertices(&self, two_m: f64) -> (Vec<usize>, bool) {
+        // moves each vertex to the neighboring community with
+        // the largest modularity gain, until no move improves it
+        let nb = self.neighbors.len();
+        let strength = (0..nb).map(|v| self.strength(v)).collect::<Vec<f64>>();
+        let mut community = (0..nb).collect::<Vec<usize>>();
+        let mut total = strength.clone();
+        let mut improved = false;
+        loop {
+            let mut moved = false;
+            for v in 0..nb {
+                let mut links = HashMap::<usize, f64>::new();
+                for (w, weight) in &self.neighbors[v] {
+                    *links.entry(community[*w]).or_insert(0.0) += weight;
+                }
+                let current = community[v];
+                total[current] -= strength[v];
+                let gain =
+                    |c: usize| links.get(&c).unwrap_or(&0.0) - total[c] * strength[v] / two_m;
+                let mut best = (current, gain(current));
+                let mut candidates = links.keys().cloned().collect::<Vec<usize>>();
+                candidates.sort_unstable();
+                for c in candidates {
+                    let g = gain(c);
+                    if g > best.1 + 1e-12 {
+                        best = (c, g);
+                    }
+                }
+                total[best.0] += strength[v];
+                if best.0 != current {
+                    community[v] = best.0;
+                    moved = true;
+                    improved = true;
+                }
+            }
+            if !moved {
+                return (community, improved);
+            }
+        }
+    }
+
+    fn aggregate(&self, community: &[usize]) -> Self {
+        // contracts each community into one vertex
+        let nb = community.iter().max().map_or(0, |c| c + 1);
+        let mut links = vec![HashMap::<usize, f64>::new(); nb];
+        let mut self_loops = vec![0.0; nb];
+        for (v, adj) in self.neighbors.iter().enumerate() {
+            let c = community[v];
+            self_loops[c] += self.self_loops[v];
+            for (w, weight) in adj {
+                let d = community[*w];
+                if c == d {
+                    // internal edges are seen from both of their end points
+                    self_loops[c] += weight / 2.0;
+                } else {
+                    *links[c].entry(d).or_insert(0.0) += weight;
+                }
+            }
+        }
+        Self {
+            neighbors: links
+                .into_iter()
+                .map(|adj| adj.into_iter().collect())
+                .collect(),
+            self_loops,
+        }
+    }
+}
+
+/// Finds communities with the Louvain method, which greedily moves vertices
+/// between communities to increase the modularity, then contracts the
+/// communities and starts again on the contracted graph, until the
+/// modularity does not increase anymore.
+/// # Example
+/// ```
+/// use algods::graph::EdgeWeightedGraph;
+/// use algods::graph::processing::louvain;
+/// let mut graph = EdgeWeightedGraph::init(4);
+/// graph.add_edge(0, 1, 10);
+/// graph.add_edge(2, 3, 10);
+/// graph.add_edge(1, 2, 1);
+/// let communities = louvain(&graph);
+/// assert_eq!(communities.communities(), &[0, 0, 1, 1]);
+/// assert!(communities.modularity() > 0.4);
+/// ```
+pub fn louvain<G: WeightedNeighbors>(graph: &G) -> Communities {
+    let nb = graph.nb_vertices();
+    let mut level = LevelGraph {
+        neighbors: vec![Vec::new(); nb],
+        self_loops: vec![0.0; nb],
+    };
+    for v in 0..nb {
+        for (w, weight) in graph.weighted_neighbors(v) {
+            if w == v {
+                level.self_loops[v] += weight;
+            } else {
+                level.neighbors[v].push((w, weight));
+            }
+        }
+    }
+    let two_m = (0..nb).map(|v| level.strength(v)).sum::<f64>();
+    let mut labels = (0..nb).collect::<Vec<usize>>();
+    if two_m == 0.0 {
+        return Communities::from_labels(graph, &labels);
+    }
+    loop {
+        let (community, improved) = level.move_vertices(two_m);
+        if !improved {
+            break;
+        }
+        // renumbers the communities from 0
+        let mut renumber = HashMap::new();
+        let community = community
+            .iter()
+            .map(|c| {
+                let next = renumber.len();
+                *renumber.entry(*c).or_insert(next)
+            })
+            .collect::<Vec<usize>>();
+        for label in labels.iter_mut() {
+            *label = community[*label];
+        }
+        level = level.aggregate(&community);
+    }
+    Communities::from_labels(graph, &labels)
+}
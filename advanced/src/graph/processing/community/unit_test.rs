@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{label_propagation, louvain, modularity};
+    use crate::graph::{EdgeWeightedGraph, UndirectedGraph};
+
+    fn ring_of_cliques(nb_cliques: usize, size: usize) -> UndirectedGraph {
+        // cliques linked in a ring by one edge
+        let mut graph = UndirectedGraph::init(nb_cliques * size);
+        for c in 0..nb_cliques {
+            let first = c * size;
+            for i in 0..size {
+                for j in 0..i {
+                    graph.add_edge(first + i, first + j);
+                }
+            }
+            graph.add_edge(first, ((c + 1) % nb_cliques) * size + 1);
+        }
+        graph
+    }
+
+    #[test]
+    fn test_modularity() {
+        let mut graph = EdgeWeightedGraph::init(4);
+        graph.add_edge(0, 1, 1);
+        graph.add_edge(2, 3, 1);
+        assert!((modularity(&graph, &[0, 0, 1, 1]) - 0.5).abs() < 1e-12);
+        assert!((modularity(&graph, &[0, 0, 0, 0])).abs() < 1e-12);
+        assert!((modularity(&graph, &[0, 1, 0, 1]) + 0.5).abs() < 1e-12);
+        graph.add_edge(0, 0, 2);
+        assert!(modularity(&graph, &[0, 0, 1, 1]) > 0.0);
+        assert_eq!(modularity(&UndirectedGraph::init(3), &[0, 1, 2]), 0.0);
+    }
+
+    #[test]
+    fn test_label_propagation() {
+        let graph = ring_of_cliques(4, 5);
+        let communities = label_propagation(&graph, 7);
+        assert_eq!(communities.count(), 4);
+        for v in 0..20 {
+            assert_eq!(communities.community(v), v / 5);
+        }
+        assert!(
+            (communities.modularity() - modularity(&graph, communities.communities())).abs()
+                < 1e-12
+        );
+
+        let communities = label_propagation(&UndirectedGraph::init(3), 0);
+        assert_eq!(communities.communities(), &[0, 1, 2]);
+    }
+
+    #[test]
+    fn test_louvain() {
+        let graph = ring_of_cliques(6, 4);
+        let communities = louvain(&graph);
+        assert_eq!(communities.count(), 6);
+        for v in 0..24 {
+            assert_eq!(communities.community(v), v / 4);
+        }
+        // modularity of the ring of cliques with the cliques as communities
+        let m: f64 = 6.0 * 7.0;
+        let expected = 6.0 * (6.0 / m - (14.0 / (2.0 * m)).powi(2));
+        assert!((communities.modularity() - expected).abs() < 1e-12);
+
+        // weights decide how two triangles sharing heavy edges are grouped
+        let mut graph = EdgeWeightedGraph::init(6);
+        for (v, w, weight) in [
+            (0, 1, 5),
+            (1, 2, 5),
+            (2, 0, 5),
+            (3, 4, 5),
+            (4, 5, 5),
+            (5, 3, 5),
+        ] {
+            graph.add_edge(v, w, weight);
+        }
+        graph.add_edge(2, 3, 1);
+        graph.add_edge(0, 5, 1);
+        let communities = louvain(&graph);
+        assert_eq!(communities.communities(), &[0, 0, 0, 1, 1, 1]);
+
+        let communities = louvain(&EdgeWeightedGraph::<u8>::init(2));
+        assert_eq!(communities.count(), 2);
+        assert_eq!(communities.modularity(), 0.0);
+    }
+}
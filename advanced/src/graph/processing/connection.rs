@@ -1,6 +1,7 @@
 use crate::graph::processing::dfs;
 use crate::graph::processing::TopologicalSort;
-use crate::graph::{DirectedGraph, UndirectedGraph};
+use crate::graph::{DirectedGraph, UndirectedGraph, VertexInfo};
+use crate::search::{UnionFind, UnionFindAlgorithm};
 pub struct ConnectedComponent {
     // Aims at answering the question are two vertives v and w connected in contant time
     // after preprocessing the graph
@@ -22,7 +23,7 @@ impl ConnectedComponent {
             ran: false,
         }
     }
-    pub fn find_cc(&mut self, graph: &UndirectedGraph) {
+    pub fn find_cc<V, E>(&mut self, graph: &UndirectedGraph<V, E>) {
         // builds all the connected components from a graph
         let nb = graph.nb_vertices();
         for v in 0..nb {
@@ -73,24 +74,63 @@ impl StrongConnectedComponent {
             nb_scc: 0,
         }
     }
-    pub fn find_scc(&mut self, graph: &DirectedGraph) {
+    pub fn find_scc<V, E>(&mut self, graph: &DirectedGraph<V, E>) {
         // builds all the string connected components from a directed graph
 
-        // run dfs on the reverse graph
+        // run dfs on the reverse graph, whose edges carry no data
         let nb = graph.nb_vertices();
+        let mut reverse = DirectedGraph::init(nb);
+        for v in 0..nb {
+            for w in graph.vertex_edges(&v) {
+                reverse.add_edge(*w, v);
+            }
+        }
         let mut topo = TopologicalSort::init(nb);
-        topo.depth_first_order(&graph.reverse());
+        topo.depth_first_order(&reverse);
         let order_second_dfs = topo.reverse_postorder();
         // order_second_dfs.reverse();
         for v in 0..nb {
             let v = order_second_dfs[nb - 1 - v];
             if !self.marked[v] {
-                // run DFS for each vertex in each component
-                dfs(graph, &mut self.marked, &mut self.id, v, v, true, true);
+                // run DFS for each vertex in each component,
+                // components are numbered in the order they are found
+                self.id[v] = self.nb_scc;
+                dfs(
+                    graph,
+                    &mut self.marked,
+                    &mut self.id,
+                    v,
+                    self.nb_scc,
+                    true,
+                    true,
+                );
                 self.nb_scc += 1;
             }
         }
     }
+    /// Gives the identifier of the strong connected component of a vertex.
+    /// Components are numbered from 0 in reverse topological order, that is
+    /// any edge between two different components goes from a component to
+    /// another one with a smaller identifier.
+    pub fn id(&self, v: usize) -> Option<usize> {
+        if !self.marked[v] {
+            return None;
+        }
+        Some(self.id[v])
+    }
+    /// Builds the kernel DAG of a directed graph, where each
+    /// strong connected component is contracted into one vertex
+    pub fn condensation<V, E>(&self, graph: &DirectedGraph<V, E>) -> DirectedGraph {
+        let mut dag = DirectedGraph::init(self.nb_scc);
+        for v in 0..graph.nb_vertices() {
+            for w in graph.vertex_edges(&v) {
+                if self.id[v] != self.id[*w] {
+                    dag.add_edge(self.id[v], self.id[*w]);
+                }
+            }
+        }
+        dag
+    }
     pub fn connected(&self, v: usize, w: usize) -> Option<bool> {
         // finds out whether or not two vertices are in the same strong connected component
         // run time complexity O(1)
@@ -103,3 +143,99 @@ impl StrongConnectedComponent {
         self.nb_scc
     }
 }
+
+/// Implementation of the connectivity of an undirected graph growing
+/// over time, where connected components are maintained with a union-find
+/// structure as vertices and edges are added.
+/// # Example
+/// ```
+/// use algods::graph::processing::DynamicConnectivity;
+/// let mut connectivity = DynamicConnectivity::init(3);
+/// connectivity.add_edge(0, 1);
+/// assert!(connectivity.connected(0, 1));
+/// assert!(!connectivity.connected(1, 2));
+/// let v = connectivity.add_vertex();
+/// connectivity.add_edge(2, v);
+/// assert_eq!(connectivity.count(), 2);
+/// assert_eq!(connectivity.history(), &[3, 2, 3, 2]);
+/// ```
+pub struct DynamicConnectivity {
+    // the graph being built
+    graph: UndirectedGraph,
+    // connection information of the vertices
+    uf: UnionFind,
+    // number of connected components
+    nb_cc: usize,
+    // number of connected components initially then after each addition
+    history: Vec<usize>,
+}
+impl DynamicConnectivity {
+    /// Creates a graph with `nb_vertices` unconnected vertices
+    pub fn init(nb_vertices: usize) -> Self {
+        Self {
+            graph: UndirectedGraph::init(nb_vertices),
+            uf: UnionFind::with_capacity(
+                nb_vertices,
+                UnionFindAlgorithm::WeightedQuickUnionPathComp,
+            ),
+            nb_cc: nb_vertices,
+            history: vec![nb_vertices],
+        }
+    }
+    /// Starts from the connected components of an existing graph
+    pub fn from_graph(graph: UndirectedGraph) -> Self {
+        // run time complexity O(V + E log*(V))
+        let nb = graph.nb_vertices();
+        let mut connectivity = Self::init(nb);
+        for v in 0..nb {
+            for w in VertexInfo::vertex_edges(&graph, &v) {
+                connectivity.union(v, *w);
+            }
+        }
+        connectivity.history = vec![connectivity.nb_cc];
+        connectivity.graph = graph;
+        connectivity
+    }
+    fn union(&mut self, v: usize, w: usize) {
+        if !self.uf.connected(v, w) {
+            self.uf.union(v, w);
+            self.nb_cc -= 1;
+        }
+    }
+    /// Adds a new isolated vertex and returns its index
+    pub fn add_vertex(&mut self) -> usize {
+        // run time complexity O(1) amortized
+        self.graph.add_vertex();
+        self.nb_cc += 1;
+        self.history.push(self.nb_cc);
+        self.uf.add_object()
+    }
+    /// Adds an edge between two vertices, merging their components if needed
+    pub fn add_edge(&mut self, v: usize, w: usize) {
+        // run time complexity O(log*(V)) ~ O(1)
+        self.graph.add_edge(v, w);
+        self.union(v, w);
+        self.history.push(self.nb_cc);
+    }
+    pub fn connected(&mut self, v: usize, w: usize) -> bool {
+        // finds out whether or not two vertices are connected
+        // run time complexity O(log*(V)) ~ O(1)
+        self.uf.connected(v, w)
+    }
+    pub fn count(&self) -> usize {
+        // run time complexity O(1)
+        self.nb_cc
+    }
+    /// Gives the number of connected components initially,
+    /// then after each addition of a vertex or an edge
+    pub fn history(&self) -> &[usize] {
+        &self.history
+    }
+    /// Gives the number of connected components after a given number of additions
+    pub fn count_at(&self, step: usize) -> Option<usize> {
+        self.history.get(step).copied()
+    }
+    pub fn graph(&self) -> &UndirectedGraph {
+        &self.graph
+    }
+}
@@ -0,0 +1,318 @@
+#[cfg(test)]
+mod unit_test;
+use crate::graph::DirectedGraph;
+
+/// Enumeration of the algorithms computing immediate dominators
+#[derive(Default)]
+pub enum DominatorAlgo {
+    /// Lengauer and Tarjan's algorithm with path compression,
+    /// running in O(E log(V))
+    #[default]
+    LengauerTarjan,
+    /// Cooper, Harvey and Kennedy's iterative algorithm, simpler
+    /// but quadratic in the worst case
+    CooperHarveyKennedy,
+}
+
+/// Implementation of the dominators of a flow graph, a vertex d dominating a
+/// vertex v when all the paths from the entry to v go through d. The
+/// immediate dominator of v is its closest strict dominator, which is its
+/// parent in the dominator tree rooted at the entry. The dominance frontier
+/// of d is made of the vertices v which d does not strictly dominate,
+/// although d dominates a predecessor of v.
+/// # Example
+/// ```
+/// use algods::graph::DirectedGraph;
+/// use algods::graph::processing::{DominatorAlgo, Dominators};
+/// let mut graph = DirectedGraph::init(4);
+/// // if-then-else: 0 branches to 1 and 2, which join in 3
+/// graph.add_edge(0, 1);
+/// graph.add_edge(0, 2);
+/// graph.add_edge(1, 3);
+/// graph.add_edge(2, 3);
+/// let mut dominators = Dominators::init(DominatorAlgo::LengauerTarjan, graph.nb_vertices());
+/// dominators.find_dominators(&graph, 0);
+/// assert_eq!(dominators.idoms(), &[None, Some(0), Some(0), Some(0)]);
+/// assert!(dominators.dominates(0, 3));
+/// assert!(!dominators.dominates(1, 3));
+/// assert_eq!(dominators.frontier(1), &[3]);
+/// ```
+pub struct Dominators {
+    // the algorithm used to compute the immediate dominators
+    algo: DominatorAlgo,
+    // immediate dominator of each vertex, None for the entry
+    // and the vertices which are not reachable from it
+    idom: Vec<Option<usize>>,
+    // whether or not each vertex is reachable from the entry
+    reachable: Vec<bool>,
+    // preorder and postorder numbers in the dominator tree
+    pre: Vec<usize>,
+    post: Vec<usize>,
+    // sorted dominance frontier of each vertex
+    frontier: Vec<Vec<usize>>,
+}
+impl Dominators {
+    pub fn init(algorithm: DominatorAlgo, nb_vertices: usize) -> Self {
+        Self {
+            algo: algorithm,
+            idom: vec![None; nb_vertices],
+            reachable: vec![false; nb_vertices],
+            pre: vec![0; nb_vertices],
+            post: vec![0; nb_vertices],
+            frontier: vec![Vec::new(); nb_vertices],
+        }
+    }
+    pub fn find_dominators<V, E>(&mut self, graph: &DirectedGraph<V, E>, entry: usize) {
+        let nb = graph.nb_vertices();
+        assert_eq!(nb, self.idom.len());
+        assert!(entry < nb);
+        // vertices reachable from the entry in depth first preorder and postorder
+        let (preorder, parent, postorder) = depth_first_orders(graph, entry);
+        self.reachable = vec![false; nb];
+        for v in &preorder {
+            self.reachable[*v] = true;
+        }
+        self.idom = match self.algo {
+            DominatorAlgo::LengauerTarjan => lengauer_tarjan(graph, &preorder, &parent),
+            DominatorAlgo::CooperHarveyKennedy => cooper_harvey_kennedy(graph, &postorder),
+        };
+        self.number_tree();
+        self.find_frontiers(graph);
+    }
+    fn number_tree(&mut self) {
+        // numbers the dominator tree to answer dominance queries in O(1)
+        let tree = self.dominator_tree();
+        let mut counter = 0;
+        let mut marked = vec![false; tree.nb_vertices()];
+        for root in (0..tree.nb_vertices()).filter(|v| self.reachable[*v]) {
+            if self.idom[root].is_some() || marked[root] {
+                continue;
+            }
+            marked[root] = true;
+            self.pre[root] = counter;
+            counter += 1;
+            let mut stack = vec![(root, tree.vertex_edges(&root))];
+            while let Some((v, children)) = stack.last_mut() {
+                match children.pop() {
+                    Some(w) => {
+                        let w = *w;
+                        marked[w] = true;
+                        self.pre[w] = counter;
+                        counter += 1;
+                        stack.push((w, tree.vertex_edges(&w)));
+                    }
+                    None => {
+                        self.post[*v] = counter;
+                        counter += 1;
+                        stack.pop();
+                    }
+                }
+            }
+        }
+    }
+    fn find_frontiers<V, E>(&mut self, graph: &DirectedGraph<V, E>) {
+        // walks up the dominator tree from the predecessors of each vertex
+        // to its immediate dominator (excluded)
+        let nb = graph.nb_vertices();
+        self.frontier = vec![Vec::new(); nb];
+        for v in (0..nb).filter(|v| self.reachable[*v]) {
+            for u in graph.in_edges(&v).iter().filter(|u| self.reachable[**u]) {
+                let mut runner = Some(*u);
+                while let Some(r) = runner {
+                    if Some(r) == self.idom[v] {
+                        break;
+                    }
+                    self.frontier[r].push(v);
+                    runner = self.idom[r];
+                }
+            }
+        }
+        for frontier in self.frontier.iter_mut() {
+            frontier.sort_unstable();
+            frontier.dedup();
+        }
+    }
+    /// Gives the immediate dominator of a vertex, `None` for
+    /// the entry and the vertices which are not reachable from it
+    pub fn idom(&self, v: usize) -> Option<usize> {
+        self.idom[v]
+    }
+    /// Gives the immediate dominator of each vertex
+    pub fn idoms(&self) -> &[Option<usize>] {
+        &self.idom
+    }
+    /// Whether or not d dominates v, each reachable vertex dominating itself
+    pub fn dominates(&self, d: usize, v: usize) -> bool {
+        self.reachable[d]
+            && self.reachable[v]
+            && self.pre[d] <= self.pre[v]
+            && self.post[v] <= self.post[d]
+    }
+    /// Builds the dominator tree, with an edge from the immediate
+    /// dominator of each vertex to the vertex
+    pub fn dominator_tree(&self) -> DirectedGraph {
+        let mut tree = DirectedGraph::init(self.idom.len());
+        for (v, d) in self.idom.iter().enumerate() {
+            if let Some(d) = d {
+                tree.add_edge(*d, v);
+            }
+        }
+        tree
+    }
+    /// Gives the dominance frontier of a vertex, in increasing order
+    pub fn frontier(&self, v: usize) -> &[usize] {
+        &self.frontier[v]
+    }
+}
+
+fn depth_first_orders<V, E>(
+    graph: &DirectedGraph<V, E>,
+    entry: usize,
+) -> (Vec<usize>, Vec<usize>, Vec<usize>) {
+    // gives the vertices reachable from the entry in preorder, the
+    // parent of each vertex in the search tree and the postorder
+    let nb = graph.nb_vertices();
+    let mut marked = vec![false; nb];
+    let mut parent = vec![entry; nb];
+    let (mut preorder, mut postorder) = (Vec::new(), Vec::new());
+    marked[entry] = true;
+    preorder.push(entry);
+    let mut stack = vec![(entry, graph.vertex_edges(&entry))];
+    while let Some((v, adjacent)) = stack.last_mut() {
+        let v = *v;
+        match adjacent.pop() {
+            Some(w) => {
+                let w = *w;
+                if !marked[w] {
+                    marked[w] = true;
+                    parent[w] = v;
+                    preorder.push(w);
+                    stack.push((w, graph.vertex_edges(&w)));
+                }
+            }
+            None => {
+                postorder.push(v);
+                stack.pop();
+            }
+        }
+    }
+    (preorder, parent, postorder)
+}
+
+fn lengauer_tarjan<V, E>(
+    graph: &DirectedGraph<V, E>,
+    preorder: &[usize],
+    parent: &[usize],
+) -> Vec<Option<usize>> {
+    // run time complexity O(E log(V))
+    let nb = graph.nb_vertices();
+    // preorder number of each reachable vertex
+    let mut number = vec![usize::MAX; nb];
+    for (i, v) in preorder.iter().enumerate() {
+        number[*v] = i;
+    }
+    // semi dominator (as a preorder number) of each vertex, and forest
+    // of the processed vertices where label is the vertex with the
+    // smallest semi dominator on the path to the root
+    let mut semi = number.clone();
+    let mut ancestor: Vec<Option<usize>> = vec![None; nb];
+    let mut label = (0..nb).collect::<Vec<usize>>();
+    let mut bucket = vec![Vec::new(); nb];
+    let mut idom = vec![None; nb];
+
+    let eval = |v: usize, ancestor: &mut [Option<usize>], label: &mut [usize], semi: &[usize]| {
+        // compresses the path from v to the root of its tree
+        let mut stack = Vec::new();
+        let mut x = v;
+        while let Some(a) = ancestor[x] {
+            if ancestor[a].is_none() {
+                break;
+            }
+            stack.push(x);
+            x = a;
+        }
+        while let Some(y) = stack.pop() {
+            let a = ancestor[y].expect("y is not a root");
+            if semi[label[a]] < semi[label[y]] {
+                label[y] = label[a];
+            }
+            ancestor[y] = ancestor[a];
+        }
+        label[v]
+    };
+
+    for w in preorder.iter().skip(1).rev() {
+        let w = *w;
+        for v in graph.in_edges(&w) {
+            if number[*v] == usize::MAX {
+                continue;
+            }
+            let u = eval(*v, &mut ancestor, &mut label, &semi);
+            if semi[u] < semi[w] {
+                semi[w] = semi[u];
+            }
+        }
+        bucket[preorder[semi[w]]].push(w);
+        let p = parent[w];
+        ancestor[w] = Some(p);
+        for v in std::mem::take(&mut bucket[p]) {
+            let u = eval(v, &mut ancestor, &mut label, &semi);
+            idom[v] = Some(if semi[u] < semi[v] { u } else { p });
+        }
+    }
+    for w in preorder.iter().skip(1) {
+        let w = *w;
+        if idom[w] != Some(preorder[semi[w]]) {
+            idom[w] = idom[idom[w].expect("w is processed")];
+        }
+    }
+    idom
+}
+
+fn cooper_harvey_kennedy<V, E>(
+    graph: &DirectedGraph<V, E>,
+    postorder: &[usize],
+) -> Vec<Option<usize>> {
+    // iterates over the vertices in reverse postorder until a fixed point
+    // run time complexity O(V(V + E)) in the worst case
+    let nb = graph.nb_vertices();
+    let mut number = vec![usize::MAX; nb];
+    for (i, v) in postorder.iter().enumerate() {
+        number[*v] = i;
+    }
+    let entry = *postorder.last().expect("the entry is visited");
+    let mut idom = vec![None; nb];
+    idom[entry] = Some(entry);
+    let intersect = |idom: &[Option<usize>], mut a: usize, mut b: usize| {
+        while a != b {
+            while number[a] < number[b] {
+                a = idom[a].expect("a is processed");
+            }
+            while number[b] < number[a] {
+                b = idom[b].expect("b is processed");
+            }
+        }
+        a
+    };
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for v in postorder.iter().rev().skip(1) {
+            let mut new_idom = None;
+            for u in graph.in_edges(v) {
+                if idom[*u].is_some() {
+                    new_idom = Some(match new_idom {
+                        None => *u,
+                        Some(d) => intersect(&idom, *u, d),
+                    });
+                }
+            }
+            if new_idom != idom[*v] {
+                idom[*v] = new_idom;
+                changed = true;
+            }
+        }
+    }
+    idom[entry] = None;
+    idom
+}
@@ -0,0 +1,123 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{DominatorAlgo, Dominators};
+    use crate::graph::DirectedGraph;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn dominators(graph: &DirectedGraph, entry: usize, algo: DominatorAlgo) -> Dominators {
+        let mut dominators = Dominators::init(algo, graph.nb_vertices());
+        dominators.find_dominators(graph, entry);
+        dominators
+    }
+
+    fn reachable_without(graph: &DirectedGraph, entry: usize, removed: usize) -> Vec<bool> {
+        let mut marked = vec![false; graph.nb_vertices()];
+        if entry == removed {
+            return marked;
+        }
+        let mut stack = vec![entry];
+        marked[entry] = true;
+        while let Some(v) = stack.pop() {
+            for w in graph.vertex_edges(&v) {
+                if *w != removed && !marked[*w] {
+                    marked[*w] = true;
+                    stack.push(*w);
+                }
+            }
+        }
+        marked
+    }
+
+    #[test]
+    fn test_lengauer_tarjan_example() {
+        // example of Lengauer and Tarjan's paper, R = 0, A = 1, ..., L = 12
+        let mut graph = DirectedGraph::init(13);
+        for (v, w) in [
+            (0, 1),
+            (0, 2),
+            (0, 3),
+            (1, 4),
+            (2, 1),
+            (2, 4),
+            (2, 5),
+            (3, 6),
+            (3, 7),
+            (4, 12),
+            (5, 8),
+            (6, 9),
+            (7, 9),
+            (7, 10),
+            (8, 5),
+            (8, 11),
+            (9, 11),
+            (10, 9),
+            (11, 0),
+            (11, 9),
+            (12, 8),
+        ] {
+            graph.add_edge(v, w);
+        }
+        let expected = [
+            None,
+            Some(0),
+            Some(0),
+            Some(0),
+            Some(0),
+            Some(0),
+            Some(3),
+            Some(3),
+            Some(0),
+            Some(0),
+            Some(7),
+            Some(0),
+            Some(4),
+        ];
+        for algo in [
+            DominatorAlgo::LengauerTarjan,
+            DominatorAlgo::CooperHarveyKennedy,
+        ] {
+            let dominators = dominators(&graph, 0, algo);
+            assert_eq!(dominators.idoms(), &expected);
+            assert_eq!(dominators.dominator_tree().nb_edges(), 12);
+            assert!(dominators.dominates(3, 10));
+            assert!(!dominators.dominates(7, 9));
+            assert_eq!(dominators.frontier(3), &[9]);
+            assert_eq!(dominators.frontier(5), &[8]);
+            assert_eq!(dominators.frontier(8), &[5, 11]);
+            assert_eq!(dominators.frontier(11), &[0, 9]);
+        }
+    }
+
+    #[test]
+    fn test_dominators_random() {
+        // compares both algorithms with the definition: d dominates v when
+        // v is not reachable anymore once d is removed
+        let mut rng = StdRng::seed_from_u64(5);
+        for _ in 0..100 {
+            let nb = rng.gen_range(1..15);
+            let mut graph = DirectedGraph::init(nb);
+            for _ in 0..rng.gen_range(0..3 * nb) {
+                graph.add_edge(rng.gen_range(0..nb), rng.gen_range(0..nb));
+            }
+            let entry = rng.gen_range(0..nb);
+            let lt = dominators(&graph, entry, DominatorAlgo::LengauerTarjan);
+            let chk = dominators(&graph, entry, DominatorAlgo::CooperHarveyKennedy);
+            assert_eq!(lt.idoms(), chk.idoms());
+            let reachable = reachable_without(&graph, entry, nb);
+            for d in 0..nb {
+                let without = reachable_without(&graph, entry, d);
+                for v in 0..nb {
+                    let expected = reachable[d] && reachable[v] && (d == v || !without[v]);
+                    assert_eq!(lt.dominates(d, v), expected);
+                }
+                assert_eq!(lt.frontier(d), chk.frontier(d));
+                for v in lt.frontier(d) {
+                    // d dominates a predecessor of v but does not strictly dominate v
+                    assert!(!lt.dominates(d, *v) || d == *v);
+                    assert!(graph.in_edges(v).iter().any(|u| lt.dominates(d, *u)));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,6 @@
+mod hungarian;
+mod stable;
+#[cfg(test)]
+mod unit_test;
+pub use hungarian::{hungarian, Assignment, Cost, Objective};
+pub use stable::{hospitals_residents, is_stable, stable_marriage, Optimality, StableMatching};
@@ -0,0 +1,277 @@
+/// This trait gives the operations on the entries of a cost matrix
+/// needed by the Hungarian algorithm, it is implemented by the
+/// integer types and the floats (which must not be NaN)
+pub trait Cost:
+    Copy + std::ops::Add<Output = Self> + std::ops::Sub<Output = Self> + PartialOrd
+{
+    fn zero() -> Self;
+    fn infinity() -> Self;
+    /// Gives the cost as an i128 for the integer types, on which the
+    /// potentials are computed without overflow, `None` otherwise
+    fn to_i128(self) -> Option<i128> {
+        None
+    }
+}
+
+macro_rules! impl_cost {
+    ($TYPE:ty, $INFINITY:expr) => {
+        impl Cost for $TYPE {
+            fn zero() -> Self {
+                0 as $TYPE
+            }
+
+            fn infinity() -> Self {
+                $INFINITY
+            }
+        }
+    };
+    ($TYPE:ty) => {
+        impl Cost for $TYPE {
+            fn zero() -> Self {
+                0
+            }
+
+            fn infinity() -> Self {
+                <$TYPE>::MAX
+            }
+
+            fn to_i128(self) -> Option<i128> {
+                Some(self as i128)
+            }
+        }
+    };
+}
+
+impl_cost!(u8);
+impl_cost!(u16);
+impl_cost!(u32);
+impl_cost!(u64);
+impl_cost!(usize);
+
+impl_cost!(i8);
+impl_cost!(i16);
+impl_cost!(i32);
+impl_cost!(i64);
+impl_cost!(isize);
+impl_cost!(i128);
+
+impl_cost!(f32, f32::INFINITY);
+impl_cost!(f64, f64::INFINITY);
+
+/// Enumeration of the objectives of the assignment problem
+#[derive(Default, Clone, Copy)]
+pub enum Objective {
+    /// Finds an assignment with the smallest total cost
+    #[default]
+    Minimize,
+    /// Finds an assignment with the largest total cost
+    Maximize,
+}
+
+/// Optimal assignment of the rows (agents) of a cost matrix to its columns
+/// (tasks). Each row gets at most one column and each column at most one row,
+/// as many rows as possible being assigned.
+#[derive(Debug)]
+pub struct Assignment<C> {
+    // column assigned to each row
+    column: Vec<Option<usize>>,
+    cost: C,
+}
+impl<C: Cost> Assignment<C> {
+    /// Gives the column assigned to a row, if any
+    pub fn column(&self, row: usize) -> Option<usize> {
+        self.column[row]
+    }
+    /// Gives the column assigned to each row
+    pub fn columns(&self) -> &[Option<usize>] {
+        &self.column
+    }
+    /// Gives the assigned (row, column) pairs, by increasing row
+    pub fn pairs(&self) -> Vec<(usize, usize)> {
+        self.column
+            .iter()
+            .enumerate()
+            .filter_map(|(row, col)| col.map(|col| (row, col)))
+            .collect()
+    }
+    /// Gives the total cost of the assignment
+    pub fn cost(&self) -> C {
+        self.cost
+    }
+}
+
+/// Solves the weighted assignment problem on a (possibly rectangular) cost
+/// matrix with the Hungarian (Kuhn-Munkres) algorithm, in its O(n^2 m)
+/// version with potentials, for n rows and m >= n columns (the matrix is
+/// transposed otherwise). Panics if the rows do not have the same length.
+/// The potentials of integer costs are computed on i128, so that only the
+/// total cost of the assignment must fit in the type of the costs.
+/// # Example
+/// ```
+/// use algods::graph::processing::{hungarian, Objective};
+/// let costs = vec![vec![4, 1, 3], vec![2, 0, 5], vec![3, 2, 2]];
+/// let assignment = hungarian(&costs, Objective::Minimize);
+/// assert_eq!(assignment.cost(), 5);
+/// assert_eq!(assignment.columns(), &[Some(1), Some(0), Some(2)]);
+/// let assignment = hungarian(&costs, Objective::Maximize);
+/// assert_eq!(assignment.cost(), 11);
+/// ```
+pub fn hungarian<C: Cost>(costs: &[Vec<C>], objective: Objective) -> Assignment<C> {
+    let nb_rows = costs.len();
+    let nb_cols = costs.first().map_or(0, |row| row.len());
+    assert!(
+        costs.iter().all(|row| row.len() == nb_cols),
+        "the rows of the cost matrix must have the same length"
+    );
+    if nb_rows == 0 || nb_cols == 0 {
+        return Assignment {
+            column: vec![None; nb_rows],
+            cost: C::zero(),
+        };
+    }
+    let wide = costs
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|c| c.to_i128())
+                .collect::<Option<Vec<i128>>>()
+        })
+        .collect::<Option<Vec<Vec<i128>>>>();
+    let column = match wide {
+        Some(wide) => assign(&wide, objective),
+        None => assign(costs, objective),
+    };
+    let cost = total(
+        column
+            .iter()
+            .enumerate()
+            .filter_map(|(row, col)| col.map(|col| costs[row][col])),
+    );
+    Assignment { column, cost }
+}
+
+fn total<C: Cost, I: Iterator<Item = C>>(costs: I) -> C {
+    // adds a negative cost to a nonnegative total and a nonnegative cost to
+    // a negative one while there are both, so that the partial sums only
+    // overflow when the total does
+    let (mut negative, mut nonnegative): (Vec<C>, Vec<C>) = costs.partition(|c| *c < C::zero());
+    let mut sum = C::zero();
+    loop {
+        let next = if sum < C::zero() {
+            nonnegative.pop().or_else(|| negative.pop())
+        } else {
+            negative.pop().or_else(|| nonnegative.pop())
+        };
+        match next {
+            Some(c) => sum = sum + c,
+            None => return sum,
+        }
+    }
+}
+
+fn assign<C: Cost>(costs: &[Vec<C>], objective: Objective) -> Vec<Option<usize>> {
+    // column assigned to each row of a nonempty cost matrix
+    let (nb_rows, nb_cols) = (costs.len(), costs[0].len());
+    let mut column = vec![None; nb_rows];
+    // maximizing the costs amounts to minimizing their
+    // (non negative) differences with the largest cost
+    let largest = costs
+        .iter()
+        .flatten()
+        .fold(costs[0][0], |max, c| if *c > max { *c } else { max });
+    let entry = |row: usize, col: usize| match objective {
+        Objective::Minimize => costs[row][col],
+        Objective::Maximize => largest - costs[row][col],
+    };
+    if nb_rows <= nb_cols {
+        for (col, row) in solve(nb_rows, nb_cols, entry).into_iter().enumerate() {
+            if let Some(row) = row {
+                column[row] = Some(col);
+            }
+        }
+    } else {
+        let transposed = |row: usize, col: usize| entry(col, row);
+        for (row, col) in solve(nb_cols, nb_rows, transposed).into_iter().enumerate() {
+            if let Some(col) = col {
+                column[row] = Some(col);
+            }
+        }
+    }
+    column
+}
+
+fn reduced_cost<C: Cost>(cost: C, u: C, minus_v: C) -> C {
+    // cost + minus_v - u without an intermediate sum, which could overflow
+    // even when the result fits, C::infinity() standing for larger values
+    if u >= minus_v {
+        cost - (u - minus_v)
+    } else if cost > C::zero() && minus_v - u > C::infinity() - cost {
+        C::infinity()
+    } else {
+        cost + (minus_v - u)
+    }
+}
+
+fn solve<C: Cost, F: Fn(usize, usize) -> C>(n: usize, m: usize, cost: F) -> Vec<Option<usize>> {
+    // assigns each of the n rows to one of the m >= n columns with minimum
+    // total cost, returns the row assigned to each column.
+    // Rows and columns are numbered from 1, the column 0 being fictitious.
+    // The reduced costs cost + minus_v - u stay non negative, u being the
+    // potentials of the rows and minus_v the opposites of the potentials of
+    // the columns, which only increase from zero for nonnegative costs and
+    // allows unsigned costs.
+    // run time complexity O(n^2 m)
+    let mut u = vec![C::zero(); n + 1];
+    let mut minus_v = vec![C::zero(); m + 1];
+    // row assigned to each column, 0 when there is none
+    let mut row_of = vec![0; m + 1];
+    // previous column on the alternating path
+    let mut way = vec![0; m + 1];
+    for i in 1..=n {
+        row_of[0] = i;
+        let mut j0 = 0;
+        let mut min_reduced = vec![C::infinity(); m + 1];
+        let mut used = vec![false; m + 1];
+        loop {
+            // grows the alternating tree by the column with the
+            // smallest reduced cost, updating the potentials
+            used[j0] = true;
+            let i0 = row_of[j0];
+            let mut delta = C::infinity();
+            let mut j1 = 0;
+            for j in (1..=m).filter(|j| !used[*j]) {
+                let reduced = reduced_cost(cost(i0 - 1, j - 1), u[i0], minus_v[j]);
+                if reduced < min_reduced[j] {
+                    min_reduced[j] = reduced;
+                    way[j] = j0;
+                }
+                if min_reduced[j] < delta {
+                    delta = min_reduced[j];
+                    j1 = j;
+                }
+            }
+            for j in 0..=m {
+                if used[j] {
+                    u[row_of[j]] = u[row_of[j]] + delta;
+                    minus_v[j] = minus_v[j] + delta;
+                } else {
+                    min_reduced[j] = min_reduced[j] - delta;
+                }
+            }
+            j0 = j1;
+            if row_of[j0] == 0 {
+                break;
+            }
+        }
+        // augments along the alternating path ending at the free column j0
+        while j0 != 0 {
+            let j1 = way[j0];
+            row_of[j0] = row_of[j1];
+            j0 = j1;
+        }
+    }
+    row_of[1..]
+        .iter()
+        .map(|i| if *i == 0 { None } else { Some(i - 1) })
+        .collect()
+}
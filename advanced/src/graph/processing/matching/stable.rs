@@ -0,0 +1,257 @@
+use std::collections::VecDeque;
+
+/// Enumeration of the stable matchings found by the Gale-Shapley algorithm
+#[derive(Default, Clone, Copy)]
+pub enum Optimality {
+    /// Each proposer gets its best partner among all the stable matchings
+    #[default]
+    ProposerOptimal,
+    /// Each receiver gets its best partners among all the stable matchings,
+    /// the receivers proposing in the algorithm
+    ReceiverOptimal,
+}
+
+/// Stable matching of proposers (e.g. residents) to receivers (e.g.
+/// hospitals), each proposer getting at most one receiver and each receiver
+/// at most its capacity of proposers (one for the stable marriage)
+#[derive(Debug)]
+pub struct StableMatching {
+    // receiver of each proposer
+    receiver: Vec<Option<usize>>,
+    // proposers of each receiver, sorted
+    proposers: Vec<Vec<usize>>,
+}
+impl StableMatching {
+    fn init(receiver: Vec<Option<usize>>, nb_receivers: usize) -> Self {
+        let mut proposers = vec![Vec::new(); nb_receivers];
+        for (p, r) in receiver.iter().enumerate() {
+            if let Some(r) = r {
+                proposers[*r].push(p);
+            }
+        }
+        Self {
+            receiver,
+            proposers,
+        }
+    }
+    /// Gives the receiver matched to a proposer, if any
+    pub fn receiver(&self, proposer: usize) -> Option<usize> {
+        self.receiver[proposer]
+    }
+    /// Gives the receiver matched to each proposer
+    pub fn receivers(&self) -> &[Option<usize>] {
+        &self.receiver
+    }
+    /// Gives the proposers matched to a receiver, sorted
+    pub fn proposers(&self, receiver: usize) -> &[usize] {
+        &self.proposers[receiver]
+    }
+    /// Gives the matched (proposer, receiver) pairs, by increasing proposer
+    pub fn pairs(&self) -> Vec<(usize, usize)> {
+        self.receiver
+            .iter()
+            .enumerate()
+            .filter_map(|(p, r)| r.map(|r| (p, r)))
+            .collect()
+    }
+}
+
+fn ranks(preferences: &[Vec<usize>], nb_others: usize) -> Vec<Vec<Option<usize>>> {
+    // position of each member of the other side in a preference list,
+    // None when it is not acceptable
+    preferences
+        .iter()
+        .map(|list| {
+            let mut rank = vec![None; nb_others];
+            for (position, other) in list.iter().enumerate() {
+                assert!(rank[*other].is_none(), "{other} is listed twice");
+                rank[*other] = Some(position);
+            }
+            rank
+        })
+        .collect()
+}
+
+/// Function that finds a stable matching between proposers and receivers
+/// (stable marriage) from their preference lists, best partner first,
+/// with the Gale-Shapley algorithm. A member left out of a list is not
+/// acceptable to its owner. No proposer and receiver which are not matched
+/// together would both rather be matched together.
+/// # Example
+/// ```
+/// use algods::graph::processing::{is_stable, stable_marriage, Optimality};
+/// let proposers = vec![vec![0, 1, 2], vec![1, 0, 2], vec![0, 1, 2]];
+/// let receivers = vec![vec![1, 0, 2], vec![0, 1, 2], vec![0, 1, 2]];
+/// let matching = stable_marriage(&proposers, &receivers, Optimality::ProposerOptimal);
+/// assert_eq!(matching.receivers(), &[Some(0), Some(1), Some(2)]);
+/// let matching = stable_marriage(&proposers, &receivers, Optimality::ReceiverOptimal);
+/// assert_eq!(matching.receivers(), &[Some(1), Some(0), Some(2)]);
+/// assert!(is_stable(&proposers, &receivers, &[1, 1, 1], matching.receivers()));
+/// ```
+pub fn stable_marriage(
+    proposers: &[Vec<usize>],
+    receivers: &[Vec<usize>],
+    optimality: Optimality,
+) -> StableMatching {
+    hospitals_residents(proposers, receivers, &vec![1; receivers.len()], optimality)
+}
+
+/// Function that finds a stable matching of residents (proposers) to
+/// hospitals (receivers) with capacities, from their preference lists, best
+/// partner first, with the Gale-Shapley algorithm. A member left out of a
+/// list is not acceptable to its owner. No resident and hospital which are
+/// not matched together would both rather be matched together, a hospital
+/// with a free place preferring any acceptable resident to none.
+/// # Example
+/// ```
+/// use algods::graph::processing::{hospitals_residents, is_stable, Optimality};
+/// let residents = vec![vec![0, 1], vec![0, 1], vec![0], vec![1, 0]];
+/// let hospitals = vec![vec![3, 1, 0, 2], vec![0, 2, 1, 3]];
+/// let capacities = [2, 1];
+/// let optimality = Optimality::ProposerOptimal;
+/// let matching = hospitals_residents(&residents, &hospitals, &capacities, optimality);
+/// assert_eq!(matching.proposers(0), &[0, 1]);
+/// assert_eq!(matching.proposers(1), &[3]);
+/// assert_eq!(matching.receiver(2), None);
+/// assert!(is_stable(&residents, &hospitals, &capacities, matching.receivers()));
+/// ```
+pub fn hospitals_residents(
+    residents: &[Vec<usize>],
+    hospitals: &[Vec<usize>],
+    capacities: &[usize],
+    optimality: Optimality,
+) -> StableMatching {
+    // at most RH proposals for R residents and H hospitals
+    assert_eq!(hospitals.len(), capacities.len());
+    let resident_rank = ranks(residents, hospitals.len());
+    let hospital_rank = ranks(hospitals, residents.len());
+    let hospital_of = match optimality {
+        Optimality::ProposerOptimal => {
+            residents_propose(residents, &hospital_rank, capacities, hospitals.len())
+        }
+        Optimality::ReceiverOptimal => hospitals_propose(hospitals, &resident_rank, capacities),
+    };
+    StableMatching::init(hospital_of, hospitals.len())
+}
+
+fn residents_propose(
+    residents: &[Vec<usize>],
+    hospital_rank: &[Vec<Option<usize>>],
+    capacities: &[usize],
+    nb_hospitals: usize,
+) -> Vec<Option<usize>> {
+    // the free residents propose to the next hospital of their list, which
+    // keeps its best acceptable residents up to its capacity
+    let mut hospital_of = vec![None; residents.len()];
+    let mut next = vec![0; residents.len()];
+    // residents held by each hospital, by decreasing rank (worst first)
+    let mut held: Vec<Vec<usize>> = vec![Vec::new(); nb_hospitals];
+    let mut free = (0..residents.len()).collect::<VecDeque<usize>>();
+    while let Some(r) = free.pop_front() {
+        let Some(h) = residents[r].get(next[r]).copied() else {
+            continue;
+        };
+        next[r] += 1;
+        let Some(rank) = hospital_rank[h][r] else {
+            free.push_back(r);
+            continue;
+        };
+        let position = held[h]
+            .iter()
+            .position(|other| hospital_rank[h][*other] < Some(rank))
+            .unwrap_or(held[h].len());
+        held[h].insert(position, r);
+        hospital_of[r] = Some(h);
+        if held[h].len() > capacities[h] {
+            let rejected = held[h].remove(0);
+            hospital_of[rejected] = None;
+            free.push_back(rejected);
+        }
+    }
+    hospital_of
+}
+
+fn hospitals_propose(
+    hospitals: &[Vec<usize>],
+    resident_rank: &[Vec<Option<usize>>],
+    capacities: &[usize],
+) -> Vec<Option<usize>> {
+    // the hospitals with free places propose to the next resident of their
+    // list, who keeps the best acceptable hospital proposing to them
+    let mut hospital_of: Vec<Option<usize>> = vec![None; resident_rank.len()];
+    let mut next = vec![0; hospitals.len()];
+    let mut nb_held = vec![0; hospitals.len()];
+    let mut free = (0..hospitals.len())
+        .filter(|h| capacities[*h] > 0)
+        .collect::<VecDeque<usize>>();
+    while let Some(h) = free.pop_front() {
+        // each hospital is in the queue at most once, with a free place
+        let Some(r) = hospitals[h].get(next[h]).copied() else {
+            continue;
+        };
+        next[h] += 1;
+        let better = match (resident_rank[r][h], hospital_of[r]) {
+            (Some(rank), Some(current)) => resident_rank[r][current].is_some_and(|c| rank < c),
+            (Some(_), None) => true,
+            (None, _) => false,
+        };
+        if better {
+            if let Some(current) = hospital_of[r].replace(h) {
+                if nb_held[current] == capacities[current] {
+                    free.push_back(current);
+                }
+                nb_held[current] -= 1;
+            }
+            nb_held[h] += 1;
+        }
+        if nb_held[h] < capacities[h] {
+            free.push_back(h);
+        }
+    }
+    hospital_of
+}
+
+/// Function that checks that a matching of proposers to receivers with
+/// capacities (one for the stable marriage), given by the receiver of each
+/// proposer, only matches mutually acceptable members, respects the
+/// capacities, and has no blocking pair: a proposer and a receiver, not
+/// matched together, who would both rather be matched together.
+pub fn is_stable(
+    proposers: &[Vec<usize>],
+    receivers: &[Vec<usize>],
+    capacities: &[usize],
+    receiver_of: &[Option<usize>],
+) -> bool {
+    let proposer_rank = ranks(proposers, receivers.len());
+    let receiver_rank = ranks(receivers, proposers.len());
+    if receiver_of.len() != proposers.len() || capacities.len() != receivers.len() {
+        return false;
+    }
+    // the worst rank among the proposers of each receiver
+    let mut nb_matched = vec![0; receivers.len()];
+    let mut worst: Vec<Option<usize>> = vec![None; receivers.len()];
+    for (p, r) in receiver_of.iter().enumerate() {
+        let Some(r) = r else {
+            continue;
+        };
+        let (Some(_), Some(rank)) = (proposer_rank[p][*r], receiver_rank[*r][p]) else {
+            return false;
+        };
+        nb_matched[*r] += 1;
+        worst[*r] = std::cmp::max(worst[*r], Some(rank));
+    }
+    if (0..receivers.len()).any(|r| nb_matched[r] > capacities[r]) {
+        return false;
+    }
+    !proposers.iter().enumerate().any(|(p, list)| {
+        // receivers the proposer prefers to its current one
+        let preferred = match receiver_of[p] {
+            Some(r) => &list[..proposer_rank[p][r].expect("checked above")],
+            None => &list[..],
+        };
+        preferred.iter().any(|r| match receiver_rank[*r][p] {
+            Some(rank) => nb_matched[*r] < capacities[*r] || worst[*r] > Some(rank),
+            None => false,
+        })
+    })
+}
@@ -0,0 +1,285 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{
+        hospitals_residents, hungarian, is_stable, stable_marriage, Objective, Optimality,
+    };
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn brute_force(costs: &[Vec<i64>], maximize: bool) -> i64 {
+        // best total cost over all the assignments of the rows,
+        // when there are at most as many rows as columns
+        fn search(costs: &[Vec<i64>], row: usize, used: &mut Vec<bool>, maximize: bool) -> i64 {
+            if row == costs.len() {
+                return 0;
+            }
+            let mut best: Option<i64> = None;
+            for col in 0..used.len() {
+                if !used[col] {
+                    used[col] = true;
+                    let total = costs[row][col] + search(costs, row + 1, used, maximize);
+                    used[col] = false;
+                    best = match best {
+                        Some(b) if (maximize && b >= total) || (!maximize && b <= total) => Some(b),
+                        _ => Some(total),
+                    };
+                }
+            }
+            best.unwrap()
+        }
+        search(costs, 0, &mut vec![false; costs[0].len()], maximize)
+    }
+
+    #[test]
+    fn test_hungarian_random() {
+        let mut rng = StdRng::seed_from_u64(3);
+        for _ in 0..50 {
+            let nb_rows = rng.gen_range(1..6);
+            let nb_cols = rng.gen_range(nb_rows..7);
+            let costs = (0..nb_rows)
+                .map(|_| (0..nb_cols).map(|_| rng.gen_range(-20..50)).collect())
+                .collect::<Vec<Vec<i64>>>();
+            for (objective, maximize) in [(Objective::Minimize, false), (Objective::Maximize, true)]
+            {
+                let assignment = hungarian(&costs, objective);
+                assert_eq!(assignment.cost(), brute_force(&costs, maximize));
+                let mut cols = assignment.pairs().iter().map(|p| p.1).collect::<Vec<_>>();
+                cols.sort_unstable();
+                cols.dedup();
+                assert_eq!(cols.len(), nb_rows);
+                // the transposed matrix has the same optimal cost
+                let transposed = (0..nb_cols)
+                    .map(|c| (0..nb_rows).map(|r| costs[r][c]).collect())
+                    .collect::<Vec<Vec<i64>>>();
+                let assignment = hungarian(&transposed, objective);
+                assert_eq!(assignment.cost(), brute_force(&costs, maximize));
+                assert_eq!(assignment.pairs().len(), nb_rows);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hungarian_types() {
+        // unsigned costs
+        let costs: Vec<Vec<u8>> = vec![vec![9, 2, 7, 8], vec![6, 4, 3, 7], vec![5, 8, 1, 8]];
+        let assignment = hungarian(&costs, Objective::Minimize);
+        assert_eq!(assignment.cost(), 9);
+        assert_eq!(assignment.columns(), &[Some(1), Some(0), Some(2)]);
+        // more rows than columns
+        let costs: Vec<Vec<u32>> = vec![vec![1, 10], vec![2, 3], vec![0, 8]];
+        let assignment = hungarian(&costs, Objective::Minimize);
+        assert_eq!(assignment.cost(), 3);
+        assert_eq!(assignment.columns(), &[None, Some(1), Some(0)]);
+        assert_eq!(assignment.column(0), None);
+        // float costs
+        let costs: Vec<Vec<f64>> = vec![vec![0.5, 1.5], vec![2.25, 0.75]];
+        let assignment = hungarian(&costs, Objective::Minimize);
+        assert!((assignment.cost() - 1.25).abs() < 1e-12);
+        let assignment = hungarian(&costs, Objective::Maximize);
+        assert!((assignment.cost() - 3.75).abs() < 1e-12);
+        assert_eq!(assignment.pairs(), vec![(0, 1), (1, 0)]);
+        // empty matrix
+        let assignment = hungarian::<i32>(&[], Objective::Minimize);
+        assert_eq!(assignment.cost(), 0);
+    }
+
+    #[test]
+    fn test_hungarian_maximize_limit() {
+        // the costs are i8::MAX apart, the largest spread of i8
+        let costs: Vec<Vec<i8>> = vec![vec![-100, 27], vec![27, 0]];
+        let assignment = hungarian(&costs, Objective::Maximize);
+        assert_eq!(assignment.cost(), 54);
+        assert_eq!(assignment.columns(), &[Some(1), Some(0)]);
+        let costs: Vec<Vec<i64>> = vec![vec![-1, i64::MAX - 1], vec![i64::MAX - 1, -1]];
+        let assignment = hungarian(&costs, Objective::Minimize);
+        assert_eq!(assignment.cost(), -2);
+        assert_eq!(assignment.pairs(), vec![(0, 0), (1, 1)]);
+    }
+
+    #[test]
+    fn test_hungarian_overflow() {
+        // the optimum fits in u8 but not the sums of the costs and potentials
+        let costs: Vec<Vec<u8>> = vec![vec![130, 110, 121], vec![227, 43, 215], vec![223, 249, 1]];
+        assert_eq!(hungarian(&costs, Objective::Minimize).cost(), 174);
+        let costs: Vec<Vec<u8>> = vec![vec![8, 124, 134], vec![22, 228, 91], vec![240, 203, 94]];
+        assert_eq!(hungarian(&costs, Objective::Minimize).cost(), 240);
+        let costs: Vec<Vec<u8>> = vec![
+            vec![183, 210, 16, 32],
+            vec![202, 109, 6, 14],
+            vec![185, 173, 16, 69],
+            vec![18, 108, 187, 190],
+        ];
+        assert_eq!(hungarian(&costs, Objective::Minimize).cost(), 175);
+        // the smallest costs of the rows add up below i8::MIN
+        let costs: Vec<Vec<i8>> = vec![vec![-100, 0], vec![-100, 50]];
+        let assignment = hungarian(&costs, Objective::Minimize);
+        assert_eq!(assignment.cost(), -100);
+        assert_eq!(assignment.columns(), &[Some(1), Some(0)]);
+        // the costs are more than i8::MAX apart
+        let costs: Vec<Vec<i8>> = vec![vec![-100, 28], vec![28, 0]];
+        let assignment = hungarian(&costs, Objective::Maximize);
+        assert_eq!(assignment.cost(), 56);
+        assert_eq!(assignment.columns(), &[Some(1), Some(0)]);
+    }
+
+    fn random_preferences(rng: &mut StdRng, nb: usize, nb_others: usize) -> Vec<Vec<usize>> {
+        // random incomplete preference lists
+        (0..nb)
+            .map(|_| {
+                let mut list = (0..nb_others)
+                    .filter(|_| rng.gen_bool(0.8))
+                    .collect::<Vec<usize>>();
+                for i in (1..list.len()).rev() {
+                    list.swap(i, rng.gen_range(0..=i));
+                }
+                list
+            })
+            .collect()
+    }
+
+    fn stable_matchings(
+        proposers: &[Vec<usize>],
+        receivers: &[Vec<usize>],
+        capacities: &[usize],
+    ) -> Vec<Vec<Option<usize>>> {
+        // all the assignments of the proposers which are stable
+        let (nb, nb_receivers) = (proposers.len(), receivers.len());
+        let mut all = Vec::new();
+        let mut choice = vec![0; nb];
+        loop {
+            let matching = choice
+                .iter()
+                .map(|c| (*c < nb_receivers).then_some(*c))
+                .collect::<Vec<Option<usize>>>();
+            if is_stable(proposers, receivers, capacities, &matching) {
+                all.push(matching);
+            }
+            let mut p = 0;
+            while p < nb {
+                choice[p] += 1;
+                if choice[p] <= nb_receivers {
+                    break;
+                }
+                choice[p] = 0;
+                p += 1;
+            }
+            if p == nb {
+                return all;
+            }
+        }
+    }
+
+    fn rank(list: &[usize], partner: Option<usize>) -> usize {
+        // position of a partner in a list, being unmatched is the worst
+        partner.map_or(list.len(), |x| list.iter().position(|y| *y == x).unwrap())
+    }
+
+    #[test]
+    fn test_stable_marriage() {
+        // cyclic preferences: each proposer gets its first choice in the
+        // proposer optimal matching, and its last one in the receiver
+        // optimal matching, the second choices making a third matching
+        let proposers = vec![vec![0, 1, 2], vec![1, 2, 0], vec![2, 0, 1]];
+        let receivers = vec![vec![1, 2, 0], vec![2, 0, 1], vec![0, 1, 2]];
+        let best = stable_marriage(&proposers, &receivers, Optimality::ProposerOptimal);
+        assert_eq!(best.receivers(), &[Some(0), Some(1), Some(2)]);
+        let worst = stable_marriage(&proposers, &receivers, Optimality::ReceiverOptimal);
+        assert_eq!(worst.receivers(), &[Some(2), Some(0), Some(1)]);
+        assert_eq!(worst.pairs(), vec![(0, 2), (1, 0), (2, 1)]);
+        let capacities = [1; 3];
+        assert!(is_stable(
+            &proposers,
+            &receivers,
+            &capacities,
+            &[Some(1), Some(2), Some(0)]
+        ));
+        // 2 and the receiver 0 rather be matched together
+        assert!(!is_stable(
+            &proposers,
+            &receivers,
+            &capacities,
+            &[Some(0), Some(2), Some(1)]
+        ));
+        // 0 and 1 are not acceptable to each other
+        let proposers = vec![vec![0], vec![0, 1]];
+        let receivers = vec![vec![1, 0], vec![1]];
+        let matching = stable_marriage(&proposers, &receivers, Optimality::ProposerOptimal);
+        assert_eq!(matching.receivers(), &[None, Some(0)]);
+        assert!(matching.proposers(1).is_empty());
+    }
+
+    #[test]
+    fn test_hospitals_residents() {
+        // the hospital 0 takes its two favorite residents 2 and 1, the
+        // resident 0 goes to the hospital 1, where 3 is then rejected
+        let residents = vec![vec![0, 1], vec![0, 1], vec![0], vec![1, 0]];
+        let hospitals = vec![vec![2, 1, 0, 3], vec![0, 3, 1]];
+        let capacities = [2, 1];
+        for optimality in [Optimality::ProposerOptimal, Optimality::ReceiverOptimal] {
+            let matching = hospitals_residents(&residents, &hospitals, &capacities, optimality);
+            assert_eq!(matching.receivers(), &[Some(1), Some(0), Some(0), None]);
+            assert_eq!(matching.proposers(0), &[1, 2]);
+            assert_eq!(matching.proposers(1), &[0]);
+        }
+    }
+
+    #[test]
+    fn test_stable_marriage_random() {
+        let mut rng = StdRng::seed_from_u64(45);
+        for _ in 0..200 {
+            let (nb, nb_receivers) = (rng.gen_range(0..5), rng.gen_range(0..5));
+            let proposers = random_preferences(&mut rng, nb, nb_receivers);
+            let receivers = random_preferences(&mut rng, nb_receivers, nb);
+            let capacities = vec![1; nb_receivers];
+            let all = stable_matchings(&proposers, &receivers, &capacities);
+            let best = stable_marriage(&proposers, &receivers, Optimality::ProposerOptimal);
+            let worst = stable_marriage(&proposers, &receivers, Optimality::ReceiverOptimal);
+            assert!(all.contains(&best.receivers().to_vec()));
+            assert!(all.contains(&worst.receivers().to_vec()));
+            for matching in &all {
+                for (p, list) in proposers.iter().enumerate() {
+                    assert!(rank(list, best.receiver(p)) <= rank(list, matching[p]));
+                    assert!(rank(list, worst.receiver(p)) >= rank(list, matching[p]));
+                }
+                for (r, list) in receivers.iter().enumerate() {
+                    let partner = matching.iter().position(|x| *x == Some(r));
+                    let optimal = worst.proposers(r).first().copied();
+                    assert!(rank(list, optimal) <= rank(list, partner));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_hospitals_residents_random() {
+        let mut rng = StdRng::seed_from_u64(46);
+        for _ in 0..200 {
+            let (nb, nb_hospitals) = (rng.gen_range(0..6), rng.gen_range(1..4));
+            let residents = random_preferences(&mut rng, nb, nb_hospitals);
+            let hospitals = random_preferences(&mut rng, nb_hospitals, nb);
+            let capacities = (0..nb_hospitals)
+                .map(|_| rng.gen_range(0..3))
+                .collect::<Vec<usize>>();
+            let all = stable_matchings(&residents, &hospitals, &capacities);
+            for optimality in [Optimality::ProposerOptimal, Optimality::ReceiverOptimal] {
+                let matching = hospitals_residents(&residents, &hospitals, &capacities, optimality);
+                assert!(all.contains(&matching.receivers().to_vec()));
+                for (h, capacity) in capacities.iter().enumerate() {
+                    assert!(matching.proposers(h).len() <= *capacity);
+                }
+                for other in &all {
+                    // the same residents are matched in all the stable matchings
+                    for (r, list) in residents.iter().enumerate() {
+                        assert_eq!(matching.receiver(r).is_some(), other[r].is_some());
+                        let (found, other) =
+                            (rank(list, matching.receiver(r)), rank(list, other[r]));
+                        match optimality {
+                            Optimality::ProposerOptimal => assert!(found <= other),
+                            Optimality::ReceiverOptimal => assert!(found >= other),
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
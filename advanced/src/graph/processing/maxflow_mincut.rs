@@ -1,6 +1,10 @@
+mod circulation;
+mod global_min_cut;
 #[cfg(test)]
 mod unit_test;
-use crate::graph::{FlowEdge, FlowNetwork, Weight};
+use crate::graph::{FlowNetwork, Weight};
+pub use circulation::{Circulation, Infeasibility};
+pub use global_min_cut::{karger_stein, stoer_wagner, GomoryHuTree};
 use std::cmp::min;
 use std::collections::VecDeque;
 
@@ -11,20 +15,34 @@ where
     T: Weight,
 {
     max_flow: Option<T>,
+    // vertices reachable from the source in the final residual network,
+    // that is the source side of a minimum cut
+    cut: Vec<bool>,
+}
+
+impl<T: Weight> Default for FordFulkerson<T> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<T: Weight> FordFulkerson<T> {
     pub fn new() -> Self {
-        Self { max_flow: None }
+        Self {
+            max_flow: None,
+            cut: Vec::new(),
+        }
     }
 
     fn has_augmenting_path(
-        &self,
-        network: &mut FlowNetwork<T>,
+        &mut self,
+        network: &FlowNetwork<T>,
         source: usize,
         destination: usize,
-        edge_to: &mut Vec<Option<usize>>,
+        edge_to: &mut [Option<(usize, usize)>],
     ) -> bool {
+        // edge_to[w] is the vertex preceding w on the path,
+        // with the position of the edge used in its list
         let zero = Weight::zero();
         let mut marked = vec![false; network.nb_vertices()];
         let mut queue = VecDeque::new();
@@ -33,12 +51,11 @@ impl<T: Weight> FordFulkerson<T> {
         queue.push_back(source);
 
         while let Some(vertex) = queue.pop_front() {
-            for edge in network.vertex_edges(&vertex) {
-                let next_vertex = edge.to();
-                let next_vertex = *next_vertex;
+            for (index, edge) in network.vertex_edges(&vertex).into_iter().enumerate() {
+                let next_vertex = *edge.to();
                 if !marked[next_vertex] && edge.residual_capacity() > zero {
                     marked[next_vertex] = true;
-                    edge_to[next_vertex] = Some(vertex);
+                    edge_to[next_vertex] = Some((vertex, index));
                     if next_vertex == destination {
                         return true;
                     }
@@ -46,12 +63,21 @@ impl<T: Weight> FordFulkerson<T> {
                 }
             }
         }
+        self.cut = marked;
         false
     }
     pub fn max_flow(&self) -> Option<T> {
         self.max_flow
     }
-    fn find_flows(&mut self, network: &mut FlowNetwork<T>, source: usize, destination: usize) {
+    /// Whether or not a vertex is on the source side of the minimum cut
+    /// found along with the maximum flow
+    pub fn in_cut(&self, v: usize) -> bool {
+        self.cut[v]
+    }
+    /// Computes a maximum flow from source to destination with the
+    /// Edmonds-Karp variant (shortest augmenting paths first)
+    pub fn find_flows(&mut self, network: &mut FlowNetwork<T>, source: usize, destination: usize) {
+        // run time complexity O(VE^2)
         let mut edge_to = vec![None; network.nb_vertices()];
         let mut max_flow = Weight::zero();
 
@@ -60,31 +86,18 @@ impl<T: Weight> FordFulkerson<T> {
 
             // Find the bottleneck capacity of the path
             let mut vertex = destination;
-            while let Some(parent_vertex) = edge_to[vertex] {
-                let res_cap = network
-                    .vertex_edges(&parent_vertex)
-                    .iter()
-                    .find(|e| e.to() == &vertex)
-                    .unwrap()
-                    .residual_capacity();
+            while vertex != source {
+                let (parent_vertex, index) = edge_to[vertex].expect("vertex on the path");
+                let res_cap = network.vertex_edges(&parent_vertex)[index].residual_capacity();
                 path_flow = min(path_flow, res_cap);
                 vertex = parent_vertex;
             }
 
             // Update the flow of each edge along the path
             vertex = destination;
-            while let Some(parent_vertex) = edge_to[vertex] {
-                let mut edges = network.vertex_edges_mut(&parent_vertex);
-                let mut forward_edge = network
-                    .vertex_edges_mut(&parent_vertex)
-                    .find(|e| e.to() == &vertex)
-                    .expect("Failed to get forward edge");
-                forward_edge.add_residual_flow_to(&vertex, path_flow);
-                let mut backward_edge = network
-                    .vertex_edges_mut(&vertex)
-                    .find(|e| e.to() == &parent_vertex)
-                    .expect("Failed to get backward edge");
-                backward_edge.add_residual_flow_to(&parent_vertex, path_flow);
+            while vertex != source {
+                let (parent_vertex, index) = edge_to[vertex].expect("vertex on the path");
+                network.push_flow(parent_vertex, index, path_flow);
                 vertex = parent_vertex;
             }
             max_flow = max_flow + path_flow;
@@ -0,0 +1,244 @@
+use super::FordFulkerson;
+use crate::graph::{FlowNetwork, Weight};
+use std::cmp::min;
+use std::collections::{HashMap, VecDeque};
+
+/// Implementation of the feasible flow problem with supplies, demands and
+/// lower bounds on the edges: each vertex sends out its supply plus what it
+/// receives minus its demand, and the flow on each edge is between its lower
+/// bound and its capacity. Vertices with a supply act as sources, vertices
+/// with a demand as sinks, and without any of them the flow is a
+/// circulation. The problem is reduced to a maximum flow from a super source
+/// to a super sink on a `FlowNetwork`.
+/// # Example
+/// ```
+/// use algods::graph::processing::Circulation;
+/// let mut problem = Circulation::init(4);
+/// let a = problem.add_edge(0, 1, 0, 5);
+/// let b = problem.add_edge(0, 2, 2, 4);
+/// let c = problem.add_edge(1, 3, 0, 5);
+/// let d = problem.add_edge(2, 3, 0, 5);
+/// problem.add_supply(0, 6);
+/// problem.add_demand(3, 6);
+/// let flows = problem.solve().unwrap();
+/// assert_eq!(flows[a] + flows[b], 6);
+/// assert!(flows[b] >= 2);
+/// assert_eq!(flows[a], flows[c]);
+/// assert_eq!(flows[b], flows[d]);
+/// // the vertex 1 receives at least 2 units but can send out only 1
+/// let mut problem = Circulation::init(3);
+/// problem.add_edge(0, 1, 2, 4);
+/// problem.add_edge(1, 2, 0, 1);
+/// problem.add_edge(2, 0, 0, 4);
+/// let certificate = problem.solve().unwrap_err();
+/// assert_eq!(certificate.vertices(), &[1]);
+/// assert!(certificate.is_surplus());
+/// assert_eq!((certificate.required(), certificate.available()), (2, 1));
+/// ```
+pub struct Circulation<T> {
+    nb_vertices: usize,
+    // (from, to, lower bound, capacity)
+    edges: Vec<(usize, usize, T, T)>,
+    supply: Vec<T>,
+    demand: Vec<T>,
+}
+impl<T: Weight> Circulation<T> {
+    /// Creates a problem on vertices without edges, supplies nor demands
+    pub fn init(nb_vertices: usize) -> Self {
+        Self {
+            nb_vertices,
+            edges: Vec::new(),
+            supply: vec![T::zero(); nb_vertices],
+            demand: vec![T::zero(); nb_vertices],
+        }
+    }
+    pub fn nb_vertices(&self) -> usize {
+        self.nb_vertices
+    }
+    /// Adds an edge whose flow is between lower and capacity,
+    /// returns its number (edges are numbered from 0)
+    pub fn add_edge(&mut self, from: usize, to: usize, lower: T, capacity: T) -> usize {
+        assert!(std::cmp::max(from, to) < self.nb_vertices);
+        assert!(lower <= capacity, "the lower bound exceeds the capacity");
+        self.edges.push((from, to, lower, capacity));
+        self.edges.len() - 1
+    }
+    /// Increases the amount of flow a vertex produces
+    pub fn add_supply(&mut self, v: usize, amount: T) {
+        self.supply[v] = self.supply[v] + amount;
+    }
+    /// Increases the amount of flow a vertex consumes
+    pub fn add_demand(&mut self, v: usize, amount: T) {
+        self.demand[v] = self.demand[v] + amount;
+    }
+    /// Finds the flow on each edge (by edge number) satisfying all the
+    /// constraints, or a set of vertices proving that there is none
+    pub fn solve(&self) -> Result<Vec<T>, Infeasibility<T>> {
+        // run time complexity O(VE^2) with the Edmonds-Karp algorithm
+        let nb = self.nb_vertices;
+        let (source, sink) = (nb, nb + 1);
+        // the lower bounds are sent beforehand, the edges keeping the rest of
+        // their capacity, and the vertices balance what they receive and send
+        let mut inflow = self.supply.clone();
+        let mut outflow = self.demand.clone();
+        let mut capacity = HashMap::new();
+        for (from, to, lower, upper) in &self.edges {
+            inflow[*to] = inflow[*to] + *lower;
+            outflow[*from] = outflow[*from] + *lower;
+            if from != to && lower < upper {
+                let entry = capacity.entry((*from, *to)).or_insert_with(T::zero);
+                *entry = *entry + (*upper - *lower);
+            }
+        }
+        let mut network = FlowNetwork::init(nb + 2);
+        // parallel edges are merged since the network would drop duplicates
+        for ((from, to), cap) in &capacity {
+            network.add_edge(*from, *to, *cap);
+        }
+        let (mut from_source, mut to_sink) = (T::zero(), T::zero());
+        for v in 0..nb {
+            if inflow[v] > outflow[v] {
+                network.add_edge(source, v, inflow[v] - outflow[v]);
+                from_source = from_source + (inflow[v] - outflow[v]);
+            } else if outflow[v] > inflow[v] {
+                network.add_edge(v, sink, outflow[v] - inflow[v]);
+                to_sink = to_sink + (outflow[v] - inflow[v]);
+            }
+        }
+        let mut max_flow = FordFulkerson::new();
+        max_flow.find_flows(&mut network, source, sink);
+        let value = max_flow.max_flow().expect("the flows are computed");
+        if value < from_source {
+            // the source side of a minimum cut cannot send out its surplus
+            let vertices = (0..nb).filter(|v| max_flow.in_cut(*v)).collect();
+            return Err(self.certificate(vertices, true));
+        }
+        if value < to_sink {
+            // the vertices which can still reach the sink cannot receive
+            // what they lack
+            let vertices = reaching(&network, sink)
+                .into_iter()
+                .take(nb)
+                .enumerate()
+                .filter_map(|(v, reach)| reach.then_some(v))
+                .collect();
+            return Err(self.certificate(vertices, false));
+        }
+        // shares the flow of each merged edge among the parallel edges
+        let mut extra = network
+            .flow_edges()
+            .into_iter()
+            .filter(|edge| *edge.from() < nb && *edge.to() < nb)
+            .map(|edge| ((*edge.from(), *edge.to()), *edge.flow()))
+            .collect::<HashMap<(usize, usize), T>>();
+        Ok(self
+            .edges
+            .iter()
+            .map(
+                |(from, to, lower, upper)| match extra.get_mut(&(*from, *to)) {
+                    Some(left) if from != to => {
+                        let share = min(*left, *upper - *lower);
+                        *left = *left - share;
+                        *lower + share
+                    }
+                    _ => *lower,
+                },
+            )
+            .collect())
+    }
+    fn certificate(&self, vertices: Vec<usize>, surplus: bool) -> Infeasibility<T> {
+        let mut inside = vec![false; self.nb_vertices];
+        for v in &vertices {
+            inside[*v] = true;
+        }
+        // a surplus set must send out (at least) its supplies and the lower
+        // bounds of the edges entering it, and can send out at most its
+        // demands and the capacities of the edges leaving it, a deficit set
+        // being the same with the edges and the supplies/demands reversed
+        let (mut required, mut available) = (T::zero(), T::zero());
+        for v in &vertices {
+            let (produced, consumed) = (self.supply[*v], self.demand[*v]);
+            let (needed, offered) = if surplus {
+                (produced, consumed)
+            } else {
+                (consumed, produced)
+            };
+            required = required + needed;
+            available = available + offered;
+        }
+        for (from, to, lower, upper) in &self.edges {
+            let (from, to) = if surplus { (*from, *to) } else { (*to, *from) };
+            if !inside[from] && inside[to] {
+                required = required + *lower;
+            } else if inside[from] && !inside[to] {
+                available = available + *upper;
+            }
+        }
+        Infeasibility {
+            vertices,
+            surplus,
+            required,
+            available,
+        }
+    }
+}
+
+fn reaching<T: Weight>(network: &FlowNetwork<T>, target: usize) -> Vec<bool> {
+    // vertices from which target can be reached in the residual network
+    let nb = network.nb_vertices();
+    let mut predecessors = vec![Vec::new(); nb];
+    for v in 0..nb {
+        for edge in network.vertex_edges(&v) {
+            if edge.residual_capacity() > T::zero() {
+                predecessors[*edge.to()].push(v);
+            }
+        }
+    }
+    let mut marked = vec![false; nb];
+    marked[target] = true;
+    let mut queue = VecDeque::from([target]);
+    while let Some(v) = queue.pop_front() {
+        for u in &predecessors[v] {
+            if !marked[*u] {
+                marked[*u] = true;
+                queue.push_back(*u);
+            }
+        }
+    }
+    marked
+}
+
+/// Certificate that a `Circulation` problem has no solution: a set of
+/// vertices which has to send out more flow than it can (surplus), or to
+/// receive more flow than it can (deficit)
+#[derive(Debug)]
+pub struct Infeasibility<T> {
+    vertices: Vec<usize>,
+    surplus: bool,
+    required: T,
+    available: T,
+}
+impl<T: Weight> Infeasibility<T> {
+    /// Gives the vertices of the set, sorted
+    pub fn vertices(&self) -> &[usize] {
+        &self.vertices
+    }
+    /// Whether the set has too much flow to send out,
+    /// rather than too little flow coming in
+    pub fn is_surplus(&self) -> bool {
+        self.surplus
+    }
+    /// Gives the flow the set must send out for a surplus (its supplies and
+    /// the lower bounds of the entering edges), or must receive for a
+    /// deficit (its demands and the lower bounds of the leaving edges)
+    pub fn required(&self) -> T {
+        self.required
+    }
+    /// Gives the most flow the set can send out for a surplus (its demands
+    /// and the capacities of the leaving edges), or can receive for a
+    /// deficit (its supplies and the capacities of the entering edges),
+    /// which is smaller than the required flow
+    pub fn available(&self) -> T {
+        self.available
+    }
+}
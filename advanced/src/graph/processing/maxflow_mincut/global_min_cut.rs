@@ -0,0 +1,345 @@
+use super::FordFulkerson;
+use crate::graph::{EdgeWeightedGraph, FlowNetwork, ToF64, Weight};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+// below this number of merged vertices, Karger-Stein enumerates all the cuts
+const SMALL_GRAPH: usize = 6;
+
+fn weight_matrix<T: Weight>(graph: &EdgeWeightedGraph<T>) -> Vec<Vec<T>> {
+    // sums the weights of parallel edges, self loops being ignored
+    let nb = graph.nb_vertices();
+    let mut matrix = vec![vec![T::zero(); nb]; nb];
+    for (u, v, w) in graph.edges() {
+        if u != v {
+            matrix[u][v] = matrix[u][v] + w;
+            matrix[v][u] = matrix[v][u] + w;
+        }
+    }
+    matrix
+}
+
+fn merge<T: Weight>(
+    matrix: &mut Vec<Vec<T>>,
+    groups: &mut Vec<Vec<usize>>,
+    keep: usize,
+    remove: usize,
+) {
+    // contracts the merged vertex remove into keep
+    let removed = matrix[remove].clone();
+    for (y, w) in removed.into_iter().enumerate() {
+        matrix[keep][y] = matrix[keep][y] + w;
+        matrix[y][keep] = matrix[y][keep] + w;
+    }
+    matrix[keep][keep] = T::zero();
+    matrix.swap_remove(remove);
+    for row in matrix.iter_mut() {
+        row.swap_remove(remove);
+    }
+    let moved = std::mem::take(&mut groups[remove]);
+    groups[keep].extend(moved);
+    groups.swap_remove(remove);
+}
+
+fn side_of_origin(nb: usize, mut side: Vec<usize>) -> Vec<usize> {
+    // returns the side of the cut containing the vertex 0, sorted
+    side.sort_unstable();
+    if side.first() == Some(&0) {
+        return side;
+    }
+    let mut in_side = vec![false; nb];
+    for v in side {
+        in_side[v] = true;
+    }
+    (0..nb).filter(|v| !in_side[*v]).collect()
+}
+
+/// Finds a global minimum cut of an undirected graph with non negative
+/// weights, using the deterministic Stoer-Wagner algorithm. Returns the total
+/// weight of the cut and its side containing the vertex 0, or `None` when the
+/// graph has less than two vertices.
+/// # Example
+/// ```
+/// use algods::graph::EdgeWeightedGraph;
+/// use algods::graph::processing::stoer_wagner;
+/// let mut graph = EdgeWeightedGraph::init(4);
+/// graph.add_edge(0, 1, 5);
+/// graph.add_edge(2, 3, 5);
+/// graph.add_edge(1, 2, 1);
+/// graph.add_edge(0, 3, 2);
+/// assert_eq!(stoer_wagner(&graph), Some((3, vec![0, 1])));
+/// ```
+pub fn stoer_wagner<T: Weight>(graph: &EdgeWeightedGraph<T>) -> Option<(T, Vec<usize>)> {
+    // run time complexity O(V^3)
+    let nb = graph.nb_vertices();
+    if nb < 2 {
+        return None;
+    }
+    let mut matrix = weight_matrix(graph);
+    let mut groups = (0..nb).map(|v| vec![v]).collect::<Vec<Vec<usize>>>();
+    let mut best: Option<(T, Vec<usize>)> = None;
+    while groups.len() > 1 {
+        // maximum adjacency ordering of the merged vertices
+        let k = groups.len();
+        let mut added = vec![false; k];
+        let mut key = vec![T::zero(); k];
+        let (mut previous, mut last) = (0, 0);
+        for _ in 0..k {
+            let z = (0..k)
+                .filter(|y| !added[*y])
+                .max_by_key(|y| (key[*y], std::cmp::Reverse(*y)))
+                .expect("a vertex is not added yet");
+            added[z] = true;
+            previous = last;
+            last = z;
+            for y in (0..k).filter(|y| !added[*y]) {
+                key[y] = key[y] + matrix[z][y];
+            }
+        }
+        // the cut of the phase separates the last added vertex from the others
+        if best.as_ref().is_none_or(|(value, _)| key[last] < *value) {
+            best = Some((key[last], groups[last].clone()));
+        }
+        merge(&mut matrix, &mut groups, previous, last);
+    }
+    best.map(|(value, side)| (value, side_of_origin(nb, side)))
+}
+
+/// Finds a global minimum cut of an undirected graph with non negative
+/// weights, using the randomized recursive contraction algorithm of
+/// Karger and Stein, repeated `nb_trials` times. Each trial finds a minimum
+/// cut with probability Ω(1 / log(V)). Returns the total weight of the best
+/// cut found and its side containing the vertex 0, or `None` when the graph
+/// has less than two vertices.
+/// # Example
+/// ```
+/// use algods::graph::EdgeWeightedGraph;
+/// use algods::graph::processing::karger_stein;
+/// let mut graph = EdgeWeightedGraph::init(4);
+/// graph.add_edge(0, 1, 5);
+/// graph.add_edge(2, 3, 5);
+/// graph.add_edge(1, 2, 1);
+/// graph.add_edge(0, 3, 2);
+/// assert_eq!(karger_stein(&graph, 10, 42), Some((3, vec![0, 1])));
+/// ```
+pub fn karger_stein<T: Weight + ToF64>(
+    graph: &EdgeWeightedGraph<T>,
+    nb_trials: usize,
+    seed: u64,
+) -> Option<(T, Vec<usize>)> {
+    // run time complexity O(nb_trials V^2 log(V))
+    let nb = graph.nb_vertices();
+    if nb < 2 {
+        return None;
+    }
+    let mut rng = StdRng::seed_from_u64(seed);
+    let matrix = weight_matrix(graph);
+    let groups = (0..nb).map(|v| vec![v]).collect::<Vec<Vec<usize>>>();
+    let mut best: Option<(T, Vec<usize>)> = None;
+    for _ in 0..std::cmp::max(nb_trials, 1) {
+        let (value, side) = recursive_contraction(matrix.clone(), groups.clone(), &mut rng);
+        if best
+            .as_ref()
+            .is_none_or(|(best_value, _)| value < *best_value)
+        {
+            best = Some((value, side));
+        }
+    }
+    best.map(|(value, side)| (value, side_of_origin(nb, side)))
+}
+
+fn recursive_contraction<T: Weight + ToF64>(
+    matrix: Vec<Vec<T>>,
+    groups: Vec<Vec<usize>>,
+    rng: &mut StdRng,
+) -> (T, Vec<usize>) {
+    let k = groups.len();
+    if k <= SMALL_GRAPH {
+        return smallest_cut(&matrix, &groups);
+    }
+    let target = (1.0 + k as f64 / std::f64::consts::SQRT_2).ceil() as usize;
+    let mut best: Option<(T, Vec<usize>)> = None;
+    for _ in 0..2 {
+        let (mut m, mut g) = (matrix.clone(), groups.clone());
+        let cut = if contract(&mut m, &mut g, target, rng) {
+            recursive_contraction(m, g, rng)
+        } else {
+            // the remaining merged vertices are not linked to each other
+            (T::zero(), g.swap_remove(0))
+        };
+        if best.as_ref().is_none_or(|(value, _)| cut.0 < *value) {
+            best = Some(cut);
+        }
+    }
+    best.expect("two contractions are made")
+}
+
+fn contract<T: Weight + ToF64>(
+    matrix: &mut Vec<Vec<T>>,
+    groups: &mut Vec<Vec<usize>>,
+    target: usize,
+    rng: &mut StdRng,
+) -> bool {
+    // contracts edges picked with probability proportional to their weight
+    // until target merged vertices remain, returns false when no edge remains
+    while groups.len() > target {
+        let k = groups.len();
+        let total: f64 = (0..k)
+            .flat_map(|u| (u + 1..k).map(move |v| (u, v)))
+            .map(|(u, v)| matrix[u][v].to_f64())
+            .sum();
+        if total <= 0.0 {
+            return false;
+        }
+        let mut threshold = rng.gen::<f64>() * total;
+        let mut chosen = None;
+        'outer: for (u, row) in matrix.iter().enumerate() {
+            for (v, weight) in row.iter().enumerate().skip(u + 1) {
+                let w = weight.to_f64();
+                if w > 0.0 {
+                    chosen = Some((u, v));
+                    threshold -= w;
+                    if threshold < 0.0 {
+                        break 'outer;
+                    }
+                }
+            }
+        }
+        let (u, v) = chosen.expect("an edge has a positive weight");
+        merge(matrix, groups, u, v);
+    }
+    true
+}
+
+fn smallest_cut<T: Weight>(matrix: &[Vec<T>], groups: &[Vec<usize>]) -> (T, Vec<usize>) {
+    // enumerates the cuts of a small graph, the last merged vertex
+    // being always outside of the enumerated side
+    let k = groups.len();
+    let mut best: Option<(T, usize)> = None;
+    for mask in 1..(1usize << (k - 1)) {
+        let mut value = T::zero();
+        for (u, row) in matrix.iter().enumerate() {
+            if mask & (1 << u) != 0 {
+                for (v, w) in row.iter().enumerate() {
+                    if mask & (1 << v) == 0 {
+                        value = value + *w;
+                    }
+                }
+            }
+        }
+        if best.is_none_or(|(best_value, _)| value < best_value) {
+            best = Some((value, mask));
+        }
+    }
+    let (value, mask) = best.expect("at least two merged vertices");
+    let side = (0..k)
+        .filter(|u| mask & (1 << u) != 0)
+        .flat_map(|u| groups[u].iter().copied())
+        .collect();
+    (value, side)
+}
+
+/// Implementation of the Gomory-Hu tree of an undirected graph with non
+/// negative weights, built with Gusfield's algorithm from V - 1 maximum flow
+/// computations. For any two vertices, the minimum weight on the tree path
+/// between them is the value of a minimum cut separating them in the graph.
+/// # Example
+/// ```
+/// use algods::graph::EdgeWeightedGraph;
+/// use algods::graph::processing::GomoryHuTree;
+/// let mut graph = EdgeWeightedGraph::init(4);
+/// graph.add_edge(0, 1, 5);
+/// graph.add_edge(2, 3, 5);
+/// graph.add_edge(1, 2, 1);
+/// graph.add_edge(0, 3, 2);
+/// let tree = GomoryHuTree::init(&graph);
+/// assert_eq!(tree.min_cut(0, 1), Some(6));
+/// assert_eq!(tree.min_cut(1, 3), Some(3));
+/// assert_eq!(tree.min_cut(2, 2), None);
+/// ```
+pub struct GomoryHuTree<T>
+where
+    T: Weight,
+{
+    // parent of each vertex in the tree rooted at 0, and the
+    // weight of the edge to its parent (zero for the root)
+    parent: Vec<usize>,
+    weight: Vec<T>,
+    depth: Vec<usize>,
+}
+impl<T: Weight> GomoryHuTree<T> {
+    pub fn init(graph: &EdgeWeightedGraph<T>) -> Self {
+        // run time complexity O(V) times that of a maximum flow
+        let nb = graph.nb_vertices();
+        let edges = graph.edges();
+        let mut parent = vec![0; nb];
+        let mut weight = vec![T::zero(); nb];
+        for s in 1..nb {
+            let t = parent[s];
+            let mut network = FlowNetwork::init(nb);
+            for (u, v, w) in edges.iter().filter(|(u, v, _)| u != v) {
+                network.add_edge(*u, *v, *w);
+                network.add_edge(*v, *u, *w);
+            }
+            let mut flow = FordFulkerson::new();
+            flow.find_flows(&mut network, s, t);
+            let value = flow.max_flow().expect("the flow is computed");
+            weight[s] = value;
+            for i in (0..nb).filter(|i| *i != s) {
+                if flow.in_cut(i) && parent[i] == t {
+                    parent[i] = s;
+                }
+            }
+            if flow.in_cut(parent[t]) {
+                parent[s] = parent[t];
+                parent[t] = s;
+                weight[s] = weight[t];
+                weight[t] = value;
+            }
+        }
+        let mut depth = vec![usize::MAX; nb];
+        if nb > 0 {
+            depth[0] = 0;
+        }
+        for v in 0..nb {
+            // walks up to a vertex of known depth
+            let mut path = Vec::new();
+            let mut u = v;
+            while depth[u] == usize::MAX {
+                path.push(u);
+                u = parent[u];
+            }
+            while let Some(w) = path.pop() {
+                depth[w] = depth[parent[w]] + 1;
+            }
+        }
+        Self {
+            parent,
+            weight,
+            depth,
+        }
+    }
+    /// Gives the value of a minimum cut separating two distinct vertices,
+    /// `None` when the vertices are the same
+    pub fn min_cut(&self, u: usize, v: usize) -> Option<T> {
+        // run time complexity O(V)
+        let (mut u, mut v) = (u, v);
+        let mut value: Option<T> = None;
+        while u != v {
+            if self.depth[u] < self.depth[v] {
+                std::mem::swap(&mut u, &mut v);
+            }
+            value = Some(value.map_or(self.weight[u], |x| std::cmp::min(x, self.weight[u])));
+            u = self.parent[u];
+        }
+        value
+    }
+    /// Returns the tree as an undirected graph
+    pub fn tree(&self) -> EdgeWeightedGraph<T> {
+        let mut tree = EdgeWeightedGraph::init(self.parent.len());
+        for v in 1..self.parent.len() {
+            tree.add_edge(v, self.parent[v], self.weight[v]);
+        }
+        tree
+    }
+}
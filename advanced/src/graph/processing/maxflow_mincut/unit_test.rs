@@ -1,7 +1,12 @@
 #[cfg(test)]
 
 mod tests {
-    use super::super::{FlowNetwork, FordFulkerson};
+    use super::super::{
+        karger_stein, stoer_wagner, Circulation, FlowNetwork, FordFulkerson, GomoryHuTree,
+    };
+    use crate::graph::EdgeWeightedGraph;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
 
     #[test]
     fn test_ford_fulkerson() {
@@ -20,4 +25,233 @@ mod tests {
         ff.find_flows(&mut network, 0, 5);
         assert_eq!(Some(23), ff.max_flow());
     }
+
+    #[test]
+    fn test_ford_fulkerson_cancels_flow() {
+        // the first shortest augmenting path 0 -> 1 -> 2 -> 3 must be
+        // partially cancelled, and 1 <-> 2 are antiparallel edges
+        let mut network = FlowNetwork::<u32>::init(4);
+        network.add_edge(0, 1, 2);
+        network.add_edge(0, 2, 1);
+        network.add_edge(1, 2, 2);
+        network.add_edge(2, 1, 1);
+        network.add_edge(1, 3, 1);
+        network.add_edge(2, 3, 2);
+        let mut ff = FordFulkerson::new();
+        ff.find_flows(&mut network, 0, 3);
+        assert_eq!(Some(3), ff.max_flow());
+        assert!(ff.in_cut(0));
+        assert!(!ff.in_cut(3));
+        let out_of_source: u32 = network
+            .flow_edges()
+            .iter()
+            .filter(|e| *e.from() == 0)
+            .map(|e| *e.flow())
+            .sum();
+        assert_eq!(out_of_source, 3);
+        assert_eq!(network.flow_edges().len(), 6);
+    }
+
+    fn stoer_wagner_graph() -> EdgeWeightedGraph<u32> {
+        // example of the Stoer-Wagner paper, with vertices numbered from 0
+        let mut graph = EdgeWeightedGraph::init(8);
+        for (u, v, w) in [
+            (0, 1, 2),
+            (0, 4, 3),
+            (1, 2, 3),
+            (1, 4, 2),
+            (1, 5, 2),
+            (2, 3, 4),
+            (2, 6, 2),
+            (3, 6, 2),
+            (3, 7, 2),
+            (4, 5, 3),
+            (5, 6, 1),
+            (6, 7, 3),
+        ] {
+            graph.add_edge(u, v, w);
+        }
+        graph
+    }
+
+    #[test]
+    fn test_stoer_wagner() {
+        let graph = stoer_wagner_graph();
+        assert_eq!(stoer_wagner(&graph), Some((4, vec![0, 1, 4, 5])));
+        // disconnected graph
+        let mut graph = EdgeWeightedGraph::init(4);
+        graph.add_edge(0, 1, 3);
+        graph.add_edge(2, 3, 1);
+        assert_eq!(stoer_wagner(&graph), Some((0, vec![0, 1])));
+        assert_eq!(stoer_wagner(&EdgeWeightedGraph::<u32>::init(1)), None);
+    }
+
+    #[test]
+    fn test_karger_stein() {
+        let graph = stoer_wagner_graph();
+        assert_eq!(karger_stein(&graph, 20, 0), Some((4, vec![0, 1, 4, 5])));
+        // two cliques linked by a single light edge
+        let mut graph = EdgeWeightedGraph::init(12);
+        for offset in [0, 6] {
+            for u in 0..6 {
+                for v in u + 1..6 {
+                    graph.add_edge(offset + u, offset + v, 3);
+                }
+            }
+        }
+        graph.add_edge(5, 6, 2);
+        let (value, side) = karger_stein(&graph, 20, 7).unwrap();
+        assert_eq!(value, 2);
+        assert_eq!(side, vec![0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_gomory_hu_tree() {
+        let graph = stoer_wagner_graph();
+        let nb = graph.nb_vertices();
+        let tree = GomoryHuTree::init(&graph);
+        assert_eq!(tree.tree().nb_edges(), nb - 1);
+        for s in 0..nb {
+            for t in 0..nb {
+                if s == t {
+                    assert_eq!(tree.min_cut(s, t), None);
+                    continue;
+                }
+                let mut network = FlowNetwork::init(nb);
+                for (u, v, w) in graph.edges() {
+                    network.add_edge(u, v, w);
+                    network.add_edge(v, u, w);
+                }
+                let mut ff = FordFulkerson::new();
+                ff.find_flows(&mut network, s, t);
+                assert_eq!(tree.min_cut(s, t), ff.max_flow());
+            }
+        }
+    }
+
+    type Edge = (usize, usize, u32, u32);
+
+    fn balanced(nb: usize, edges: &[Edge], balance: &[(u32, u32)], flows: &[u32]) -> bool {
+        // checks the bounds and that each vertex sends out
+        // its supply plus what it receives minus its demand
+        let mut inflow = balance.iter().map(|(s, _)| *s).collect::<Vec<u32>>();
+        let mut outflow = balance.iter().map(|(_, d)| *d).collect::<Vec<u32>>();
+        for ((from, to, lower, upper), flow) in edges.iter().zip(flows) {
+            if flow < lower || flow > upper {
+                return false;
+            }
+            inflow[*to] += flow;
+            outflow[*from] += flow;
+        }
+        (0..nb).all(|v| inflow[v] == outflow[v])
+    }
+
+    fn brute_force(nb: usize, edges: &[Edge], balance: &[(u32, u32)]) -> bool {
+        let mut flows = edges.iter().map(|e| e.2).collect::<Vec<u32>>();
+        loop {
+            if balanced(nb, edges, balance, &flows) {
+                return true;
+            }
+            let mut e = 0;
+            while e < edges.len() {
+                flows[e] += 1;
+                if flows[e] <= edges[e].3 {
+                    break;
+                }
+                flows[e] = edges[e].2;
+                e += 1;
+            }
+            if e == edges.len() {
+                return false;
+            }
+        }
+    }
+
+    #[test]
+    fn test_circulation() {
+        // the lower bound of 0 -> 1 equals the capacity of 1 -> 2,
+        // so that the flow is unique
+        let mut problem = Circulation::init(4);
+        problem.add_edge(0, 1, 2, 5);
+        problem.add_edge(1, 2, 0, 2);
+        problem.add_edge(0, 2, 0, 3);
+        problem.add_edge(2, 3, 1, 4);
+        problem.add_supply(0, 4);
+        problem.add_demand(3, 4);
+        assert_eq!(problem.solve().unwrap(), vec![2, 2, 2, 4]);
+        // a circulation whose lower bound goes around the cycle
+        let mut problem = Circulation::init(3);
+        problem.add_edge(0, 1, 3, 6);
+        problem.add_edge(1, 2, 0, 3);
+        problem.add_edge(2, 0, 1, 5);
+        assert_eq!(problem.solve().unwrap(), vec![3, 3, 3]);
+    }
+
+    #[test]
+    fn test_circulation_infeasible() {
+        // the vertex 0 produces 5 units but can send out only 4
+        let mut problem = Circulation::init(4);
+        problem.add_edge(0, 1, 0, 2);
+        problem.add_edge(0, 2, 0, 2);
+        problem.add_edge(1, 3, 0, 5);
+        problem.add_edge(2, 3, 0, 5);
+        problem.add_supply(0, 5);
+        problem.add_demand(3, 5);
+        let certificate = problem.solve().unwrap_err();
+        assert_eq!(certificate.vertices(), &[0]);
+        assert!(certificate.is_surplus());
+        assert_eq!((certificate.required(), certificate.available()), (5, 4));
+        // the vertices consume 3 units but only 2 are produced
+        let mut problem = Circulation::init(3);
+        problem.add_edge(0, 1, 0, 5);
+        problem.add_edge(1, 2, 0, 5);
+        problem.add_supply(0, 2);
+        problem.add_demand(2, 3);
+        let certificate = problem.solve().unwrap_err();
+        assert_eq!(certificate.vertices(), &[0, 1, 2]);
+        assert!(!certificate.is_surplus());
+        assert_eq!((certificate.required(), certificate.available()), (3, 2));
+    }
+
+    #[test]
+    fn test_circulation_random() {
+        let mut rng = StdRng::seed_from_u64(44);
+        for _ in 0..500 {
+            let nb = rng.gen_range(1..5);
+            let edges = (0..rng.gen_range(0..6))
+                .map(|_| {
+                    let lower = rng.gen_range(0..2);
+                    (
+                        rng.gen_range(0..nb),
+                        rng.gen_range(0..nb),
+                        lower,
+                        lower + rng.gen_range(0..3),
+                    )
+                })
+                .collect::<Vec<Edge>>();
+            let balance = (0..nb)
+                .map(|_| match rng.gen_range(0..4) {
+                    0 => (rng.gen_range(1..4), 0),
+                    1 => (0, rng.gen_range(1..4)),
+                    _ => (0, 0),
+                })
+                .collect::<Vec<(u32, u32)>>();
+            let mut problem = Circulation::init(nb);
+            for (from, to, lower, upper) in &edges {
+                problem.add_edge(*from, *to, *lower, *upper);
+            }
+            for (v, (supply, demand)) in balance.iter().enumerate() {
+                problem.add_supply(v, *supply);
+                problem.add_demand(v, *demand);
+            }
+            match problem.solve() {
+                Ok(flows) => assert!(balanced(nb, &edges, &balance, &flows)),
+                Err(certificate) => {
+                    assert!(!brute_force(nb, &edges, &balance));
+                    assert!(!certificate.vertices().is_empty());
+                    assert!(certificate.required() > certificate.available());
+                }
+            }
+        }
+    }
 }
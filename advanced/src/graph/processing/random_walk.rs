@@ -0,0 +1,279 @@
+#[cfg(test)]
+mod unit_test;
+use crate::graph::{EdgeWeightedDigraph, ToF64, VertexInfo, Weight};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Enumeration of the ways a random walk chooses its next vertex
+#[derive(Default, Clone, Copy, Debug)]
+pub enum WalkStrategy {
+    /// Moves to an out neighbor with a probability proportional to the
+    /// weight of the edge (uniformly for unweighted graphs), stopping at a
+    /// vertex without out neighbors
+    #[default]
+    Uniform,
+    /// Moves back to the start with some probability at each step
+    /// (and when stuck), otherwise moves like the uniform walk
+    Restart { probability: f64 },
+    /// Second order walk of node2vec: coming from t to v, the weight of an
+    /// edge from v to x is divided by p when x is t (return parameter), kept
+    /// when x is an out neighbor of t, and divided by q otherwise (in-out
+    /// parameter), the first step being uniform
+    Node2Vec { p: f64, q: f64 },
+}
+
+/// Implementation of random walks on a directed graph (undirected edges
+/// being followed both ways), possibly with nonnegative edge weights.
+/// The walks are seedable, each walk of a batch having its own random
+/// generator so that the walks do not depend on the number of threads.
+/// # Example
+/// ```
+/// use algods::graph::UndirectedGraph;
+/// use algods::graph::processing::{RandomWalker, WalkStrategy};
+/// let mut graph = UndirectedGraph::init(4);
+/// graph.add_edge(0, 1);
+/// graph.add_edge(1, 2);
+/// graph.add_edge(2, 3);
+/// let walker = RandomWalker::init(&graph);
+/// let walk = walker.walk(0, 5, WalkStrategy::Uniform, 42);
+/// assert_eq!(walk.len(), 6);
+/// assert!(walk.windows(2).all(|w| w[0].abs_diff(w[1]) == 1));
+/// // two walks from each vertex, generated by two threads
+/// let starts = (0..4).flat_map(|v| [v, v]).collect::<Vec<usize>>();
+/// let strategy = WalkStrategy::Node2Vec { p: 4.0, q: 0.5 };
+/// let walks = walker.walks(&starts, 10, strategy, 42, 2);
+/// assert_eq!(walks.len(), 8);
+/// assert_eq!(walks, walker.walks(&starts, 10, strategy, 42, 1));
+/// ```
+pub struct RandomWalker {
+    // out neighbors of each vertex, sorted
+    neighbors: Vec<Vec<usize>>,
+    // cumulative weights of the edges to the out neighbors
+    cumulative: Vec<Vec<f64>>,
+}
+impl RandomWalker {
+    /// Creates the walker of an unweighted graph
+    pub fn init<G: VertexInfo>(graph: &G) -> Self {
+        let nb = graph.nb_vertices();
+        Self::from_edges(
+            (0..nb)
+                .map(|v| {
+                    graph
+                        .vertex_edges(&v)
+                        .into_iter()
+                        .map(|w| (*w, 1.0))
+                        .collect()
+                })
+                .collect(),
+        )
+    }
+    /// Creates the walker of a weighted graph, whose weights must be
+    /// nonnegative, parallel edges adding up their weights
+    pub fn init_weighted<T: Weight + ToF64>(graph: &EdgeWeightedDigraph<T>) -> Self {
+        let nb = graph.nb_vertices();
+        Self::from_edges(
+            (0..nb)
+                .map(|v| {
+                    graph
+                        .vertex_edges(&v)
+                        .into_iter()
+                        .map(|(w, weight)| {
+                            assert!(*weight >= T::zero(), "negative edge weight");
+                            (*w, weight.to_f64())
+                        })
+                        .collect()
+                })
+                .collect(),
+        )
+    }
+    fn from_edges(mut edges: Vec<Vec<(usize, f64)>>) -> Self {
+        let mut neighbors = Vec::with_capacity(edges.len());
+        let mut cumulative = Vec::with_capacity(edges.len());
+        for adjacent in edges.iter_mut() {
+            adjacent.sort_unstable_by_key(|(w, _)| *w);
+            let (mut vertices, mut sums) = (Vec::<usize>::new(), Vec::<f64>::new());
+            for (w, weight) in adjacent.iter() {
+                let total = sums.last().copied().unwrap_or(0.0);
+                if vertices.last() == Some(w) {
+                    *sums.last_mut().expect("same length as vertices") += weight;
+                } else {
+                    vertices.push(*w);
+                    sums.push(total + weight);
+                }
+            }
+            neighbors.push(vertices);
+            cumulative.push(sums);
+        }
+        Self {
+            neighbors,
+            cumulative,
+        }
+    }
+    pub fn nb_vertices(&self) -> usize {
+        self.neighbors.len()
+    }
+    fn weight(&self, v: usize, i: usize) -> f64 {
+        // weight of the edge to the i-th out neighbor of v
+        let sums = &self.cumulative[v];
+        sums[i] - if i > 0 { sums[i - 1] } else { 0.0 }
+    }
+    fn first_order_step(&self, v: usize, rng: &mut StdRng) -> Option<usize> {
+        // run time complexity O(log(out degree))
+        let total = *self.cumulative[v].last()?;
+        if total <= 0.0 {
+            return None;
+        }
+        let target = rng.gen::<f64>() * total;
+        let i = self.cumulative[v].partition_point(|sum| *sum <= target);
+        // rounding may lead past the last out neighbor
+        Some(self.neighbors[v][std::cmp::min(i, self.neighbors[v].len() - 1)])
+    }
+    fn second_order_step(
+        &self,
+        previous: usize,
+        v: usize,
+        (p, q): (f64, f64),
+        rng: &mut StdRng,
+    ) -> Option<usize> {
+        // run time complexity O(out degree of v * log(out degree of previous))
+        let biased = (0..self.neighbors[v].len())
+            .map(|i| {
+                let x = self.neighbors[v][i];
+                let factor = if x == previous {
+                    1.0 / p
+                } else if self.neighbors[previous].binary_search(&x).is_ok() {
+                    1.0
+                } else {
+                    1.0 / q
+                };
+                self.weight(v, i) * factor
+            })
+            .collect::<Vec<f64>>();
+        let total = biased.iter().sum::<f64>();
+        if total <= 0.0 {
+            return None;
+        }
+        let mut target = rng.gen::<f64>() * total;
+        for (i, weight) in biased.iter().enumerate() {
+            if target < *weight {
+                return Some(self.neighbors[v][i]);
+            }
+            target -= weight;
+        }
+        // rounding may lead past the last out neighbor with a positive weight
+        biased
+            .iter()
+            .rposition(|weight| *weight > 0.0)
+            .map(|i| self.neighbors[v][i])
+    }
+    fn walk_with(
+        &self,
+        start: usize,
+        nb_steps: usize,
+        strategy: WalkStrategy,
+        rng: &mut StdRng,
+    ) -> Vec<usize> {
+        assert!(start < self.nb_vertices());
+        let mut walk = Vec::with_capacity(nb_steps + 1);
+        walk.push(start);
+        while walk.len() <= nb_steps {
+            let v = *walk.last().expect("the walk starts at start");
+            let next = match strategy {
+                WalkStrategy::Uniform => self.first_order_step(v, rng),
+                WalkStrategy::Restart { probability } => {
+                    if rng.gen::<f64>() < probability {
+                        Some(start)
+                    } else {
+                        // a stuck walk restarts, unless it is stuck at the start
+                        self.first_order_step(v, rng)
+                            .or((v != start).then_some(start))
+                    }
+                }
+                WalkStrategy::Node2Vec { p, q } => match walk.len() {
+                    1 => self.first_order_step(v, rng),
+                    len => self.second_order_step(walk[len - 2], v, (p, q), rng),
+                },
+            };
+            match next {
+                Some(w) => walk.push(w),
+                None => break,
+            }
+        }
+        walk
+    }
+    /// Gives a random walk of at most `nb_steps` steps from a vertex, the
+    /// walk starting with the vertex, and stopping early when it is stuck
+    pub fn walk(
+        &self,
+        start: usize,
+        nb_steps: usize,
+        strategy: WalkStrategy,
+        seed: u64,
+    ) -> Vec<usize> {
+        check(strategy);
+        self.walk_with(start, nb_steps, strategy, &mut StdRng::seed_from_u64(seed))
+    }
+    /// Gives the seed of the i-th walk of a batch, mixing the seed of the
+    /// batch with the index by a SplitMix64 step, so that the walks of
+    /// batches with close seeds (or of the same batch) are not correlated
+    pub fn walk_seed(seed: u64, i: usize) -> u64 {
+        let mut z = seed.wrapping_add((i as u64).wrapping_add(1).wrapping_mul(0x9E3779B97F4A7C15));
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+    /// Gives one random walk from each start vertex, generated by
+    /// `nb_threads` threads, the walk from `starts[i]` being the one given by
+    /// `self.walk(starts[i], nb_steps, strategy, RandomWalker::walk_seed(seed, i))`
+    pub fn walks(
+        &self,
+        starts: &[usize],
+        nb_steps: usize,
+        strategy: WalkStrategy,
+        seed: u64,
+        nb_threads: usize,
+    ) -> Vec<Vec<usize>> {
+        check(strategy);
+        assert!(nb_threads > 0, "at least one thread is needed");
+        let chunk_size = std::cmp::max(1, starts.len().div_ceil(nb_threads));
+        std::thread::scope(|scope| {
+            let handles = starts
+                .chunks(chunk_size)
+                .enumerate()
+                .map(|(c, chunk)| {
+                    scope.spawn(move || {
+                        chunk
+                            .iter()
+                            .enumerate()
+                            .map(|(i, start)| {
+                                let seed = Self::walk_seed(seed, c * chunk_size + i);
+                                let mut rng = StdRng::seed_from_u64(seed);
+                                self.walk_with(*start, nb_steps, strategy, &mut rng)
+                            })
+                            .collect::<Vec<Vec<usize>>>()
+                    })
+                })
+                .collect::<Vec<_>>();
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().expect("a walking thread panicked"))
+                .collect()
+        })
+    }
+}
+
+fn check(strategy: WalkStrategy) {
+    match strategy {
+        WalkStrategy::Uniform => {}
+        WalkStrategy::Restart { probability } => assert!(
+            (0.0..=1.0).contains(&probability),
+            "the restart probability is not in [0, 1]"
+        ),
+        WalkStrategy::Node2Vec { p, q } => {
+            assert!(
+                p > 0.0 && q > 0.0,
+                "the parameters p and q must be positive"
+            )
+        }
+    }
+}
@@ -0,0 +1,142 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{RandomWalker, WalkStrategy};
+    use crate::graph::{DirectedGraph, EdgeWeightedDigraph, UndirectedGraph};
+
+    fn cycle(nb: usize) -> UndirectedGraph {
+        let mut graph = UndirectedGraph::init(nb);
+        for v in 0..nb {
+            graph.add_edge(v, (v + 1) % nb);
+        }
+        graph
+    }
+
+    #[test]
+    fn test_uniform_walk() {
+        let graph = cycle(7);
+        let walker = RandomWalker::init(&graph);
+        let walk = walker.walk(3, 50, WalkStrategy::Uniform, 0);
+        assert_eq!(walk.len(), 51);
+        assert_eq!(walk[0], 3);
+        assert!(walk
+            .windows(2)
+            .all(|w| (w[0] + 1) % 7 == w[1] || (w[1] + 1) % 7 == w[0]));
+        assert_eq!(walk, walker.walk(3, 50, WalkStrategy::Uniform, 0));
+        assert_eq!(walker.walk(3, 0, WalkStrategy::Uniform, 0), vec![3]);
+
+        // the walk stops at a vertex without out neighbors
+        let mut graph = DirectedGraph::init(3);
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        let walker = RandomWalker::init(&graph);
+        assert_eq!(walker.walk(0, 10, WalkStrategy::Uniform, 1), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_weighted_walk() {
+        let mut graph = EdgeWeightedDigraph::<u32>::init(4);
+        graph.add_edge(0, 1, 3);
+        graph.add_edge(0, 2, 1);
+        graph.add_edge(0, 3, 0);
+        for v in 1..4 {
+            graph.add_edge(v, 0, 1);
+        }
+        let walker = RandomWalker::init_weighted(&graph);
+        let walk = walker.walk(0, 20_000, WalkStrategy::Uniform, 5);
+        let mut counts = [0; 4];
+        for v in walk.iter().skip(1).step_by(2) {
+            counts[*v] += 1;
+        }
+        assert_eq!(counts[0] + counts[3], 0);
+        let frequency = counts[1] as f64 / 10_000.0;
+        assert!((frequency - 0.75).abs() < 0.02, "{frequency}");
+    }
+
+    #[test]
+    fn test_restart_walk() {
+        let mut graph = DirectedGraph::init(4);
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        graph.add_edge(2, 3);
+        let walker = RandomWalker::init(&graph);
+        // a stuck walk goes back to the start
+        let strategy = WalkStrategy::Restart { probability: 0.0 };
+        let walk = walker.walk(1, 7, strategy, 3);
+        assert_eq!(walk, vec![1, 2, 3, 1, 2, 3, 1, 2]);
+        let strategy = WalkStrategy::Restart { probability: 1.0 };
+        assert_eq!(walker.walk(2, 4, strategy, 3), vec![2; 5]);
+        // the walk cannot leave a start without out neighbors
+        let strategy = WalkStrategy::Restart { probability: 0.5 };
+        assert_eq!(walker.walk(3, 4, strategy, 3).len(), 5);
+        let walk = walker.walk(0, 1000, strategy, 3);
+        let nb_restarts = walk.windows(2).filter(|w| w[1] == 0).count();
+        assert!(nb_restarts > 300, "{nb_restarts}");
+    }
+
+    #[test]
+    fn test_node2vec_walk() {
+        let graph = cycle(6);
+        let walker = RandomWalker::init(&graph);
+        // the walk nearly always goes back when p is small
+        let strategy = WalkStrategy::Node2Vec { p: 1e-9, q: 1.0 };
+        let walk = walker.walk(0, 100, strategy, 11);
+        assert!(walk.windows(3).all(|w| w[0] == w[2]));
+        // and nearly never when p is large
+        let strategy = WalkStrategy::Node2Vec { p: 1e9, q: 1.0 };
+        let walk = walker.walk(0, 100, strategy, 11);
+        assert!(walk.windows(3).all(|w| w[0] != w[2]));
+
+        // on a triangle with a tail, a small q favors moving away
+        let mut graph = UndirectedGraph::init(4);
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        graph.add_edge(2, 0);
+        graph.add_edge(1, 3);
+        let walker = RandomWalker::init(&graph);
+        let strategy = WalkStrategy::Node2Vec { p: 1e9, q: 1e-9 };
+        let starts = vec![0; 100];
+        for walk in walker.walks(&starts, 2, strategy, 0, 4) {
+            if walk[1] == 1 {
+                assert_eq!(walk[2], 3);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parallel_walks() {
+        let graph = cycle(10);
+        let walker = RandomWalker::init(&graph);
+        let starts = (0..10).flat_map(|v| [v; 3]).collect::<Vec<usize>>();
+        let strategy = WalkStrategy::Node2Vec { p: 0.5, q: 2.0 };
+        let walks = walker.walks(&starts, 15, strategy, 17, 1);
+        assert_eq!(walks.len(), 30);
+        for (i, walk) in walks.iter().enumerate() {
+            assert_eq!(walk[0], starts[i]);
+            let seed = RandomWalker::walk_seed(17, i);
+            assert_eq!(walk, &walker.walk(starts[i], 15, strategy, seed));
+        }
+        for nb_threads in [2, 3, 7, 30, 64] {
+            assert_eq!(walks, walker.walks(&starts, 15, strategy, 17, nb_threads));
+        }
+        assert!(walker.walks(&[], 15, strategy, 17, 4).is_empty());
+    }
+
+    #[test]
+    fn test_walk_seed() {
+        // reference values of SplitMix64 from the seed 0
+        assert_eq!(RandomWalker::walk_seed(0, 0), 0xE220A8397B1DCDAF);
+        assert_eq!(RandomWalker::walk_seed(0, 1), 0x6E789E6AA1B965F4);
+        // the walks of batches with consecutive seeds differ
+        let seeds = (0..100)
+            .flat_map(|seed| (0..100).map(move |i| RandomWalker::walk_seed(seed, i)))
+            .collect::<std::collections::HashSet<u64>>();
+        assert_eq!(seeds.len(), 10_000);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_node2vec_parameters() {
+        let walker = RandomWalker::init(&cycle(3));
+        walker.walk(0, 3, WalkStrategy::Node2Vec { p: 0.0, q: 1.0 }, 0);
+    }
+}
@@ -0,0 +1,230 @@
+#[cfg(test)]
+mod unit_test;
+use crate::graph::processing::StrongConnectedComponent;
+use crate::graph::DirectedGraph;
+use bitvec::prelude::{BitVec, Lsb0};
+
+/// Implementation of the transitive closure of a directed graph,
+/// answering whether or not a vertex can reach another one in constant time
+/// # Example
+/// ```
+/// use algods::graph::DirectedGraph;
+/// use algods::graph::processing::TransitiveClosure;
+/// let mut graph = DirectedGraph::init(3);
+/// graph.add_edge(0, 1);
+/// graph.add_edge(1, 2);
+/// let mut closure = TransitiveClosure::init(graph.nb_vertices());
+/// closure.find_closure(&graph);
+/// assert!(closure.reachable(0, 2));
+/// assert!(!closure.reachable(2, 0));
+/// ```
+pub struct TransitiveClosure {
+    // reachable[v][w] indicates whether or not there is a path from v to w,
+    // each vertex reaches itself
+    reachable: Vec<BitVec<usize, Lsb0>>,
+}
+impl TransitiveClosure {
+    pub fn init(nb_vertices: usize) -> Self {
+        Self {
+            reachable: vec![BitVec::repeat(false, nb_vertices); nb_vertices],
+        }
+    }
+    pub fn find_closure<V, E>(&mut self, graph: &DirectedGraph<V, E>) {
+        // runs a depth first search from each vertex
+        // run time complexity O(V(V + E))
+        let nb = graph.nb_vertices();
+        assert_eq!(nb, self.reachable.len());
+        let mut stack = Vec::new();
+        for (v, marked) in self.reachable.iter_mut().enumerate() {
+            marked.set(v, true);
+            stack.push(v);
+            while let Some(x) = stack.pop() {
+                for u in graph.vertex_edges(&x) {
+                    if !marked[*u] {
+                        marked.set(*u, true);
+                        stack.push(*u);
+                    }
+                }
+            }
+        }
+    }
+    pub fn reachable(&self, v: usize, w: usize) -> bool {
+        // finds out whether or not there is a path from v to w
+        // run time complexity O(1)
+        self.reachable[v][w]
+    }
+    /// Gives the number of vertices reachable from a vertex, itself included
+    pub fn nb_reachable(&self, v: usize) -> usize {
+        self.reachable[v].count_ones()
+    }
+    /// Builds a graph with the same vertices and reachability relation as the
+    /// processed graph, having as few edges as possible. Each strong connected
+    /// component is reduced to a cycle going through its vertices, and the
+    /// components are linked by the edges of the transitive reduction of the
+    /// condensation. For a DAG, this is its unique transitive reduction.
+    pub fn transitive_reduction(&self) -> DirectedGraph {
+        // run time complexity O(V^3 / 64)
+        let nb = self.reachable.len();
+        let mut reduction = DirectedGraph::init(nb);
+        // the representative of a component is its smallest vertex
+        let mut representative = (0..nb).collect::<Vec<usize>>();
+        for v in 0..nb {
+            if representative[v] != v {
+                continue;
+            }
+            let members = self.reachable[v]
+                .iter_ones()
+                .filter(|w| self.reachable[*w][v])
+                .collect::<Vec<usize>>();
+            for w in &members {
+                representative[*w] = v;
+            }
+            if members.len() > 1 {
+                for i in 0..members.len() {
+                    reduction.add_edge(members[i], members[(i + 1) % members.len()]);
+                }
+            }
+        }
+        // the components strictly reachable from each component
+        let strict = (0..nb)
+            .map(|v| {
+                let mut reps = BitVec::<usize, Lsb0>::repeat(false, nb);
+                if representative[v] == v {
+                    for w in self.reachable[v].iter_ones() {
+                        if representative[w] != v {
+                            reps.set(representative[w], true);
+                        }
+                    }
+                }
+                reps
+            })
+            .collect::<Vec<BitVec<usize, Lsb0>>>();
+        for v in (0..nb).filter(|v| representative[*v] == *v) {
+            // removes the components reachable through another component
+            let mut indirect = BitVec::<usize, Lsb0>::repeat(false, nb);
+            for w in strict[v].iter_ones() {
+                *indirect.as_mut_bitslice() |= strict[w].as_bitslice();
+            }
+            for w in strict[v].iter_ones().filter(|w| !indirect[*w]) {
+                reduction.add_edge(v, w);
+            }
+        }
+        reduction
+    }
+}
+
+/// Implementation of a compact reachability index for large (nearly acyclic)
+/// directed graphs. The graph is contracted into its condensation, on which a
+/// spanning forest is numbered in postorder. Each component is then labeled by
+/// the merged intervals of postorder numbers of the components it can reach.
+/// # Example
+/// ```
+/// use algods::graph::DirectedGraph;
+/// use algods::graph::processing::ReachabilityIndex;
+/// let mut graph = DirectedGraph::init(4);
+/// graph.add_edge(0, 1);
+/// graph.add_edge(1, 0);
+/// graph.add_edge(1, 2);
+/// let mut index = ReachabilityIndex::init(graph.nb_vertices());
+/// index.build_index(&graph);
+/// assert!(index.reachable(0, 2));
+/// assert!(index.reachable(1, 0));
+/// assert!(!index.reachable(2, 0));
+/// assert!(!index.reachable(0, 3));
+/// ```
+pub struct ReachabilityIndex {
+    // strong connected component of each vertex
+    component: Vec<usize>,
+    // postorder number of each component in the spanning forest
+    post: Vec<usize>,
+    // sorted disjoint intervals of postorder numbers reachable from each component
+    labels: Vec<Vec<(usize, usize)>>,
+}
+impl ReachabilityIndex {
+    pub fn init(nb_vertices: usize) -> Self {
+        Self {
+            component: vec![0; nb_vertices],
+            post: Vec::new(),
+            labels: Vec::new(),
+        }
+    }
+    pub fn build_index<V, E>(&mut self, graph: &DirectedGraph<V, E>) {
+        let nb = graph.nb_vertices();
+        assert_eq!(nb, self.component.len());
+        let mut scc = StrongConnectedComponent::init(nb);
+        scc.find_scc(graph);
+        for v in 0..nb {
+            self.component[v] = scc.id(v).expect("all vertices are visited");
+        }
+        let dag = scc.condensation(graph);
+        let nb_cc = dag.nb_vertices();
+
+        // number the components in postorder of a depth first spanning forest,
+        // low[c] being the smallest postorder number in the subtree of c
+        self.post = vec![0; nb_cc];
+        let mut low = vec![0; nb_cc];
+        let mut marked = vec![false; nb_cc];
+        let mut counter = 0;
+        let mut stack = Vec::new();
+        for root in (0..nb_cc).rev().filter(|c| dag.in_edges(c).is_empty()) {
+            marked[root] = true;
+            stack.push((root, dag.vertex_edges(&root), counter));
+            while let Some((c, mut adjacent, start)) = stack.pop() {
+                match adjacent.pop() {
+                    Some(d) => {
+                        let d = *d;
+                        stack.push((c, adjacent, start));
+                        if !marked[d] {
+                            marked[d] = true;
+                            stack.push((d, dag.vertex_edges(&d), counter));
+                        }
+                    }
+                    None => {
+                        low[c] = start;
+                        self.post[c] = counter;
+                        counter += 1;
+                    }
+                }
+            }
+        }
+
+        // edges of the condensation go from a component to components
+        // with smaller identifiers, which are then labeled first
+        self.labels = Vec::with_capacity(nb_cc);
+        for (c, start) in low.into_iter().enumerate() {
+            let mut intervals = vec![(start, self.post[c])];
+            for d in dag.vertex_edges(&c) {
+                intervals.extend(self.labels[*d].iter());
+            }
+            self.labels.push(merge_intervals(intervals));
+        }
+    }
+    pub fn reachable(&self, v: usize, w: usize) -> bool {
+        // finds out whether or not there is a path from v to w
+        // run time complexity O(log(number of intervals of v))
+        let number = self.post[self.component[w]];
+        let label = &self.labels[self.component[v]];
+        match label.binary_search_by(|(start, _)| start.cmp(&number)) {
+            Ok(_) => true,
+            Err(0) => false,
+            Err(pos) => label[pos - 1].1 >= number,
+        }
+    }
+    /// Gives the total number of intervals stored by the index
+    pub fn nb_intervals(&self) -> usize {
+        self.labels.iter().map(|label| label.len()).sum()
+    }
+}
+
+fn merge_intervals(mut intervals: Vec<(usize, usize)>) -> Vec<(usize, usize)> {
+    // merges overlapping or contiguous integer intervals
+    intervals.sort_unstable();
+    let mut merged: Vec<(usize, usize)> = Vec::with_capacity(intervals.len());
+    for (start, end) in intervals {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 + 1 => last.1 = std::cmp::max(last.1, end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
@@ -0,0 +1,117 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{ReachabilityIndex, TransitiveClosure};
+    use crate::graph::processing::DepthFirstSearch;
+    use crate::graph::DirectedGraph;
+
+    fn tiny_digraph() -> DirectedGraph {
+        let mut graph = DirectedGraph::init(13);
+        graph.add_edge(0, 1);
+        graph.add_edge(0, 5);
+        graph.add_edge(2, 0);
+        graph.add_edge(2, 3);
+        graph.add_edge(3, 2);
+        graph.add_edge(3, 5);
+        graph.add_edge(4, 2);
+        graph.add_edge(4, 3);
+        graph.add_edge(5, 4);
+        graph.add_edge(6, 0);
+        graph.add_edge(6, 4);
+        graph.add_edge(6, 8);
+        graph.add_edge(6, 9);
+        graph.add_edge(7, 6);
+        graph.add_edge(7, 9);
+        graph.add_edge(8, 6);
+        graph.add_edge(9, 10);
+        graph.add_edge(9, 11);
+        graph.add_edge(10, 12);
+        graph.add_edge(11, 4);
+        graph.add_edge(11, 12);
+        graph.add_edge(12, 9);
+        graph
+    }
+
+    fn tiny_dag() -> DirectedGraph {
+        let mut graph = DirectedGraph::init(7);
+        graph.add_edge(0, 1);
+        graph.add_edge(0, 2);
+        graph.add_edge(0, 5);
+        graph.add_edge(1, 4);
+        graph.add_edge(3, 2);
+        graph.add_edge(3, 4);
+        graph.add_edge(3, 5);
+        graph.add_edge(3, 6);
+        graph.add_edge(5, 2);
+        graph.add_edge(6, 4);
+        graph.add_edge(6, 0);
+        graph
+    }
+
+    fn dfs_reachable(graph: &DirectedGraph, v: usize, w: usize) -> bool {
+        let mut dfs = DepthFirstSearch::init(graph.nb_vertices(), v);
+        dfs.find_paths(graph);
+        dfs.path_to(w).is_some()
+    }
+
+    #[test]
+    fn test_transitive_closure() {
+        for graph in [tiny_digraph(), tiny_dag()] {
+            let nb = graph.nb_vertices();
+            let mut closure = TransitiveClosure::init(nb);
+            closure.find_closure(&graph);
+            for v in 0..nb {
+                for w in 0..nb {
+                    assert_eq!(closure.reachable(v, w), dfs_reachable(&graph, v, w));
+                }
+            }
+        }
+        let mut closure = TransitiveClosure::init(13);
+        closure.find_closure(&tiny_digraph());
+        assert_eq!(closure.nb_reachable(1), 1);
+        assert_eq!(closure.nb_reachable(0), 6);
+    }
+
+    #[test]
+    fn test_transitive_reduction() {
+        let graph = tiny_dag();
+        let mut closure = TransitiveClosure::init(graph.nb_vertices());
+        closure.find_closure(&graph);
+        let reduction = closure.transitive_reduction();
+        // 0 -> 2, 3 -> 2, 3 -> 4, 3 -> 5 and 3 -> 0 are implied by other edges
+        assert_eq!(reduction.nb_edges(), 6);
+        for (v, w) in [(0, 1), (0, 5), (1, 4), (3, 6), (5, 2), (6, 0)] {
+            assert!(reduction.vertex_edges(&v).contains(&&w));
+        }
+
+        let graph = tiny_digraph();
+        let mut closure = TransitiveClosure::init(graph.nb_vertices());
+        closure.find_closure(&graph);
+        let reduction = closure.transitive_reduction();
+        let mut reduced_closure = TransitiveClosure::init(graph.nb_vertices());
+        reduced_closure.find_closure(&reduction);
+        for v in 0..graph.nb_vertices() {
+            for w in 0..graph.nb_vertices() {
+                assert_eq!(closure.reachable(v, w), reduced_closure.reachable(v, w));
+            }
+        }
+        // cycles through the components of size 5, 4 and 2,
+        // plus 7 -> 6 -> 9 -> 4 -> 1 between the components
+        assert_eq!(reduction.nb_edges(), 5 + 4 + 2 + 4);
+    }
+
+    #[test]
+    fn test_reachability_index() {
+        for graph in [tiny_digraph(), tiny_dag()] {
+            let nb = graph.nb_vertices();
+            let mut closure = TransitiveClosure::init(nb);
+            closure.find_closure(&graph);
+            let mut index = ReachabilityIndex::init(nb);
+            index.build_index(&graph);
+            for v in 0..nb {
+                for w in 0..nb {
+                    assert_eq!(index.reachable(v, w), closure.reachable(v, w));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,196 @@
+#[cfg(test)]
+mod unit_test;
+use crate::graph::processing::{ShortestPath, ShortestPathAlgo};
+use crate::graph::{EdgeWeightedDigraph, Weight};
+
+/// Implementation of critical path scheduling of jobs with durations,
+/// precedence constraints and relative deadlines. The start times are the
+/// longest paths in a constraint graph, computed as shortest paths with
+/// negated durations by the Bellman-Ford algorithm, so that deadlines
+/// (backward edges) are supported.
+/// # Example
+/// ```
+/// use algods::graph::processing::scheduling::Scheduler;
+/// let mut scheduler = Scheduler::new();
+/// let design = scheduler.add_job(3);
+/// let build = scheduler.add_job(5);
+/// let docs = scheduler.add_job(2);
+/// let release = scheduler.add_job(1);
+/// scheduler.add_precedence(design, build);
+/// scheduler.add_precedence(design, docs);
+/// scheduler.add_precedence(build, release);
+/// scheduler.add_precedence(docs, release);
+/// let schedule = scheduler.schedule().unwrap();
+/// assert_eq!(schedule.makespan(), 9);
+/// assert_eq!(schedule.start(release), 8);
+/// assert_eq!(schedule.slack(docs), 3);
+/// assert_eq!(schedule.critical_path(), &[design, build, release]);
+/// // the documentation must start at most four units after the design
+/// scheduler.add_deadline(design, docs, 4);
+/// assert_eq!(scheduler.schedule().unwrap().slack(docs), 1);
+/// // the release cannot start five units after the design
+/// scheduler.add_deadline(design, release, 5);
+/// assert!(scheduler.schedule().is_none());
+/// ```
+pub struct Scheduler<T> {
+    durations: Vec<T>,
+    // (before, after): after cannot start before before is finished
+    precedences: Vec<(usize, usize)>,
+    // (job, other, limit): other must start at most limit after job starts
+    deadlines: Vec<(usize, usize, T)>,
+}
+impl<T: Weight + std::ops::Neg<Output = T>> Default for Scheduler<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T: Weight + std::ops::Neg<Output = T>> Scheduler<T> {
+    /// Creates a scheduler without jobs
+    pub fn new() -> Self {
+        Self {
+            durations: Vec::new(),
+            precedences: Vec::new(),
+            deadlines: Vec::new(),
+        }
+    }
+    /// Creates a scheduler with jobs of the given durations,
+    /// numbered from 0 in the given order
+    pub fn init(durations: Vec<T>) -> Self {
+        let mut scheduler = Self::new();
+        scheduler.durations = durations;
+        scheduler
+    }
+    /// Gives the number of jobs
+    pub fn nb_jobs(&self) -> usize {
+        self.durations.len()
+    }
+    /// Adds a job with a (non negative) duration, returns its number
+    pub fn add_job(&mut self, duration: T) -> usize {
+        self.durations.push(duration);
+        self.durations.len() - 1
+    }
+    /// Adds the constraint that the job after cannot start
+    /// before the job before is finished
+    pub fn add_precedence(&mut self, before: usize, after: usize) {
+        assert!(std::cmp::max(before, after) < self.nb_jobs());
+        self.precedences.push((before, after));
+    }
+    /// Adds the constraint that the job other must start
+    /// at most limit after the start of the job job
+    pub fn add_deadline(&mut self, job: usize, other: usize, limit: T) {
+        assert!(std::cmp::max(job, other) < self.nb_jobs());
+        self.deadlines.push((job, other, limit));
+    }
+    /// Gives the constraint graph with negated weights: vertex nb_jobs is the
+    /// start of the project, vertex nb_jobs + 1 its end, and a path of weight
+    /// -w from a vertex to another means that the latter starts at least w
+    /// after the former.
+    pub fn constraint_graph(&self) -> EdgeWeightedDigraph<T> {
+        let nb = self.nb_jobs();
+        let (source, sink) = (nb, nb + 1);
+        let mut graph = EdgeWeightedDigraph::init(nb + 2);
+        for (job, duration) in self.durations.iter().enumerate() {
+            graph.add_edge(source, job, T::zero());
+            graph.add_edge(job, sink, -*duration);
+        }
+        for (before, after) in &self.precedences {
+            graph.add_edge(*before, *after, -self.durations[*before]);
+        }
+        for (job, other, limit) in &self.deadlines {
+            graph.add_edge(*other, *job, *limit);
+        }
+        graph
+    }
+    /// Computes the earliest schedule satisfying all the constraints,
+    /// `None` when they are contradictory (a cycle of precedences with a
+    /// positive duration, or deadlines which are too short)
+    pub fn schedule(&self) -> Option<Schedule<T>> {
+        // run time complexity O(V(V + E)) with E the number of constraints
+        let nb = self.nb_jobs();
+        let (source, sink) = (nb, nb + 1);
+        if nb == 0 {
+            return Some(Schedule {
+                start: Vec::new(),
+                latest_start: Vec::new(),
+                durations: Vec::new(),
+                makespan: T::zero(),
+                critical_path: Vec::new(),
+            });
+        }
+        let graph = self.constraint_graph();
+        let mut earliest = ShortestPath::init(source, ShortestPathAlgo::BellmanFord, nb + 2);
+        earliest.find_paths(&graph);
+        if earliest.has_negative_cycle() {
+            return None;
+        }
+        let makespan = -*earliest.dist_to(sink);
+
+        // the latest start of a job is the makespan minus the longest
+        // path from the job to the end, found in the reversed graph
+        let mut reversed = EdgeWeightedDigraph::init(nb + 2);
+        for v in 0..nb + 2 {
+            for (w, weight) in graph.vertex_edges(&v) {
+                reversed.add_edge(*w, v, *weight);
+            }
+        }
+        let mut latest = ShortestPath::init(sink, ShortestPathAlgo::BellmanFord, nb + 2);
+        latest.find_paths(&reversed);
+
+        let mut critical_path = earliest.path_to(sink).expect("the end is reachable");
+        critical_path.retain(|v| *v < nb);
+        critical_path.reverse();
+        Some(Schedule {
+            start: (0..nb).map(|job| -*earliest.dist_to(job)).collect(),
+            latest_start: (0..nb).map(|job| makespan + *latest.dist_to(job)).collect(),
+            durations: self.durations.clone(),
+            makespan,
+            critical_path,
+        })
+    }
+}
+
+/// Earliest schedule of jobs found by a `Scheduler`
+#[derive(Debug)]
+pub struct Schedule<T> {
+    start: Vec<T>,
+    // latest start of each job which does not delay the project
+    latest_start: Vec<T>,
+    durations: Vec<T>,
+    makespan: T,
+    // jobs of a longest chain of tight constraints, in order
+    critical_path: Vec<usize>,
+}
+impl<T: Weight> Schedule<T> {
+    /// Gives the earliest start time of a job
+    pub fn start(&self, job: usize) -> T {
+        self.start[job]
+    }
+    /// Gives the earliest start times of the jobs
+    pub fn starts(&self) -> &[T] {
+        &self.start
+    }
+    /// Gives the earliest finish time of a job
+    pub fn finish(&self, job: usize) -> T {
+        self.start[job] + self.durations[job]
+    }
+    /// Gives the latest start time of a job which does not delay the project
+    pub fn latest_start(&self, job: usize) -> T {
+        self.latest_start[job]
+    }
+    /// Gives how much a job can be delayed without delaying the project
+    pub fn slack(&self, job: usize) -> T {
+        self.latest_start[job] - self.start[job]
+    }
+    /// Gives the duration of the project
+    pub fn makespan(&self) -> T {
+        self.makespan
+    }
+    /// Gives a chain of jobs determining the makespan, in order
+    pub fn critical_path(&self) -> &[usize] {
+        &self.critical_path
+    }
+    /// Whether or not a job has no slack
+    pub fn is_critical(&self, job: usize) -> bool {
+        self.latest_start[job] == self.start[job]
+    }
+}
@@ -0,0 +1,51 @@
+#[cfg(test)]
+mod tests {
+    use super::super::Scheduler;
+
+    #[test]
+    fn test_scheduling() {
+        // jobs of the example of Sedgewick and Wayne's Algorithms, 4.4
+        let durations = vec![41, 51, 50, 36, 38, 45, 21, 32, 32, 29];
+        let mut scheduler = Scheduler::<i64>::init(durations);
+        for (before, afters) in [
+            (0, vec![1, 7, 9]),
+            (1, vec![2]),
+            (6, vec![3, 8]),
+            (7, vec![3, 8]),
+            (8, vec![2]),
+            (9, vec![4, 6]),
+        ] {
+            for after in afters {
+                scheduler.add_precedence(before, after);
+            }
+        }
+        let schedule = scheduler.schedule().unwrap();
+        assert_eq!(schedule.starts(), &[0, 41, 123, 91, 70, 0, 70, 41, 91, 41]);
+        assert_eq!(schedule.makespan(), 173);
+        assert_eq!(schedule.critical_path(), &[0, 9, 6, 8, 2]);
+        assert_eq!(schedule.slack(5), 128);
+        assert_eq!(schedule.slack(1), 31);
+        assert_eq!(schedule.latest_start(3), 137);
+        assert_eq!(schedule.finish(2), 173);
+        assert!((0..10)
+            .filter(|j| schedule.is_critical(*j))
+            .eq([0, 2, 6, 8, 9]));
+
+        // job 3 must start at most 10 after job 5, which is then delayed
+        scheduler.add_deadline(5, 3, 10);
+        let schedule = scheduler.schedule().unwrap();
+        assert_eq!(schedule.start(5), 81);
+        assert_eq!(schedule.makespan(), 173);
+        assert_eq!(schedule.slack(5), 47);
+        // cycle of precedences
+        scheduler.add_precedence(2, 0);
+        assert!(scheduler.schedule().is_none());
+    }
+
+    #[test]
+    fn test_scheduling_empty() {
+        let schedule = Scheduler::<i32>::new().schedule().unwrap();
+        assert_eq!(schedule.makespan(), 0);
+        assert!(schedule.critical_path().is_empty());
+    }
+}
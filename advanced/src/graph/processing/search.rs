@@ -1,11 +1,15 @@
 mod first_search;
+mod k_shortest_path;
 mod shortest_path;
+mod traversal;
 #[cfg(test)]
 mod unit_test;
 use crate::graph::{EdgeWeightedDigraph, FlowEdge, FlowNetwork, VertexInfo, Weight};
 pub use first_search::{bfs, dfs};
+pub use k_shortest_path::{yen, KShortestPaths};
 pub use shortest_path::{bellman_ford, dijkstra, shortest_path_ewdag};
 use std::marker::PhantomData;
+pub use traversal::{depth_first_visit, Bfs, Dfs, Visitor};
 
 pub struct DepthFirstSearch<G> {
     // Indicates whether or not a vertex w in the graph is visited
@@ -126,6 +130,9 @@ pub struct ShortestPath<T> {
     // stores the vertex that is the closest
     // to an edge in the shortest path
     edge_to: Vec<usize>,
+    // whether or not Bellman-Ford found a negative
+    // cycle reachable from the source
+    negative_cycle: bool,
 }
 impl<T: Weight + Clone + std::hash::Hash> ShortestPath<T> {
     pub fn init(from: usize, algorithm: ShortestPathAlgo, nb_vertices: usize) -> Self {
@@ -134,6 +141,7 @@ impl<T: Weight + Clone + std::hash::Hash> ShortestPath<T> {
             algo: algorithm,
             dist_to: vec![Weight::max(); nb_vertices],
             edge_to: vec![usize::MAX; nb_vertices],
+            negative_cycle: false,
         }
     }
 }
@@ -145,6 +153,11 @@ impl<T> ShortestPath<T> {
     pub fn edge_to(&self, v: usize) -> usize {
         self.edge_to[v]
     }
+    /// Whether or not a negative cycle is reachable from the source,
+    /// only detected by the Bellman-Ford algorithm
+    pub fn has_negative_cycle(&self) -> bool {
+        self.negative_cycle
+    }
 }
 impl<T: Eq + Weight> ShortestPath<T> {
     pub fn path_to(&self, v: usize) -> Option<Vec<usize>> {
@@ -172,7 +185,8 @@ impl<T: Ord + Weight + std::ops::Add<Output = T> + std::hash::Hash> ShortestPath
                 shortest_path_ewdag(graph, self.source, &mut self.edge_to, &mut self.dist_to);
             }
             ShortestPathAlgo::BellmanFord => {
-                bellman_ford(graph, self.source, &mut self.edge_to, &mut self.dist_to);
+                self.negative_cycle =
+                    !bellman_ford(graph, self.source, &mut self.edge_to, &mut self.dist_to);
             }
         }
     }
@@ -16,44 +16,36 @@ pub fn dfs<G>(
 {
     // finds all reachable vertices from origin and adds them to the connected component w
     // run time complexity O(sum of degrees of all reachable vertices from origin)
+    // the vertices being explored are kept on an explicit stack,
+    // with the position of their next adjacent vertex to look at
     assert!(VertexInfo::nb_vertices(graph) >= std::cmp::max(origin, component));
     // mark vertex w as visited
     marked[origin] = true;
+    let mut stack = vec![(origin, graph.vertex_edges(&origin), 0)];
 
-    // define how to mutate the edge_to list
-    let source = if is_component { component } else { origin };
-    // recursively visit all unmarked adjacent vertices to w
-    let adjacent_vertices = graph.vertex_edges(&origin);
-    if mut_edge_to {
-        for u in adjacent_vertices {
-            if !marked[*u] {
-                dfs(
-                    graph,
-                    marked,
-                    edge_to,
-                    *u,
-                    component,
-                    mut_edge_to,
-                    is_component,
-                );
-                edge_to[*u] = source;
+    while let Some((x, adjacent_vertices, position)) = stack.last_mut() {
+        let x = *x;
+        match adjacent_vertices.get(*position) {
+            Some(u) => {
+                let u = **u;
+                *position += 1;
+                // visit the unmarked adjacent vertices to x
+                if !marked[u] {
+                    marked[u] = true;
+                    if mut_edge_to {
+                        // define how to mutate the edge_to list
+                        edge_to[u] = if is_component { component } else { x };
+                    }
+                    stack.push((u, graph.vertex_edges(&u), 0));
+                }
             }
-        }
-    } else {
-        for u in adjacent_vertices {
-            if !marked[*u] {
-                dfs(
-                    graph,
-                    marked,
-                    edge_to,
-                    *u,
-                    component,
-                    mut_edge_to,
-                    is_component,
-                );
+            None => {
+                stack.pop();
+                if !mut_edge_to {
+                    edge_to.push(x);
+                }
             }
         }
-        edge_to.push(origin);
     }
 }
 
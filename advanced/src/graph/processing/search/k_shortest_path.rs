@@ -0,0 +1,194 @@
+use crate::graph::processing::{ShortestPath, ShortestPathAlgo};
+use crate::graph::{EdgeWeightedDigraph, Weight};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashSet};
+
+#[derive(Eq, PartialEq)]
+struct CandidatePath<T> {
+    cost: T,
+    path: Vec<usize>,
+}
+
+impl<T: Ord> Ord for CandidatePath<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // flip the ordering to get a min oriented binary heap,
+        // ties are broken with the paths to keep the order deterministic
+        other
+            .cost
+            .cmp(&self.cost)
+            .then_with(|| other.path.cmp(&self.path))
+    }
+}
+impl<T: Ord> PartialOrd for CandidatePath<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Iterator over the loopless paths between two vertices of an edge weighted
+/// directed graph with non negative weights, ordered by total weight, using
+/// Yen's algorithm. Each item is the total weight of the path and the path
+/// itself, starting from the source and ending at the target.
+/// # Example
+/// ```
+/// use algods::graph::EdgeWeightedDigraph;
+/// use algods::graph::processing::KShortestPaths;
+/// let mut graph = EdgeWeightedDigraph::init(4);
+/// graph.add_edge(0, 1, 1);
+/// graph.add_edge(1, 3, 1);
+/// graph.add_edge(0, 2, 2);
+/// graph.add_edge(2, 3, 2);
+/// let mut paths = KShortestPaths::init(&graph, 0, 3);
+/// assert_eq!(paths.next(), Some((2, vec![0, 1, 3])));
+/// assert_eq!(paths.next(), Some((4, vec![0, 2, 3])));
+/// assert_eq!(paths.next(), None);
+/// ```
+pub struct KShortestPaths<'a, T>
+where
+    T: Weight,
+{
+    graph: &'a EdgeWeightedDigraph<T>,
+    source: usize,
+    target: usize,
+    // paths already returned by the iterator
+    found: Vec<Vec<usize>>,
+    // potential next shortest paths
+    candidates: BinaryHeap<CandidatePath<T>>,
+    // paths that are already either found or candidates
+    seen: HashSet<Vec<usize>>,
+    // whether or not all the paths are found
+    exhausted: bool,
+}
+
+impl<'a, T: Weight> KShortestPaths<'a, T> {
+    pub fn init(graph: &'a EdgeWeightedDigraph<T>, source: usize, target: usize) -> Self {
+        assert!(graph.nb_vertices() > std::cmp::max(source, target));
+        Self {
+            graph,
+            source,
+            target,
+            found: Vec::new(),
+            candidates: BinaryHeap::new(),
+            seen: HashSet::new(),
+            exhausted: false,
+        }
+    }
+
+    fn edge_weight(&self, from: usize, to: usize) -> T {
+        // the lightest of the (possibly parallel) edges from -> to
+        self.graph
+            .vertex_edges(&from)
+            .into_iter()
+            .filter(|(w, _)| **w == to)
+            .map(|(_, weight)| *weight)
+            .min()
+            .expect("the edge should be in the graph")
+    }
+
+    fn spur_graph(
+        &self,
+        removed_vertices: &[bool],
+        removed_edges: &HashSet<(usize, usize)>,
+    ) -> EdgeWeightedDigraph<T> {
+        // copy of the graph without the removed vertices and edges
+        let nb = self.graph.nb_vertices();
+        let mut graph = EdgeWeightedDigraph::init(nb);
+        for v in (0..nb).filter(|v| !removed_vertices[*v]) {
+            for (w, weight) in self.graph.vertex_edges(&v) {
+                if !removed_vertices[*w] && !removed_edges.contains(&(v, *w)) {
+                    graph.add_edge(v, *w, *weight);
+                }
+            }
+        }
+        graph
+    }
+
+    fn shortest_path(
+        &self,
+        graph: &EdgeWeightedDigraph<T>,
+        from: usize,
+    ) -> Option<(T, Vec<usize>)> {
+        let mut sp = ShortestPath::init(from, ShortestPathAlgo::Dijkstra, graph.nb_vertices());
+        sp.find_paths(graph);
+        let mut path = sp.path_to(self.target)?;
+        path.reverse();
+        Some((*sp.dist_to(self.target), path))
+    }
+
+    fn add_candidates(&mut self) {
+        // computes the deviations of the last found path, at each of its vertices
+        let last = self
+            .found
+            .last()
+            .expect("at least one path is found")
+            .clone();
+        let mut root_cost = T::zero();
+        for i in 0..last.len() - 1 {
+            let spur = last[i];
+            let root = &last[..=i];
+            let removed_edges = self
+                .found
+                .iter()
+                .filter(|path| path.len() > i + 1 && &path[..=i] == root)
+                .map(|path| (path[i], path[i + 1]))
+                .collect::<HashSet<(usize, usize)>>();
+            let mut removed_vertices = vec![false; self.graph.nb_vertices()];
+            for v in &last[..i] {
+                removed_vertices[*v] = true;
+            }
+            let graph = self.spur_graph(&removed_vertices, &removed_edges);
+            if let Some((spur_cost, spur_path)) = self.shortest_path(&graph, spur) {
+                let mut path = last[..i].to_vec();
+                path.extend(spur_path);
+                if self.seen.insert(path.clone()) {
+                    self.candidates.push(CandidatePath {
+                        cost: root_cost + spur_cost,
+                        path,
+                    });
+                }
+            }
+            root_cost = root_cost + self.edge_weight(spur, last[i + 1]);
+        }
+    }
+}
+
+impl<'a, T: Weight> Iterator for KShortestPaths<'a, T> {
+    type Item = (T, Vec<usize>);
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.exhausted {
+            return None;
+        }
+        let next = if self.found.is_empty() {
+            self.shortest_path(self.graph, self.source)
+        } else {
+            self.add_candidates();
+            self.candidates
+                .pop()
+                .map(|candidate| (candidate.cost, candidate.path))
+        };
+        match next {
+            Some((cost, path)) => {
+                self.seen.insert(path.clone());
+                self.found.push(path.clone());
+                Some((cost, path))
+            }
+            None => {
+                self.exhausted = true;
+                None
+            }
+        }
+    }
+}
+
+/// Function that computes at most `k` shortest loopless paths from a source
+/// to a target, in increasing order of total weight, using Yen's algorithm
+pub fn yen<T: Weight>(
+    graph: &EdgeWeightedDigraph<T>,
+    source: usize,
+    target: usize,
+    k: usize,
+) -> Vec<(T, Vec<usize>)> {
+    KShortestPaths::init(graph, source, target)
+        .take(k)
+        .collect()
+}
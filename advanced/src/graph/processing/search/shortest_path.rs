@@ -84,7 +84,9 @@ fn relax<T: Weight + std::hash::Hash>(
 
 /// Function that computes the shortest paths from a source
 /// for edge weighted directed acyclic graphs with possibly
-/// negative and/or positive weights
+/// negative and/or positive weights. The vertices which are not
+/// reachable from the source keep the distance `Weight::max()`,
+/// their edges being skipped, so that no distance overflows.
 pub fn shortest_path_ewdag<T: Weight + std::hash::Hash>(
     graph: &EdgeWeightedDigraph<T>,
     source: usize,
@@ -107,7 +109,8 @@ pub fn shortest_path_ewdag<T: Weight + std::hash::Hash>(
         if *vertex == source {
             flag_source = true;
         }
-        if flag_source {
+        // unreachable vertices are skipped
+        if flag_source && dist_to[*vertex] != Weight::max() {
             let neighbors = graph.vertex_edges(vertex);
             for (neighbor, dist) in neighbors {
                 if dist_to[*neighbor] > dist_to[*vertex] + *dist {
@@ -119,22 +122,36 @@ pub fn shortest_path_ewdag<T: Weight + std::hash::Hash>(
 }
 
 /// Function that computes the shortest paths from a source
-/// for edge weighted directed graph with negative weights  
-/// and without any negative cycle
+/// for edge weighted directed graph with negative weights.
+/// Returns false when a negative cycle is reachable from the source,
+/// the distances being meaningless then.
 pub fn bellman_ford<T: Weight + std::hash::Hash>(
     graph: &EdgeWeightedDigraph<T>,
     source: usize,
     edge_to: &mut [usize],
     dist_to: &mut [T],
-) {
+) -> bool {
+    // run time complexity O(VE)
     dist_to[source] = Weight::zero();
-    let nb = graph.nb_edges();
-    for vertex in 0..nb {
-        let adj_v = graph.vertex_edges(&vertex);
-        for (u, w) in adj_v {
-            if dist_to[*u] > dist_to[vertex] + *w {
-                relax(dist_to, edge_to, vertex, *u, *w);
+    let nb = graph.nb_vertices();
+    // after V - 1 passes, the distances only change along negative cycles
+    for _ in 0..nb {
+        let mut relaxed = false;
+        for vertex in 0..nb {
+            if dist_to[vertex] == Weight::max() {
+                continue;
             }
+            let adj_v = graph.vertex_edges(&vertex);
+            for (u, w) in adj_v {
+                if dist_to[*u] > dist_to[vertex] + *w {
+                    relax(dist_to, edge_to, vertex, *u, *w);
+                    relaxed = true;
+                }
+            }
+        }
+        if !relaxed {
+            return true;
         }
     }
+    false
 }
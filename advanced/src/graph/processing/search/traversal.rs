@@ -0,0 +1,241 @@
+use crate::graph::VertexInfo;
+use std::collections::VecDeque;
+
+/// Lazy depth-first traversal of the vertices reachable from a start vertex,
+/// yielded in discovery (pre)order. The search uses an explicit stack,
+/// so it can be stopped at any time and never overflows the call stack.
+/// # Example
+/// ```
+/// use algods::graph::DirectedGraph;
+/// use algods::graph::processing::Dfs;
+/// let mut graph = DirectedGraph::init(5);
+/// graph.add_edge(0, 1);
+/// graph.add_edge(1, 2);
+/// graph.add_edge(0, 3);
+/// let order = Dfs::new(&graph, 0).collect::<Vec<usize>>();
+/// assert_eq!(order.len(), 4);
+/// assert_eq!(order[0], 0);
+/// let mut dfs = Dfs::new(&graph, 0);
+/// assert!(dfs.find(|v| *v == 2).is_some());
+/// assert_eq!(dfs.parent(2), Some(1));
+/// assert!(!dfs.is_marked(4));
+/// ```
+pub struct Dfs<'a, G> {
+    graph: &'a G,
+    marked: Vec<bool>,
+    // vertex from which each discovered vertex is reached
+    parent: Vec<Option<usize>>,
+    // vertices being explored, with their adjacent vertices
+    // and the position of the next one to look at
+    stack: Vec<(usize, Vec<usize>, usize)>,
+    // start vertex, yielded by the first call to next
+    pending: Option<usize>,
+}
+impl<'a, G: VertexInfo> Dfs<'a, G> {
+    pub fn new(graph: &'a G, start: usize) -> Self {
+        let nb = graph.nb_vertices();
+        assert!(start < nb);
+        let mut marked = vec![false; nb];
+        marked[start] = true;
+        Self {
+            graph,
+            marked,
+            parent: vec![None; nb],
+            stack: vec![(start, adjacent(graph, start), 0)],
+            pending: Some(start),
+        }
+    }
+    /// Whether or not a vertex has been discovered so far
+    pub fn is_marked(&self, v: usize) -> bool {
+        self.marked[v]
+    }
+    /// Gives the vertex from which a discovered vertex is reached,
+    /// `None` for the start vertex and the undiscovered vertices
+    pub fn parent(&self, v: usize) -> Option<usize> {
+        self.parent[v]
+    }
+}
+impl<G: VertexInfo> Iterator for Dfs<'_, G> {
+    type Item = usize;
+    fn next(&mut self) -> Option<usize> {
+        if let Some(start) = self.pending.take() {
+            return Some(start);
+        }
+        while let Some((v, adjacent_vertices, position)) = self.stack.last_mut() {
+            let v = *v;
+            match adjacent_vertices.get(*position) {
+                Some(w) => {
+                    let w = *w;
+                    *position += 1;
+                    if !self.marked[w] {
+                        self.marked[w] = true;
+                        self.parent[w] = Some(v);
+                        self.stack.push((w, adjacent(self.graph, w), 0));
+                        return Some(w);
+                    }
+                }
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Lazy breadth-first traversal of the vertices reachable from a start
+/// vertex, yielded by increasing number of edges from the start vertex.
+/// # Example
+/// ```
+/// use algods::graph::UndirectedGraph;
+/// use algods::graph::processing::Bfs;
+/// let mut graph = UndirectedGraph::init(4);
+/// graph.add_edge(0, 1);
+/// graph.add_edge(1, 2);
+/// graph.add_edge(0, 3);
+/// let mut bfs = Bfs::new(&graph, 0);
+/// assert_eq!(bfs.next(), Some(0));
+/// assert_eq!(bfs.by_ref().last(), Some(2));
+/// assert_eq!(bfs.depth(2), Some(2));
+/// assert_eq!(bfs.parent(2), Some(1));
+/// ```
+pub struct Bfs<'a, G> {
+    graph: &'a G,
+    // number of edges from the start vertex of each discovered vertex
+    depth: Vec<Option<usize>>,
+    parent: Vec<Option<usize>>,
+    queue: VecDeque<usize>,
+}
+impl<'a, G: VertexInfo> Bfs<'a, G> {
+    pub fn new(graph: &'a G, start: usize) -> Self {
+        let nb = graph.nb_vertices();
+        assert!(start < nb);
+        let mut depth = vec![None; nb];
+        depth[start] = Some(0);
+        Self {
+            graph,
+            depth,
+            parent: vec![None; nb],
+            queue: VecDeque::from([start]),
+        }
+    }
+    /// Whether or not a vertex has been discovered so far
+    pub fn is_marked(&self, v: usize) -> bool {
+        self.depth[v].is_some()
+    }
+    /// Gives the number of edges of a shortest path from the start vertex
+    /// to a discovered vertex
+    pub fn depth(&self, v: usize) -> Option<usize> {
+        self.depth[v]
+    }
+    /// Gives the vertex from which a discovered vertex is reached,
+    /// `None` for the start vertex and the undiscovered vertices
+    pub fn parent(&self, v: usize) -> Option<usize> {
+        self.parent[v]
+    }
+}
+impl<G: VertexInfo> Iterator for Bfs<'_, G> {
+    type Item = usize;
+    fn next(&mut self) -> Option<usize> {
+        // vertices are discovered when their predecessor is yielded
+        let v = self.queue.pop_front()?;
+        let depth = self.depth[v].map(|d| d + 1);
+        for w in self.graph.vertex_edges(&v) {
+            if self.depth[*w].is_none() {
+                self.depth[*w] = depth;
+                self.parent[*w] = Some(v);
+                self.queue.push_back(*w);
+            }
+        }
+        Some(v)
+    }
+}
+
+fn adjacent<G: VertexInfo>(graph: &G, v: usize) -> Vec<usize> {
+    graph.vertex_edges(&v).into_iter().copied().collect()
+}
+
+/// This trait gives the events of a depth-first search, all of them doing
+/// nothing by default. For undirected graphs, each tree edge is also
+/// seen as a back edge from its child to its parent.
+pub trait Visitor {
+    /// Called when a vertex is reached for the first time
+    fn discover_vertex(&mut self, _v: usize) {}
+    /// Called on an edge leading to an undiscovered vertex
+    fn tree_edge(&mut self, _v: usize, _w: usize) {}
+    /// Called on an edge leading to a vertex which is still being explored
+    fn back_edge(&mut self, _v: usize, _w: usize) {}
+    /// Called on an edge leading to a vertex which is already finished
+    fn forward_or_cross_edge(&mut self, _v: usize, _w: usize) {}
+    /// Called when all the edges of a vertex are explored
+    fn finish_vertex(&mut self, _v: usize) {}
+}
+
+/// Function that runs a depth-first search from each root not visited yet,
+/// in the given order, reporting its events to a visitor.
+/// The search uses an explicit stack and does not recurse.
+/// # Example
+/// ```
+/// use algods::graph::DirectedGraph;
+/// use algods::graph::processing::{depth_first_visit, Visitor};
+/// // detects a cycle with the back edges
+/// struct CycleDetector(bool);
+/// impl Visitor for CycleDetector {
+///     fn back_edge(&mut self, _v: usize, _w: usize) {
+///         self.0 = true;
+///     }
+/// }
+/// let mut graph = DirectedGraph::init(3);
+/// graph.add_edge(0, 1);
+/// graph.add_edge(1, 2);
+/// let mut detector = CycleDetector(false);
+/// depth_first_visit(&graph, &[0, 1, 2], &mut detector);
+/// assert!(!detector.0);
+/// graph.add_edge(2, 0);
+/// depth_first_visit(&graph, &[0, 1, 2], &mut detector);
+/// assert!(detector.0);
+/// ```
+pub fn depth_first_visit<G, V>(graph: &G, roots: &[usize], visitor: &mut V)
+where
+    G: VertexInfo,
+    V: Visitor,
+{
+    // run time complexity O(V + E)
+    // a vertex is undiscovered, being explored (on the stack) or finished
+    let nb = graph.nb_vertices();
+    let mut discovered = vec![false; nb];
+    let mut finished = vec![false; nb];
+    let mut stack = Vec::new();
+    for root in roots {
+        if discovered[*root] {
+            continue;
+        }
+        discovered[*root] = true;
+        visitor.discover_vertex(*root);
+        stack.push((*root, adjacent(graph, *root), 0));
+        while let Some((v, adjacent_vertices, position)) = stack.last_mut() {
+            let v = *v;
+            match adjacent_vertices.get(*position) {
+                Some(w) => {
+                    let w = *w;
+                    *position += 1;
+                    if !discovered[w] {
+                        visitor.tree_edge(v, w);
+                        discovered[w] = true;
+                        visitor.discover_vertex(w);
+                        stack.push((w, adjacent(graph, w), 0));
+                    } else if !finished[w] {
+                        visitor.back_edge(v, w);
+                    } else {
+                        visitor.forward_or_cross_edge(v, w);
+                    }
+                }
+                None => {
+                    stack.pop();
+                    finished[v] = true;
+                    visitor.finish_vertex(v);
+                }
+            }
+        }
+    }
+}
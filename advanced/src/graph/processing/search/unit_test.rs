@@ -1,6 +1,10 @@
 #[cfg(test)]
 mod tests {
-    use super::super::{EdgeWeightedDigraph, ShortestPath, ShortestPathAlgo};
+    use super::super::{
+        depth_first_visit, dfs, yen, Bfs, Dfs, EdgeWeightedDigraph, KShortestPaths, ShortestPath,
+        ShortestPathAlgo, Visitor,
+    };
+    use crate::graph::{DirectedGraph, UndirectedGraph};
 
     #[test]
     fn test_dijkstra() {
@@ -157,4 +161,147 @@ mod tests {
         assert_eq!(sp_dag.path_to(6), Some(vec![6, 2, 7]));
         assert_eq!(sp_dag.path_to(7), Some(vec![7]));
     }
+
+    #[test]
+    fn test_shortest_path_ewdag_unreachable() {
+        // 2 and 1 follow the source 3 in the topological order without
+        // being reachable from it, their edges must not be relaxed
+        let mut graph = EdgeWeightedDigraph::init(4);
+        graph.add_edge(3, 0, 5);
+        graph.add_edge(2, 1, 1);
+        graph.add_edge(1, 0, -2);
+        let mut sp_dag = ShortestPath::init(3, ShortestPathAlgo::SpDag, graph.nb_vertices());
+        sp_dag.find_paths(&graph);
+        assert_eq!(sp_dag.path_to(0), Some(vec![0, 3]));
+        assert_eq!(*sp_dag.dist_to(0), 5);
+        assert_eq!(sp_dag.path_to(1), None);
+        assert_eq!(sp_dag.path_to(2), None);
+        assert_eq!(*sp_dag.dist_to(2), i32::MAX);
+    }
+
+    #[test]
+    fn test_k_shortest_paths() {
+        // Example from the Wikipedia page of Yen's algorithm
+        // C=0, D=1, E=2, F=3, G=4, H=5
+        let mut graph = EdgeWeightedDigraph::init(6);
+        graph.add_edge(0, 1, 3);
+        graph.add_edge(0, 2, 2);
+        graph.add_edge(1, 3, 4);
+        graph.add_edge(2, 1, 1);
+        graph.add_edge(2, 3, 2);
+        graph.add_edge(2, 4, 3);
+        graph.add_edge(3, 4, 2);
+        graph.add_edge(3, 5, 1);
+        graph.add_edge(4, 5, 2);
+
+        let paths = yen(&graph, 0, 5, 3);
+        assert_eq!(
+            paths,
+            vec![
+                (5, vec![0, 2, 3, 5]),
+                (7, vec![0, 2, 4, 5]),
+                (8, vec![0, 1, 3, 5]),
+            ]
+        );
+
+        let all_paths = KShortestPaths::init(&graph, 0, 5).collect::<Vec<_>>();
+        assert_eq!(all_paths.len(), 7);
+        assert!(all_paths.windows(2).all(|w| w[0].0 <= w[1].0));
+        for (_, path) in &all_paths {
+            let mut sorted = path.clone();
+            sorted.sort();
+            sorted.dedup();
+            assert_eq!(sorted.len(), path.len());
+        }
+
+        assert_eq!(KShortestPaths::init(&graph, 5, 0).next(), None);
+        assert_eq!(yen(&graph, 3, 3, 2), vec![(0, vec![3])]);
+    }
+
+    #[test]
+    fn test_traversal_iterators() {
+        // a long path does not overflow the call stack
+        let nb = 200_000;
+        let mut graph = DirectedGraph::init(nb);
+        for v in 0..nb - 1 {
+            graph.add_edge(v, v + 1);
+        }
+        assert!(Dfs::new(&graph, 0).eq(0..nb));
+        assert!(Bfs::new(&graph, 0).eq(0..nb));
+        let mut marked = vec![false; nb];
+        let mut edge_to = Vec::new();
+        dfs(&graph, &mut marked, &mut edge_to, 0, 0, false, false);
+        assert!(edge_to.into_iter().eq((0..nb).rev()));
+
+        // stops early without exploring the rest of the graph
+        let mut graph = UndirectedGraph::init(7);
+        for (v, w) in [(0, 1), (0, 2), (1, 3), (1, 4), (2, 5), (2, 6)] {
+            graph.add_edge(v, w);
+        }
+        let mut bfs = Bfs::new(&graph, 0);
+        let first = bfs.by_ref().take(3).collect::<Vec<usize>>();
+        assert_eq!(first[0], 0);
+        assert!((3..7).all(|v| bfs.depth(v) == Some(2)));
+        assert!((3..7).all(|v| bfs.parent(v) == Some((v - 1) / 2)));
+        let mut dfs = Dfs::new(&graph, 3);
+        assert_eq!(dfs.next(), Some(3));
+        assert_eq!(dfs.next(), Some(1));
+        assert!(!dfs.is_marked(2));
+        let mut order = dfs.collect::<Vec<usize>>();
+        order.sort_unstable();
+        assert_eq!(order, vec![0, 2, 4, 5, 6]);
+    }
+
+    #[derive(Default)]
+    struct Recorder {
+        discovered: Vec<usize>,
+        finished: Vec<usize>,
+        tree: Vec<(usize, usize)>,
+        back: Vec<(usize, usize)>,
+        forward_or_cross: Vec<(usize, usize)>,
+    }
+    impl Visitor for Recorder {
+        fn discover_vertex(&mut self, v: usize) {
+            self.discovered.push(v);
+        }
+        fn tree_edge(&mut self, v: usize, w: usize) {
+            self.tree.push((v, w));
+        }
+        fn back_edge(&mut self, v: usize, w: usize) {
+            self.back.push((v, w));
+        }
+        fn forward_or_cross_edge(&mut self, v: usize, w: usize) {
+            self.forward_or_cross.push((v, w));
+        }
+        fn finish_vertex(&mut self, v: usize) {
+            self.finished.push(v);
+        }
+    }
+
+    #[test]
+    fn test_depth_first_visit() {
+        // 0 -> 1 -> 2 -> 0 is a cycle, 3 -> 2 is a cross edge
+        let mut graph = DirectedGraph::init(4);
+        for (v, w) in [(0, 1), (1, 2), (2, 0), (3, 2)] {
+            graph.add_edge(v, w);
+        }
+        let mut recorder = Recorder::default();
+        depth_first_visit(&graph, &[0, 1, 2, 3], &mut recorder);
+        assert_eq!(recorder.discovered, vec![0, 1, 2, 3]);
+        assert_eq!(recorder.finished, vec![2, 1, 0, 3]);
+        assert_eq!(recorder.tree, vec![(0, 1), (1, 2)]);
+        assert_eq!(recorder.back, vec![(2, 0)]);
+        assert_eq!(recorder.forward_or_cross, vec![(3, 2)]);
+
+        // a long path does not overflow the call stack
+        let nb = 200_000;
+        let mut graph = DirectedGraph::init(nb);
+        for v in 1..nb {
+            graph.add_edge(v, v - 1);
+        }
+        let mut recorder = Recorder::default();
+        depth_first_visit(&graph, &[nb - 1], &mut recorder);
+        assert_eq!(recorder.tree.len(), nb - 1);
+        assert!(recorder.finished.into_iter().eq(0..nb));
+    }
 }
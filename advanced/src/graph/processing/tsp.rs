@@ -0,0 +1,433 @@
+#[cfg(test)]
+mod unit_test;
+use crate::graph::{EdgeWeightedDigraph, Weight};
+use crate::search::{UnionFind, UnionFindAlgorithm};
+
+// largest number of cities accepted by the Held-Karp algorithm
+const HELD_KARP_MAX_CITIES: usize = 20;
+
+/// Matrix of the (possibly asymmetric) distances between cities,
+/// `None` standing for a missing connection
+/// # Example
+/// ```
+/// use algods::graph::EdgeWeightedDigraph;
+/// use algods::graph::processing::DistanceMatrix;
+/// let mut graph = EdgeWeightedDigraph::init(2);
+/// graph.add_edge(0, 1, 3);
+/// graph.add_edge(0, 1, 2);
+/// let distances = DistanceMatrix::from_graph(&graph);
+/// assert_eq!(distances.distance(0, 1), Some(2));
+/// assert_eq!(distances.distance(1, 0), None);
+/// ```
+pub struct DistanceMatrix<T> {
+    dist: Vec<Vec<Option<T>>>,
+}
+impl<T: Weight> DistanceMatrix<T> {
+    /// Creates the matrix from the distances between all the pairs of cities
+    pub fn from_matrix(matrix: &[Vec<T>]) -> Self {
+        assert!(matrix.iter().all(|row| row.len() == matrix.len()));
+        Self {
+            dist: matrix
+                .iter()
+                .map(|row| row.iter().map(|d| Some(*d)).collect())
+                .collect(),
+        }
+    }
+    /// Creates the matrix from the shortest edges between the vertices
+    pub fn from_graph(graph: &EdgeWeightedDigraph<T>) -> Self {
+        let nb = graph.nb_vertices();
+        let mut dist = vec![vec![None; nb]; nb];
+        for (u, row) in dist.iter_mut().enumerate() {
+            for (v, w) in graph.vertex_edges(&u) {
+                if row[*v].is_none_or(|d| *w < d) {
+                    row[*v] = Some(*w);
+                }
+            }
+        }
+        Self { dist }
+    }
+    /// Gives the number of cities
+    pub fn nb_cities(&self) -> usize {
+        self.dist.len()
+    }
+    /// Gives the distance from a city to another one, if they are connected
+    pub fn distance(&self, u: usize, v: usize) -> Option<T> {
+        self.dist[u][v]
+    }
+    /// Gives the length of a path going through the cities in order
+    pub fn path_cost(&self, cities: &[usize]) -> Option<T> {
+        cities.windows(2).try_fold(T::zero(), |total, pair| {
+            Some(total + self.dist[pair[0]][pair[1]]?)
+        })
+    }
+    /// Gives the length of a tour going through the cities
+    /// in order and back to the first one
+    pub fn tour_cost(&self, cities: &[usize]) -> Option<T> {
+        match (cities.first(), cities.last()) {
+            (Some(first), Some(last)) => Some(self.path_cost(cities)? + self.dist[*last][*first]?),
+            _ => Some(T::zero()),
+        }
+    }
+    fn tour(&self, order: Vec<usize>) -> Option<Tour<T>> {
+        Some(Tour {
+            cost: self.tour_cost(&order)?,
+            order,
+        })
+    }
+}
+
+/// Tour visiting each city once and going back to the first one
+#[derive(Debug, Clone)]
+pub struct Tour<T> {
+    order: Vec<usize>,
+    cost: T,
+}
+impl<T: Weight> Tour<T> {
+    /// Creates the tour visiting the cities in a given order,
+    /// `None` when two consecutive cities are not connected
+    pub fn from_order(distances: &DistanceMatrix<T>, order: Vec<usize>) -> Option<Self> {
+        let mut sorted = order.clone();
+        sorted.sort_unstable();
+        assert!(
+            sorted.into_iter().eq(0..distances.nb_cities()),
+            "a tour visits each city once"
+        );
+        distances.tour(order)
+    }
+    /// Gives the cities in the order of the visits
+    pub fn order(&self) -> &[usize] {
+        &self.order
+    }
+    /// Gives the total length of the tour
+    pub fn cost(&self) -> T {
+        self.cost
+    }
+}
+
+fn shorter<T: Weight>(a: Option<T>, b: Option<T>) -> bool {
+    // compares lengths, None standing for an infinite length
+    match (a, b) {
+        (Some(a), Some(b)) => a < b,
+        (Some(_), None) => true,
+        _ => false,
+    }
+}
+
+fn sum<T: Weight>(lengths: &[Option<T>]) -> Option<T> {
+    lengths
+        .iter()
+        .try_fold(T::zero(), |total, length| Some(total + (*length)?))
+}
+
+/// Builds a tour from a start city by always going to the
+/// nearest city not visited yet, `None` when it gets stuck
+/// # Example
+/// ```
+/// use algods::graph::processing::{nearest_neighbor_tour, DistanceMatrix};
+/// let distances = DistanceMatrix::from_matrix(&[
+///     vec![0, 1, 4, 2],
+///     vec![1, 0, 2, 5],
+///     vec![4, 2, 0, 3],
+///     vec![2, 5, 3, 0],
+/// ]);
+/// let tour = nearest_neighbor_tour(&distances, 0).unwrap();
+/// assert_eq!(tour.order(), &[0, 1, 2, 3]);
+/// assert_eq!(tour.cost(), 8);
+/// ```
+pub fn nearest_neighbor_tour<T: Weight>(
+    distances: &DistanceMatrix<T>,
+    start: usize,
+) -> Option<Tour<T>> {
+    // run time complexity O(V^2)
+    let nb = distances.nb_cities();
+    let mut visited = vec![false; nb];
+    let mut order = vec![start];
+    visited[start] = true;
+    let mut current = start;
+    while order.len() < nb {
+        let next = (0..nb)
+            .filter(|v| !visited[*v])
+            .filter_map(|v| Some((distances.distance(current, v)?, v)))
+            .min()?
+            .1;
+        visited[next] = true;
+        order.push(next);
+        current = next;
+    }
+    distances.tour(order)
+}
+
+/// Builds a tour by adding the shortest connections one after another,
+/// as long as no city is left or entered twice and no cycle is closed
+/// before all the cities are visited, `None` when it gets stuck
+/// # Example
+/// ```
+/// use algods::graph::processing::{greedy_tour, DistanceMatrix};
+/// let distances = DistanceMatrix::from_matrix(&[
+///     vec![0, 1, 4, 2],
+///     vec![1, 0, 2, 5],
+///     vec![4, 2, 0, 3],
+///     vec![2, 5, 3, 0],
+/// ]);
+/// assert_eq!(greedy_tour(&distances).unwrap().cost(), 8);
+/// ```
+pub fn greedy_tour<T: Weight>(distances: &DistanceMatrix<T>) -> Option<Tour<T>> {
+    // run time complexity O(V^2 log(V))
+    let nb = distances.nb_cities();
+    if nb < 2 {
+        return distances.tour((0..nb).collect());
+    }
+    let mut arcs = (0..nb)
+        .flat_map(|u| (0..nb).map(move |v| (u, v)))
+        .filter(|(u, v)| u != v)
+        .filter_map(|(u, v)| Some((distances.distance(u, v)?, u, v)))
+        .collect::<Vec<(T, usize, usize)>>();
+    arcs.sort_unstable();
+    let mut next = vec![None; nb];
+    let mut has_previous = vec![false; nb];
+    // paths built so far
+    let mut paths = UnionFind::with_capacity(nb, UnionFindAlgorithm::WeightedQuickUnionPathComp);
+    let mut nb_arcs = 0;
+    for (_, u, v) in arcs {
+        if nb_arcs == nb - 1 {
+            break;
+        }
+        if next[u].is_none() && !has_previous[v] && !paths.connected(u, v) {
+            next[u] = Some(v);
+            has_previous[v] = true;
+            paths.union(u, v);
+            nb_arcs += 1;
+        }
+    }
+    if nb_arcs < nb - 1 {
+        return None;
+    }
+    // follows the Hamiltonian path from its first city
+    let first = (0..nb).find(|v| !has_previous[*v])?;
+    let mut order = vec![first];
+    while let Some(v) = next[*order.last()?] {
+        order.push(v);
+    }
+    distances.tour(order)
+}
+
+/// Builds a tour following a minimum spanning tree in depth first preorder,
+/// which is at most twice as long as an optimal tour when the distances
+/// are symmetric and satisfy the triangle inequality (metric instance)
+/// # Example
+/// ```
+/// use algods::graph::processing::{mst_tour, DistanceMatrix};
+/// let distances = DistanceMatrix::from_matrix(&[
+///     vec![0, 1, 4, 2],
+///     vec![1, 0, 2, 5],
+///     vec![4, 2, 0, 3],
+///     vec![2, 5, 3, 0],
+/// ]);
+/// assert!(mst_tour(&distances).unwrap().cost() <= 16);
+/// ```
+pub fn mst_tour<T: Weight>(distances: &DistanceMatrix<T>) -> Option<Tour<T>> {
+    // Prim's algorithm on the complete graph
+    // run time complexity O(V^2)
+    let nb = distances.nb_cities();
+    if nb == 0 {
+        return distances.tour(Vec::new());
+    }
+    let mut in_tree = vec![false; nb];
+    let mut best: Vec<Option<(T, usize)>> = vec![None; nb];
+    let mut children = vec![Vec::new(); nb];
+    best[0] = Some((T::zero(), 0));
+    for _ in 0..nb {
+        let (_, u) = (0..nb)
+            .filter(|v| !in_tree[*v])
+            .filter_map(|v| Some((best[v]?.0, v)))
+            .min()?;
+        in_tree[u] = true;
+        let parent = best[u]?.1;
+        if parent != u {
+            children[parent].push(u);
+        }
+        for v in (0..nb).filter(|v| !in_tree[*v]) {
+            if let Some(d) = distances.distance(u, v) {
+                if best[v].is_none_or(|(b, _)| d < b) {
+                    best[v] = Some((d, u));
+                }
+            }
+        }
+    }
+    let mut order = Vec::with_capacity(nb);
+    let mut stack = vec![0];
+    while let Some(u) = stack.pop() {
+        order.push(u);
+        stack.extend(children[u].iter().rev());
+    }
+    distances.tour(order)
+}
+
+/// Improves a tour by reversing parts of it (2-opt moves)
+/// as long as it gets shorter
+/// # Example
+/// ```
+/// use algods::graph::processing::{two_opt, DistanceMatrix, Tour};
+/// let distances = DistanceMatrix::from_matrix(&[
+///     vec![0, 1, 4, 2],
+///     vec![1, 0, 2, 5],
+///     vec![4, 2, 0, 3],
+///     vec![2, 5, 3, 0],
+/// ]);
+/// let tour = Tour::from_order(&distances, vec![0, 2, 1, 3]).unwrap();
+/// assert_eq!(tour.cost(), 13);
+/// assert_eq!(two_opt(&distances, tour).cost(), 8);
+/// ```
+pub fn two_opt<T: Weight>(distances: &DistanceMatrix<T>, tour: Tour<T>) -> Tour<T> {
+    // run time complexity O(V^3) per improving pass, the reversed part
+    // being measured in both directions for asymmetric distances
+    let nb = tour.order.len();
+    let mut order = tour.order;
+    let d = |u: usize, v: usize| distances.distance(u, v);
+    let mut improved = true;
+    while improved {
+        improved = false;
+        for i in 1..nb {
+            for j in i + 1..nb {
+                // reverses order[i..=j], the first city staying in place, so
+                // that before and after are outside the reversed part and the
+                // reversal gives another tour (after being order[0] for j = nb - 1)
+                let (before, after) = (order[i - 1], order[(j + 1) % nb]);
+                let inside = &order[i..=j];
+                let old = sum(&[
+                    d(before, order[i]),
+                    distances.path_cost(inside),
+                    d(order[j], after),
+                ]);
+                let reversed = inside.iter().rev().copied().collect::<Vec<usize>>();
+                let new = sum(&[
+                    d(before, order[j]),
+                    distances.path_cost(&reversed),
+                    d(order[i], after),
+                ]);
+                if shorter(new, old) {
+                    order[i..=j].reverse();
+                    improved = true;
+                }
+            }
+        }
+    }
+    distances.tour(order).expect("the tour only gets shorter")
+}
+
+/// Improves a tour by moving segments of one to three consecutive cities
+/// elsewhere in the tour (Or-opt moves) as long as it gets shorter
+/// # Example
+/// ```
+/// use algods::graph::processing::{or_opt, DistanceMatrix, Tour};
+/// let distances = DistanceMatrix::from_matrix(&[
+///     vec![0, 1, 4, 2],
+///     vec![1, 0, 2, 5],
+///     vec![4, 2, 0, 3],
+///     vec![2, 5, 3, 0],
+/// ]);
+/// let tour = Tour::from_order(&distances, vec![0, 2, 1, 3]).unwrap();
+/// assert_eq!(or_opt(&distances, tour).cost(), 8);
+/// ```
+pub fn or_opt<T: Weight>(distances: &DistanceMatrix<T>, tour: Tour<T>) -> Tour<T> {
+    // run time complexity O(V^2) per improving pass
+    let nb = tour.order.len();
+    let mut order = tour.order;
+    let d = |u: usize, v: usize| distances.distance(u, v);
+    let mut improved = true;
+    while improved {
+        improved = false;
+        'search: for length in 1..=std::cmp::min(3, nb.saturating_sub(2)) {
+            for i in 0..nb {
+                // segment order[i], ..., order[i + length - 1] (cyclically),
+                // moved between the cities at positions k and k + 1
+                let position = |p: usize| order[p % nb];
+                let (first, last) = (position(i), position(i + length - 1));
+                let (before, after) = (position(i + nb - 1), position(i + length));
+                for k in i + length..i + nb - 1 {
+                    let (u, v) = (position(k), position(k + 1));
+                    let old = sum(&[d(before, first), d(last, after), d(u, v)]);
+                    let new = sum(&[d(before, after), d(u, first), d(last, v)]);
+                    if shorter(new, old) {
+                        let segment = (i..i + length).map(position).collect::<Vec<usize>>();
+                        let mut rest = (i + length..i + nb).map(position).collect::<Vec<usize>>();
+                        let at = rest.iter().position(|c| *c == u).expect("u is kept") + 1;
+                        rest.splice(at..at, segment);
+                        order = rest;
+                        improved = true;
+                        break 'search;
+                    }
+                }
+            }
+        }
+    }
+    distances.tour(order).expect("the tour only gets shorter")
+}
+
+/// Finds a shortest tour with Held and Karp's dynamic programming over the
+/// subsets of cities, for at most 20 cities, `None` when there is no tour
+/// # Example
+/// ```
+/// use algods::graph::processing::{held_karp, DistanceMatrix};
+/// let distances = DistanceMatrix::from_matrix(&[
+///     vec![0, 1, 4, 2],
+///     vec![1, 0, 2, 5],
+///     vec![4, 2, 0, 3],
+///     vec![2, 5, 3, 0],
+/// ]);
+/// let tour = held_karp(&distances).unwrap();
+/// assert_eq!(tour.cost(), 8);
+/// assert_eq!(tour.order()[0], 0);
+/// ```
+pub fn held_karp<T: Weight>(distances: &DistanceMatrix<T>) -> Option<Tour<T>> {
+    // run time complexity O(2^V V^2), memory O(2^V V)
+    let nb = distances.nb_cities();
+    assert!(
+        nb <= HELD_KARP_MAX_CITIES,
+        "Held-Karp is limited to {HELD_KARP_MAX_CITIES} cities"
+    );
+    if nb < 2 {
+        return distances.tour((0..nb).collect());
+    }
+    // best[mask * m + j]: shortest path from the city 0 through the cities
+    // of mask (cities 1, ..., nb - 1 being bits 0, ..., m - 1) ending at j + 1
+    let m = nb - 1;
+    let mut best: Vec<Option<T>> = vec![None; (1 << m) * m];
+    let mut previous = vec![usize::MAX; (1 << m) * m];
+    for j in 0..m {
+        best[(1 << j) * m + j] = distances.distance(0, j + 1);
+    }
+    for mask in 1usize..1 << m {
+        for j in (0..m).filter(|j| mask & (1 << j) != 0) {
+            let Some(length) = best[mask * m + j] else {
+                continue;
+            };
+            for k in (0..m).filter(|k| mask & (1 << k) == 0) {
+                let Some(d) = distances.distance(j + 1, k + 1) else {
+                    continue;
+                };
+                let index = (mask | 1 << k) * m + k;
+                if shorter(Some(length + d), best[index]) {
+                    best[index] = Some(length + d);
+                    previous[index] = j;
+                }
+            }
+        }
+    }
+    let full = (1 << m) - 1;
+    let (_, mut j) = (0..m)
+        .filter_map(|j| Some((best[full * m + j]? + distances.distance(j + 1, 0)?, j)))
+        .min()?;
+    // walks back from the last city
+    let mut order = Vec::with_capacity(nb);
+    let mut mask = full;
+    while mask != 0 {
+        order.push(j + 1);
+        let before = previous[mask * m + j];
+        mask ^= 1 << j;
+        j = before;
+    }
+    order.push(0);
+    order.reverse();
+    distances.tour(order)
+}
@@ -0,0 +1,169 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{
+        greedy_tour, held_karp, mst_tour, nearest_neighbor_tour, or_opt, two_opt, DistanceMatrix,
+        Tour,
+    };
+    use crate::graph::EdgeWeightedDigraph;
+    use rand::rngs::StdRng;
+    use rand::seq::SliceRandom;
+    use rand::{Rng, SeedableRng};
+
+    fn permutations(cities: &mut Vec<usize>, k: usize, all: &mut Vec<Vec<usize>>) {
+        if k == cities.len() {
+            all.push(cities.clone());
+            return;
+        }
+        for i in k..cities.len() {
+            cities.swap(k, i);
+            permutations(cities, k + 1, all);
+            cities.swap(k, i);
+        }
+    }
+
+    fn brute_force(distances: &DistanceMatrix<u32>) -> Option<u32> {
+        // tours starting at the city 0
+        let nb = distances.nb_cities();
+        let mut all = Vec::new();
+        permutations(&mut (1..nb).collect(), 0, &mut all);
+        all.into_iter()
+            .filter_map(|mut order| {
+                order.insert(0, 0);
+                distances.tour_cost(&order)
+            })
+            .min()
+    }
+
+    fn random_points(rng: &mut StdRng, nb: usize) -> DistanceMatrix<u32> {
+        // Manhattan distances between random points (a metric instance)
+        let points = (0..nb)
+            .map(|_| (rng.gen_range(0..50i32), rng.gen_range(0..50i32)))
+            .collect::<Vec<_>>();
+        let matrix = points
+            .iter()
+            .map(|(x, y)| {
+                points
+                    .iter()
+                    .map(|(a, b)| ((x - a).abs() + (y - b).abs()) as u32)
+                    .collect()
+            })
+            .collect::<Vec<Vec<u32>>>();
+        DistanceMatrix::from_matrix(&matrix)
+    }
+
+    fn is_tour(tour: &Tour<u32>, distances: &DistanceMatrix<u32>) -> bool {
+        let mut order = tour.order().to_vec();
+        order.sort_unstable();
+        order.into_iter().eq(0..distances.nb_cities())
+            && distances.tour_cost(tour.order()) == Some(tour.cost())
+    }
+
+    #[test]
+    fn test_tsp() {
+        // four cities whose only optimal tour is 0 - 1 - 3 - 2 - 0,
+        // up to its direction
+        let distances = DistanceMatrix::from_matrix(&[
+            vec![0u32, 10, 15, 20],
+            vec![10, 0, 35, 25],
+            vec![15, 35, 0, 30],
+            vec![20, 25, 30, 0],
+        ]);
+        let exact = held_karp(&distances).unwrap();
+        assert_eq!((exact.order(), exact.cost()), (&[0, 2, 3, 1][..], 80));
+        let tour = nearest_neighbor_tour(&distances, 0).unwrap();
+        assert_eq!((tour.order(), tour.cost()), (&[0, 1, 3, 2][..], 80));
+        assert_eq!(greedy_tour(&distances).unwrap().cost(), 80);
+        // the crossing tour 0 - 2 - 1 - 3 is uncrossed
+        let crossing = Tour::from_order(&distances, vec![0, 2, 1, 3]).unwrap();
+        assert_eq!(crossing.cost(), 95);
+        assert_eq!(two_opt(&distances, crossing.clone()).cost(), 80);
+        assert_eq!(or_opt(&distances, crossing).cost(), 80);
+    }
+
+    #[test]
+    fn test_tsp_metric_random() {
+        let mut rng = StdRng::seed_from_u64(17);
+        for _ in 0..50 {
+            let nb = rng.gen_range(1..8);
+            let distances = random_points(&mut rng, nb);
+            let optimum = brute_force(&distances).unwrap();
+            let exact = held_karp(&distances).unwrap();
+            assert!(is_tour(&exact, &distances));
+            assert_eq!(exact.cost(), optimum);
+            let approximation = mst_tour(&distances).unwrap();
+            assert!(is_tour(&approximation, &distances));
+            assert!(approximation.cost() <= 2 * optimum);
+            let tours = [
+                nearest_neighbor_tour(&distances, rng.gen_range(0..nb)).unwrap(),
+                greedy_tour(&distances).unwrap(),
+                approximation,
+            ];
+            for tour in tours {
+                assert!(is_tour(&tour, &distances));
+                let improved = two_opt(&distances, tour.clone());
+                assert!(is_tour(&improved, &distances));
+                assert!(improved.cost() <= tour.cost() && improved.cost() >= optimum);
+                let improved = or_opt(&distances, tour.clone());
+                assert!(is_tour(&improved, &distances));
+                assert!(improved.cost() <= tour.cost() && improved.cost() >= optimum);
+            }
+        }
+    }
+
+    #[test]
+    fn test_tsp_asymmetric_random() {
+        // sparse asymmetric instances given by graphs
+        let mut rng = StdRng::seed_from_u64(23);
+        for _ in 0..100 {
+            let nb = rng.gen_range(2..8);
+            let mut graph = EdgeWeightedDigraph::init(nb);
+            for u in 0..nb {
+                for v in (0..nb).filter(|v| *v != u) {
+                    if rng.gen_bool(0.7) {
+                        graph.add_edge(u, v, rng.gen_range(1..30u32));
+                    }
+                }
+            }
+            let distances = DistanceMatrix::from_graph(&graph);
+            let optimum = brute_force(&distances);
+            assert_eq!(held_karp(&distances).map(|tour| tour.cost()), optimum);
+            if optimum.is_none() {
+                continue;
+            }
+            let mut order = (0..nb).collect::<Vec<usize>>();
+            order.shuffle(&mut rng);
+            if let Some(tour) = Tour::from_order(&distances, order) {
+                for improved in [
+                    two_opt(&distances, tour.clone()),
+                    or_opt(&distances, tour.clone()),
+                ] {
+                    assert!(is_tour(&improved, &distances));
+                    assert!(improved.cost() <= tour.cost());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_tsp_no_tour() {
+        let mut graph = EdgeWeightedDigraph::init(3);
+        graph.add_edge(0, 1, 1u32);
+        graph.add_edge(1, 2, 1);
+        let distances = DistanceMatrix::from_graph(&graph);
+        assert!(held_karp(&distances).is_none());
+        assert!(nearest_neighbor_tour(&distances, 0).is_none());
+        assert!(greedy_tour(&distances).is_none());
+        assert!(Tour::from_order(&distances, vec![0, 1, 2]).is_none());
+        graph.add_edge(2, 0, 1);
+        let distances = DistanceMatrix::from_graph(&graph);
+        assert_eq!(held_karp(&distances).unwrap().order(), &[0, 1, 2]);
+        assert_eq!(greedy_tour(&distances).unwrap().cost(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_held_karp_too_many_cities() {
+        let distances = DistanceMatrix::from_matrix(&vec![vec![1u32; 21]; 21]);
+        held_karp(&distances);
+    }
+}
@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod unit_test;
+use crate::graph::processing::StrongConnectedComponent;
+use crate::graph::DirectedGraph;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Implementation of a 2-SAT solver, which finds whether or not a conjunction
+/// of clauses with at most two literals over named boolean variables can be
+/// satisfied. A literal is a pair (name, value), true when the variable
+/// has the given value. Each clause a or b gives the implications
+/// not a => b and not b => a, and the formula is satisfiable if and only if
+/// no variable is in the same strong connected component as its negation.
+/// # Example
+/// ```
+/// use algods::graph::processing::TwoSat;
+/// let mut sat = TwoSat::new();
+/// // dark mode requires the new theme, which conflicts with the legacy ui
+/// sat.add_implication(("dark_mode", true), ("new_theme", true));
+/// sat.add_clause(("new_theme", false), ("legacy_ui", false));
+/// sat.add_unit(("dark_mode", true));
+/// let assignment = sat.solve().unwrap();
+/// assert!(assignment["new_theme"]);
+/// assert!(!assignment["legacy_ui"]);
+/// sat.add_unit(("legacy_ui", true));
+/// assert_eq!(sat.solve(), None);
+/// ```
+pub struct TwoSat<N> {
+    // number of each variable, and name of each number
+    index: HashMap<N, usize>,
+    names: Vec<N>,
+    // clauses over the literals 2 * variable (true) and 2 * variable + 1 (false)
+    clauses: Vec<(usize, usize)>,
+}
+impl<N: Eq + Hash + Clone> Default for TwoSat<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<N: Eq + Hash + Clone> TwoSat<N> {
+    pub fn new() -> Self {
+        Self {
+            index: HashMap::new(),
+            names: Vec::new(),
+            clauses: Vec::new(),
+        }
+    }
+    /// Gives the number of variables
+    pub fn nb_variables(&self) -> usize {
+        self.names.len()
+    }
+    /// Gives the number of clauses
+    pub fn nb_clauses(&self) -> usize {
+        self.clauses.len()
+    }
+    /// Adds a variable if it is new, returns its number
+    pub fn add_variable(&mut self, name: N) -> usize {
+        if let Some(v) = self.index.get(&name) {
+            return *v;
+        }
+        self.names.push(name.clone());
+        self.index.insert(name, self.names.len() - 1);
+        self.names.len() - 1
+    }
+    fn literal(&mut self, (name, value): (N, bool)) -> usize {
+        2 * self.add_variable(name) + usize::from(!value)
+    }
+    /// Adds the clause a or b
+    pub fn add_clause(&mut self, a: (N, bool), b: (N, bool)) {
+        let (a, b) = (self.literal(a), self.literal(b));
+        self.clauses.push((a, b));
+    }
+    /// Adds the clause a => b, that is not a or b
+    pub fn add_implication(&mut self, a: (N, bool), b: (N, bool)) {
+        self.add_clause((a.0, !a.1), b);
+    }
+    /// Adds the clause made of a single literal, which must be true
+    pub fn add_unit(&mut self, a: (N, bool)) {
+        self.add_clause(a.clone(), a);
+    }
+    /// Builds the implication graph, where the vertex 2 * v stands for the
+    /// variable numbered v being true, and 2 * v + 1 for it being false
+    pub fn implication_graph(&self) -> DirectedGraph {
+        let mut graph = DirectedGraph::init(2 * self.nb_variables());
+        for (a, b) in &self.clauses {
+            graph.add_edge(a ^ 1, *b);
+            graph.add_edge(b ^ 1, *a);
+        }
+        graph
+    }
+    /// Finds a value for each variable satisfying all the clauses,
+    /// `None` when there is none
+    pub fn solve(&self) -> Option<HashMap<N, bool>> {
+        // run time complexity O(V + C), C being the number of clauses
+        let graph = self.implication_graph();
+        let mut scc = StrongConnectedComponent::init(graph.nb_vertices());
+        scc.find_scc(&graph);
+        let component = |literal: usize| scc.id(literal).expect("all literals are visited");
+        let mut assignment = HashMap::with_capacity(self.nb_variables());
+        for (v, name) in self.names.iter().enumerate() {
+            let (positive, negative) = (component(2 * v), component(2 * v + 1));
+            if positive == negative {
+                return None;
+            }
+            // components are numbered in reverse topological order, the literal
+            // coming last in topological order can be true without forcing
+            // its negation
+            assignment.insert(name.clone(), positive < negative);
+        }
+        Some(assignment)
+    }
+}
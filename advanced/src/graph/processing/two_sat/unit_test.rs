@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod tests {
+    use super::super::TwoSat;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    type Clause = ((usize, bool), (usize, bool));
+
+    fn satisfies(clauses: &[Clause], values: &[bool]) -> bool {
+        clauses
+            .iter()
+            .all(|((a, va), (b, vb))| values[*a] == *va || values[*b] == *vb)
+    }
+
+    #[test]
+    fn test_two_sat_random() {
+        // compares with an exhaustive search on small random formulas
+        let mut rng = StdRng::seed_from_u64(11);
+        for _ in 0..200 {
+            let nb = rng.gen_range(1..7);
+            let clauses = (0..rng.gen_range(1..12))
+                .map(|_| {
+                    (
+                        (rng.gen_range(0..nb), rng.gen()),
+                        (rng.gen_range(0..nb), rng.gen()),
+                    )
+                })
+                .collect::<Vec<Clause>>();
+            let mut sat = TwoSat::new();
+            for v in 0..nb {
+                sat.add_variable(v);
+            }
+            for (a, b) in &clauses {
+                sat.add_clause(*a, *b);
+            }
+            let exists = (0..1usize << nb).any(|mask| {
+                let values = (0..nb).map(|v| mask & (1 << v) != 0).collect::<Vec<_>>();
+                satisfies(&clauses, &values)
+            });
+            match sat.solve() {
+                Some(assignment) => {
+                    let values = (0..nb).map(|v| assignment[&v]).collect::<Vec<_>>();
+                    assert!(satisfies(&clauses, &values));
+                }
+                None => assert!(!exists),
+            }
+        }
+    }
+
+    #[test]
+    fn test_two_sat_named() {
+        let mut sat = TwoSat::new();
+        sat.add_clause(("a".to_string(), true), ("b".to_string(), true));
+        sat.add_clause(("a".to_string(), false), ("b".to_string(), true));
+        sat.add_clause(("b".to_string(), false), ("c".to_string(), false));
+        assert_eq!(sat.nb_variables(), 3);
+        assert_eq!(sat.nb_clauses(), 3);
+        assert_eq!(sat.implication_graph().nb_vertices(), 6);
+        let assignment = sat.solve().unwrap();
+        assert!(assignment["b"]);
+        assert!(!assignment["c"]);
+        // x <=> not x
+        let mut sat = TwoSat::new();
+        sat.add_implication(("x", true), ("x", false));
+        sat.add_implication(("x", false), ("x", true));
+        assert_eq!(sat.solve(), None);
+        assert_eq!(TwoSat::<u8>::new().solve().map(|a| a.len()), Some(0));
+    }
+}
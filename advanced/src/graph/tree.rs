@@ -0,0 +1,199 @@
+mod heavy_light;
+mod lca;
+#[cfg(test)]
+mod unit_test;
+use crate::graph::VertexInfo;
+pub use heavy_light::HeavyLightDecomposition;
+pub use lca::{BinaryLifting, EulerTourLca};
+
+/// Function that checks that an undirected graph is a tree: it is
+/// connected, has no self loop and one edge less than vertices.
+/// The graph without vertices is not a tree.
+/// # Example
+/// ```
+/// use algods::graph::UndirectedGraph;
+/// use algods::graph::tree::is_tree;
+/// let mut graph = UndirectedGraph::init(3);
+/// graph.add_edge(0, 1);
+/// assert!(!is_tree(&graph));
+/// graph.add_edge(1, 2);
+/// assert!(is_tree(&graph));
+/// graph.add_edge(2, 0);
+/// assert!(!is_tree(&graph));
+/// ```
+pub fn is_tree<G: VertexInfo>(graph: &G) -> bool {
+    graph.nb_vertices() > 0 && RootedTree::init(graph, 0).is_some()
+}
+
+/// Implementation of a tree hanging from a root vertex, built from an
+/// undirected graph which is a tree.
+/// # Example
+/// ```
+/// use algods::graph::UndirectedGraph;
+/// use algods::graph::tree::RootedTree;
+/// let mut graph = UndirectedGraph::init(5);
+/// graph.add_edge(0, 1);
+/// graph.add_edge(1, 2);
+/// graph.add_edge(1, 3);
+/// graph.add_edge(3, 4);
+/// let tree = RootedTree::init(&graph, 0).unwrap();
+/// assert_eq!(tree.parent(3), Some(1));
+/// assert_eq!(tree.depth(4), 3);
+/// assert_eq!(tree.subtree_size(1), 4);
+/// assert_eq!(tree.diameter(), vec![0, 1, 3, 4]);
+/// assert_eq!(tree.centers(), vec![1, 3]);
+/// assert_eq!(tree.centroids(), vec![1]);
+/// // the graph has a cycle
+/// graph.add_edge(4, 2);
+/// assert!(RootedTree::init(&graph, 0).is_none());
+/// ```
+pub struct RootedTree {
+    root: usize,
+    parent: Vec<Option<usize>>,
+    children: Vec<Vec<usize>>,
+    // number of edges from the root
+    depth: Vec<usize>,
+    // number of vertices of the subtree of each vertex
+    size: Vec<usize>,
+    // vertices by increasing depth, starting with the root
+    order: Vec<usize>,
+}
+impl RootedTree {
+    /// Roots a tree at a vertex, `None` when the graph is not a tree
+    pub fn init<G: VertexInfo>(graph: &G, root: usize) -> Option<Self> {
+        // run time complexity O(V + E)
+        let nb = graph.nb_vertices();
+        assert!(root < nb);
+        let mut parent = vec![None; nb];
+        let mut children = vec![Vec::new(); nb];
+        let mut depth = vec![0; nb];
+        let mut marked = vec![false; nb];
+        let mut order = Vec::with_capacity(nb);
+        marked[root] = true;
+        order.push(root);
+        let mut nb_edge_ends = 0;
+        let mut next = 0;
+        while let Some(v) = order.get(next).copied() {
+            next += 1;
+            for w in graph.vertex_edges(&v) {
+                nb_edge_ends += 1;
+                if parent[v] == Some(*w) {
+                    continue;
+                }
+                // reaching a vertex twice means there is a cycle
+                if marked[*w] {
+                    return None;
+                }
+                marked[*w] = true;
+                parent[*w] = Some(v);
+                depth[*w] = depth[v] + 1;
+                children[v].push(*w);
+                order.push(*w);
+            }
+        }
+        // all the vertices must be reached, and each edge seen from both ends
+        if order.len() < nb || nb_edge_ends != 2 * (nb - 1) {
+            return None;
+        }
+        let mut size = vec![1; nb];
+        for v in order.iter().rev() {
+            if let Some(p) = parent[*v] {
+                size[p] += size[*v];
+            }
+        }
+        Some(Self {
+            root,
+            parent,
+            children,
+            depth,
+            size,
+            order,
+        })
+    }
+    pub fn root(&self) -> usize {
+        self.root
+    }
+    pub fn nb_vertices(&self) -> usize {
+        self.parent.len()
+    }
+    /// Gives the parent of a vertex, `None` for the root
+    pub fn parent(&self, v: usize) -> Option<usize> {
+        self.parent[v]
+    }
+    pub fn children(&self, v: usize) -> &[usize] {
+        &self.children[v]
+    }
+    /// Gives the number of edges between the root and a vertex
+    pub fn depth(&self, v: usize) -> usize {
+        self.depth[v]
+    }
+    /// Gives the number of vertices of the subtree of a vertex
+    pub fn subtree_size(&self, v: usize) -> usize {
+        self.size[v]
+    }
+    /// Gives the vertices by increasing depth, starting with the root
+    pub fn order(&self) -> &[usize] {
+        &self.order
+    }
+    fn farthest_from(&self, start: usize) -> (usize, Vec<Option<usize>>) {
+        // farthest vertex from a vertex, with the vertex
+        // preceding each vertex on its path from start
+        let nb = self.nb_vertices();
+        let mut previous = vec![None; nb];
+        let mut marked = vec![false; nb];
+        marked[start] = true;
+        let mut queue = std::collections::VecDeque::from([start]);
+        let mut last = start;
+        while let Some(v) = queue.pop_front() {
+            last = v;
+            let neighbors = self.children[v].iter().chain(self.parent[v].iter());
+            for w in neighbors {
+                if !marked[*w] {
+                    marked[*w] = true;
+                    previous[*w] = Some(v);
+                    queue.push_back(*w);
+                }
+            }
+        }
+        (last, previous)
+    }
+    /// Gives a longest path of the tree, its number of edges being the
+    /// diameter, found with two breadth first searches
+    pub fn diameter(&self) -> Vec<usize> {
+        // run time complexity O(V)
+        let (first, _) = self.farthest_from(self.root);
+        let (last, previous) = self.farthest_from(first);
+        let mut path = vec![last];
+        while let Some(v) = previous[*path.last().expect("path is not empty")] {
+            path.push(v);
+        }
+        path
+    }
+    /// Gives the center(s) of the tree, the one or two vertices minimizing
+    /// the largest number of edges to the other vertices, found in the
+    /// middle of a longest path, sorted
+    pub fn centers(&self) -> Vec<usize> {
+        let path = self.diameter();
+        let middle = path.len() / 2;
+        let mut centers = if path.len() % 2 == 1 {
+            vec![path[middle]]
+        } else {
+            vec![path[middle - 1], path[middle]]
+        };
+        centers.sort_unstable();
+        centers
+    }
+    /// Gives the centroid(s) of the tree, the one or two vertices whose
+    /// removal leaves components of at most half the vertices, sorted
+    pub fn centroids(&self) -> Vec<usize> {
+        // run time complexity O(V)
+        let nb = self.nb_vertices();
+        (0..nb)
+            .filter(|v| {
+                let largest_child = self.children[*v].iter().map(|c| self.size[*c]).max();
+                let above = nb - self.size[*v];
+                2 * std::cmp::max(largest_child.unwrap_or(0), above) <= nb
+            })
+            .collect()
+    }
+}
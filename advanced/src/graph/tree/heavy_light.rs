@@ -0,0 +1,139 @@
+use crate::graph::tree::RootedTree;
+
+/// Implementation of the heavy-light decomposition of a rooted tree: each
+/// vertex continues the path of its parent when it has the largest subtree
+/// among its siblings, so that any path of the tree crosses O(log(V)) of
+/// these heavy paths. The vertices are numbered such that each heavy path
+/// and each subtree is a contiguous range of positions, on which the values
+/// of the vertices can be stored (e.g. in a segment tree) to answer
+/// path queries.
+/// # Example
+/// ```
+/// use algods::graph::UndirectedGraph;
+/// use algods::graph::tree::{HeavyLightDecomposition, RootedTree};
+/// let mut graph = UndirectedGraph::init(6);
+/// graph.add_edge(0, 1);
+/// graph.add_edge(0, 2);
+/// graph.add_edge(1, 3);
+/// graph.add_edge(1, 4);
+/// graph.add_edge(4, 5);
+/// let tree = RootedTree::init(&graph, 0).unwrap();
+/// let hld = HeavyLightDecomposition::init(&tree);
+/// // values of the vertices, stored by position
+/// let values = [10, 20, 30, 40, 50, 60];
+/// let mut by_position = vec![0; 6];
+/// for v in 0..6 {
+///     by_position[hld.position(v)] = values[v];
+/// }
+/// // sum of the values on the path 3 - 1 - 0 - 2
+/// let sum = hld
+///     .path_segments(3, 2)
+///     .into_iter()
+///     .map(|(start, end)| by_position[start..=end].iter().sum::<i32>())
+///     .sum::<i32>();
+/// assert_eq!(sum, 100);
+/// assert_eq!(hld.lca(3, 5), 1);
+/// let (start, end) = hld.subtree_segment(1);
+/// assert_eq!(end - start + 1, 4);
+/// ```
+pub struct HeavyLightDecomposition {
+    parent: Vec<Option<usize>>,
+    depth: Vec<usize>,
+    // first (shallowest) vertex of the heavy path of each vertex
+    head: Vec<usize>,
+    // position of each vertex, increasing along heavy paths
+    position: Vec<usize>,
+    // vertex at each position
+    vertex: Vec<usize>,
+    size: Vec<usize>,
+}
+impl HeavyLightDecomposition {
+    pub fn init(tree: &RootedTree) -> Self {
+        // run time complexity O(V)
+        let nb = tree.nb_vertices();
+        let heavy = (0..nb)
+            .map(|v| {
+                tree.children(v)
+                    .iter()
+                    .copied()
+                    .max_by_key(|c| tree.subtree_size(*c))
+            })
+            .collect::<Vec<Option<usize>>>();
+        let mut head = vec![tree.root(); nb];
+        let mut position = vec![0; nb];
+        let mut vertex = Vec::with_capacity(nb);
+        // depth first preorder visiting the heavy child first
+        let mut stack = vec![tree.root()];
+        while let Some(v) = stack.pop() {
+            position[v] = vertex.len();
+            vertex.push(v);
+            for c in tree.children(v) {
+                if heavy[v] != Some(*c) {
+                    head[*c] = *c;
+                    stack.push(*c);
+                }
+            }
+            if let Some(h) = heavy[v] {
+                head[h] = head[v];
+                stack.push(h);
+            }
+        }
+        Self {
+            parent: (0..nb).map(|v| tree.parent(v)).collect(),
+            depth: (0..nb).map(|v| tree.depth(v)).collect(),
+            head,
+            position,
+            vertex,
+            size: (0..nb).map(|v| tree.subtree_size(v)).collect(),
+        }
+    }
+    /// Gives the position of a vertex
+    pub fn position(&self, v: usize) -> usize {
+        self.position[v]
+    }
+    /// Gives the vertex at a position
+    pub fn vertex(&self, position: usize) -> usize {
+        self.vertex[position]
+    }
+    /// Gives the first vertex of the heavy path of a vertex
+    pub fn head(&self, v: usize) -> usize {
+        self.head[v]
+    }
+    /// Gives the inclusive range of positions of the subtree of a vertex
+    pub fn subtree_segment(&self, v: usize) -> (usize, usize) {
+        (self.position[v], self.position[v] + self.size[v] - 1)
+    }
+    /// Gives O(log(V)) disjoint inclusive ranges of positions covering
+    /// exactly the vertices of the path between two vertices
+    pub fn path_segments(&self, u: usize, v: usize) -> Vec<(usize, usize)> {
+        let (mut u, mut v) = (u, v);
+        let mut segments = Vec::new();
+        // climbs from the vertex whose heavy path starts deeper
+        while self.head[u] != self.head[v] {
+            if self.depth[self.head[u]] < self.depth[self.head[v]] {
+                std::mem::swap(&mut u, &mut v);
+            }
+            segments.push((self.position[self.head[u]], self.position[u]));
+            u = self.parent[self.head[u]].expect("the head is not the root");
+        }
+        let (a, b) = (self.position[u], self.position[v]);
+        segments.push((std::cmp::min(a, b), std::cmp::max(a, b)));
+        segments
+    }
+    /// Gives the deepest vertex which is an ancestor of both vertices
+    pub fn lca(&self, u: usize, v: usize) -> usize {
+        // run time complexity O(log(V))
+        let (mut u, mut v) = (u, v);
+        while self.head[u] != self.head[v] {
+            if self.depth[self.head[u]] < self.depth[self.head[v]] {
+                std::mem::swap(&mut u, &mut v);
+            }
+            u = self.parent[self.head[u]].expect("the head is not the root");
+        }
+        if self.depth[u] <= self.depth[v] {
+            u
+        } else {
+            v
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use crate::graph::tree::RootedTree;
+
+/// Implementation of lowest common ancestor queries by binary lifting: each
+/// vertex knows its 2^k-th ancestors, so that any ancestor is reached by
+/// O(log(V)) jumps. The preprocessing takes O(V log(V)) time and memory.
+/// # Example
+/// ```
+/// use algods::graph::UndirectedGraph;
+/// use algods::graph::tree::{BinaryLifting, RootedTree};
+/// let mut graph = UndirectedGraph::init(6);
+/// graph.add_edge(0, 1);
+/// graph.add_edge(0, 2);
+/// graph.add_edge(1, 3);
+/// graph.add_edge(1, 4);
+/// graph.add_edge(4, 5);
+/// let tree = RootedTree::init(&graph, 0).unwrap();
+/// let lifting = BinaryLifting::init(&tree);
+/// assert_eq!(lifting.lca(3, 5), 1);
+/// assert_eq!(lifting.lca(5, 2), 0);
+/// assert_eq!(lifting.ancestor(5, 2), Some(1));
+/// assert_eq!(lifting.ancestor(5, 4), None);
+/// assert_eq!(lifting.distance(3, 5), 3);
+/// ```
+pub struct BinaryLifting {
+    // up[k][v]: the 2^k-th ancestor of v, the root being its own ancestor
+    up: Vec<Vec<usize>>,
+    depth: Vec<usize>,
+}
+impl BinaryLifting {
+    pub fn init(tree: &RootedTree) -> Self {
+        let nb = tree.nb_vertices();
+        let mut up = vec![(0..nb)
+            .map(|v| tree.parent(v).unwrap_or(v))
+            .collect::<Vec<usize>>()];
+        let max_depth = (0..nb).map(|v| tree.depth(v)).max().unwrap_or(0);
+        while 1 << up.len() <= max_depth {
+            let last = up.last().expect("up is not empty");
+            let next = (0..nb).map(|v| last[last[v]]).collect();
+            up.push(next);
+        }
+        Self {
+            up,
+            depth: (0..nb).map(|v| tree.depth(v)).collect(),
+        }
+    }
+    /// Gives the ancestor of a vertex k edges above it, if any
+    pub fn ancestor(&self, v: usize, k: usize) -> Option<usize> {
+        // run time complexity O(log(V))
+        if k > self.depth[v] {
+            return None;
+        }
+        let mut v = v;
+        for (level, up) in self.up.iter().enumerate() {
+            if k & (1 << level) != 0 {
+                v = up[v];
+            }
+        }
+        Some(v)
+    }
+    /// Gives the deepest vertex which is an ancestor of both vertices
+    pub fn lca(&self, u: usize, v: usize) -> usize {
+        // run time complexity O(log(V))
+        let (u, v) = if self.depth[u] < self.depth[v] {
+            (v, u)
+        } else {
+            (u, v)
+        };
+        let mut u = self
+            .ancestor(u, self.depth[u] - self.depth[v])
+            .expect("v is not deeper than u");
+        let mut v = v;
+        if u == v {
+            return u;
+        }
+        for up in self.up.iter().rev() {
+            if up[u] != up[v] {
+                u = up[u];
+                v = up[v];
+            }
+        }
+        self.up[0][u]
+    }
+    /// Gives the number of edges between two vertices
+    pub fn distance(&self, u: usize, v: usize) -> usize {
+        self.depth[u] + self.depth[v] - 2 * self.depth[self.lca(u, v)]
+    }
+}
+
+/// Implementation of lowest common ancestor queries in constant time with
+/// an Euler tour of the tree: the lowest common ancestor of two vertices is
+/// the shallowest vertex visited between them, found with a sparse table
+/// of range minima. The preprocessing takes O(V log(V)) time and memory.
+/// # Example
+/// ```
+/// use algods::graph::UndirectedGraph;
+/// use algods::graph::tree::{EulerTourLca, RootedTree};
+/// let mut graph = UndirectedGraph::init(5);
+/// graph.add_edge(2, 0);
+/// graph.add_edge(2, 1);
+/// graph.add_edge(1, 3);
+/// graph.add_edge(1, 4);
+/// let tree = RootedTree::init(&graph, 2).unwrap();
+/// let lca = EulerTourLca::init(&tree);
+/// assert_eq!(lca.lca(3, 4), 1);
+/// assert_eq!(lca.lca(0, 4), 2);
+/// assert_eq!(lca.lca(1, 1), 1);
+/// ```
+pub struct EulerTourLca {
+    // vertices in the order of the tour, a vertex being
+    // visited again after each of its children
+    tour: Vec<usize>,
+    // position of the first visit of each vertex in the tour
+    first: Vec<usize>,
+    depth: Vec<usize>,
+    // sparse[k][i]: shallowest vertex of tour[i..i + 2^k]
+    sparse: Vec<Vec<usize>>,
+}
+impl EulerTourLca {
+    pub fn init(tree: &RootedTree) -> Self {
+        let nb = tree.nb_vertices();
+        let depth = (0..nb).map(|v| tree.depth(v)).collect::<Vec<usize>>();
+        let mut tour = Vec::with_capacity(2 * nb);
+        let mut first = vec![0; nb];
+        // vertices being visited, with the position of their next child
+        let mut stack = vec![(tree.root(), 0)];
+        first[tree.root()] = 0;
+        tour.push(tree.root());
+        while let Some((v, position)) = stack.last_mut() {
+            let v = *v;
+            match tree.children(v).get(*position) {
+                Some(c) => {
+                    *position += 1;
+                    first[*c] = tour.len();
+                    tour.push(*c);
+                    stack.push((*c, 0));
+                }
+                None => {
+                    stack.pop();
+                    if let Some((parent, _)) = stack.last() {
+                        tour.push(*parent);
+                    }
+                }
+            }
+        }
+        let shallowest = |a: usize, b: usize| if depth[a] <= depth[b] { a } else { b };
+        let mut sparse = vec![tour.clone()];
+        while 1 << sparse.len() <= tour.len() {
+            let half = 1 << (sparse.len() - 1);
+            let last = sparse.last().expect("sparse is not empty");
+            let next = (0..=tour.len() - 2 * half)
+                .map(|i| shallowest(last[i], last[i + half]))
+                .collect();
+            sparse.push(next);
+        }
+        Self {
+            tour,
+            first,
+            depth,
+            sparse,
+        }
+    }
+    /// Gives the deepest vertex which is an ancestor of both vertices
+    pub fn lca(&self, u: usize, v: usize) -> usize {
+        // run time complexity O(1)
+        let (i, j) = if self.first[u] <= self.first[v] {
+            (self.first[u], self.first[v])
+        } else {
+            (self.first[v], self.first[u])
+        };
+        // two overlapping ranges of length 2^level cover tour[i..=j]
+        let level = (j - i + 1).ilog2() as usize;
+        let (a, b) = (
+            self.sparse[level][i],
+            self.sparse[level][j + 1 - (1 << level)],
+        );
+        if self.depth[a] <= self.depth[b] {
+            a
+        } else {
+            b
+        }
+    }
+    /// Gives the vertices in the order of the Euler tour
+    pub fn tour(&self) -> &[usize] {
+        &self.tour
+    }
+}
@@ -0,0 +1,201 @@
+#[cfg(test)]
+mod tests {
+    use super::super::{is_tree, BinaryLifting, EulerTourLca, HeavyLightDecomposition, RootedTree};
+    use crate::graph::UndirectedGraph;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::collections::VecDeque;
+
+    fn random_tree(rng: &mut StdRng, nb: usize) -> UndirectedGraph {
+        let mut graph = UndirectedGraph::init(nb);
+        for v in 1..nb {
+            graph.add_edge(v, rng.gen_range(0..v));
+        }
+        graph
+    }
+
+    fn distances(graph: &UndirectedGraph, start: usize) -> Vec<usize> {
+        let mut dist = vec![usize::MAX; graph.nb_vertices()];
+        dist[start] = 0;
+        let mut queue = VecDeque::from([start]);
+        while let Some(v) = queue.pop_front() {
+            for w in crate::graph::VertexInfo::vertex_edges(graph, &v) {
+                if dist[*w] == usize::MAX {
+                    dist[*w] = dist[v] + 1;
+                    queue.push_back(*w);
+                }
+            }
+        }
+        dist
+    }
+
+    fn naive_lca(tree: &RootedTree, u: usize, v: usize) -> usize {
+        let mut ancestors = vec![u];
+        while let Some(p) = tree.parent(*ancestors.last().unwrap()) {
+            ancestors.push(p);
+        }
+        let mut v = v;
+        while !ancestors.contains(&v) {
+            v = tree.parent(v).unwrap();
+        }
+        v
+    }
+
+    #[test]
+    fn test_tree() {
+        //       0
+        //      / \
+        //     1   2
+        //     |   | \
+        //     3   5  4
+        //         |
+        //         6
+        let mut graph = UndirectedGraph::init(7);
+        for (v, w) in [(0, 1), (0, 2), (1, 3), (2, 4), (2, 5), (5, 6)] {
+            graph.add_edge(v, w);
+        }
+        assert!(is_tree(&graph));
+        let tree = RootedTree::init(&graph, 0).unwrap();
+        let depths = (0..7).map(|v| tree.depth(v)).collect::<Vec<usize>>();
+        assert_eq!(depths, vec![0, 1, 1, 2, 2, 2, 3]);
+        let sizes = (0..7).map(|v| tree.subtree_size(v)).collect::<Vec<usize>>();
+        assert_eq!(sizes, vec![7, 2, 4, 1, 1, 2, 1]);
+        assert_eq!(tree.parent(6), Some(5));
+        assert_eq!(tree.diameter(), vec![3, 1, 0, 2, 5, 6]);
+        assert_eq!(tree.centers(), vec![0, 2]);
+        assert_eq!(tree.centroids(), vec![2]);
+
+        let lifting = BinaryLifting::init(&tree);
+        let euler = EulerTourLca::init(&tree);
+        let hld = HeavyLightDecomposition::init(&tree);
+        for (u, v, lca) in [(3, 6, 0), (4, 6, 2), (5, 6, 5), (1, 3, 1)] {
+            assert_eq!(lifting.lca(u, v), lca);
+            assert_eq!(euler.lca(u, v), lca);
+            assert_eq!(hld.lca(u, v), lca);
+        }
+        assert_eq!(lifting.distance(3, 6), 5);
+        assert_eq!(lifting.ancestor(6, 2), Some(2));
+        assert_eq!(lifting.ancestor(6, 4), None);
+        // the heavy path 0 - 2 - 5 - 6 comes first
+        let positions = (0..7).map(|v| hld.position(v)).collect::<Vec<usize>>();
+        assert_eq!(positions, vec![0, 5, 1, 6, 4, 2, 3]);
+        assert_eq!((hld.head(6), hld.head(4), hld.head(3)), (0, 4, 1));
+        assert_eq!(hld.path_segments(3, 6), vec![(5, 6), (0, 3)]);
+        assert_eq!(hld.subtree_segment(2), (1, 4));
+    }
+
+    #[test]
+    fn test_is_tree() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..100 {
+            let nb = rng.gen_range(1..20);
+            let mut graph = random_tree(&mut rng, nb);
+            assert!(is_tree(&graph));
+            let root = rng.gen_range(0..nb);
+            assert!(RootedTree::init(&graph, root).is_some());
+            let (u, v) = (rng.gen_range(0..nb), rng.gen_range(0..nb));
+            let linked = crate::graph::VertexInfo::vertex_edges(&graph, &u).contains(&&v);
+            graph.add_edge(u, v);
+            // a new edge closes a cycle (or is a self loop)
+            assert_eq!(is_tree(&graph), linked && u != v);
+        }
+        assert!(!is_tree(&UndirectedGraph::init(0)));
+        assert!(!is_tree(&UndirectedGraph::init(2)));
+    }
+
+    #[test]
+    fn test_tree_metrics_random() {
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..100 {
+            let nb = rng.gen_range(1..40);
+            let graph = random_tree(&mut rng, nb);
+            let tree = RootedTree::init(&graph, rng.gen_range(0..nb)).unwrap();
+            let all = (0..nb).map(|v| distances(&graph, v)).collect::<Vec<_>>();
+            let eccentricity = all
+                .iter()
+                .map(|d| *d.iter().max().unwrap())
+                .collect::<Vec<_>>();
+            let diameter = tree.diameter();
+            assert_eq!(diameter.len() - 1, *eccentricity.iter().max().unwrap());
+            assert!(diameter
+                .windows(2)
+                .all(|e| tree.parent(e[0]) == Some(e[1]) || tree.parent(e[1]) == Some(e[0])));
+            let radius = *eccentricity.iter().min().unwrap();
+            let centers = (0..nb)
+                .filter(|v| eccentricity[*v] == radius)
+                .collect::<Vec<_>>();
+            assert_eq!(tree.centers(), centers);
+            // removing a centroid leaves components of at most half the vertices
+            let centroids = tree.centroids();
+            assert!(!centroids.is_empty() && centroids.len() <= 2);
+            for (v, depth) in all[tree.root()].iter().enumerate() {
+                let size = (0..nb).filter(|w| naive_lca(&tree, *w, v) == v).count();
+                assert_eq!(tree.subtree_size(v), size);
+                assert_eq!(tree.depth(v), *depth);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lca_random() {
+        let mut rng = StdRng::seed_from_u64(3);
+        for _ in 0..50 {
+            let nb = rng.gen_range(1..60);
+            let graph = random_tree(&mut rng, nb);
+            let tree = RootedTree::init(&graph, rng.gen_range(0..nb)).unwrap();
+            let lifting = BinaryLifting::init(&tree);
+            let euler = EulerTourLca::init(&tree);
+            let hld = HeavyLightDecomposition::init(&tree);
+            assert_eq!(euler.tour().len(), 2 * nb - 1);
+            for _ in 0..50 {
+                let (u, v) = (rng.gen_range(0..nb), rng.gen_range(0..nb));
+                let lca = naive_lca(&tree, u, v);
+                assert_eq!(lifting.lca(u, v), lca);
+                assert_eq!(euler.lca(u, v), lca);
+                assert_eq!(hld.lca(u, v), lca);
+                assert_eq!(lifting.distance(u, v), distances(&graph, u)[v]);
+                let k = rng.gen_range(0..nb);
+                let mut ancestor = Some(u);
+                for _ in 0..k {
+                    ancestor = ancestor.and_then(|a| tree.parent(a));
+                }
+                assert_eq!(lifting.ancestor(u, k), ancestor);
+            }
+        }
+    }
+
+    #[test]
+    fn test_heavy_light_random() {
+        let mut rng = StdRng::seed_from_u64(5);
+        for _ in 0..50 {
+            let nb = rng.gen_range(1..60);
+            let graph = random_tree(&mut rng, nb);
+            let tree = RootedTree::init(&graph, rng.gen_range(0..nb)).unwrap();
+            let hld = HeavyLightDecomposition::init(&tree);
+            for v in 0..nb {
+                assert_eq!(hld.vertex(hld.position(v)), v);
+                let (start, end) = hld.subtree_segment(v);
+                let mut subtree = (start..=end).map(|p| hld.vertex(p)).collect::<Vec<_>>();
+                subtree.sort_unstable();
+                let expected = (0..nb)
+                    .filter(|w| naive_lca(&tree, *w, v) == v)
+                    .collect::<Vec<_>>();
+                assert_eq!(subtree, expected);
+            }
+            for _ in 0..50 {
+                let (u, v) = (rng.gen_range(0..nb), rng.gen_range(0..nb));
+                let mut on_path = hld
+                    .path_segments(u, v)
+                    .into_iter()
+                    .flat_map(|(start, end)| (start..=end).map(|p| hld.vertex(p)))
+                    .collect::<Vec<_>>();
+                on_path.sort_unstable();
+                let (du, dv) = (distances(&graph, u), distances(&graph, v));
+                let expected = (0..nb)
+                    .filter(|w| du[*w] + dv[*w] == du[v])
+                    .collect::<Vec<_>>();
+                assert_eq!(on_path, expected);
+            }
+        }
+    }
+}
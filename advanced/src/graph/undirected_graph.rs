@@ -1,15 +1,36 @@
 #[cfg(test)]
 mod unit_test;
-use crate::graph::VertexInfo;
-use std::collections::HashSet;
+use crate::graph::{VertexInfo, Weight};
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
 
-pub struct UndirectedGraph {
+/// Implementation of an adjacency-list based unweighted undirected graph,
+/// whose vertices and edges can carry data (labels, timestamps, ...),
+/// `()` by default
+/// # Example
+/// ```
+/// use algods::graph::UndirectedGraph;
+/// let mut graph = UndirectedGraph::<char, &str>::with_vertices(vec!['a', 'b', 'c']);
+/// graph.add_edge_with(0, 1, "road");
+/// graph.add_edge_with(2, 1, "rail");
+/// assert_eq!(graph.edge_data(&1, &2), Some(&"rail"));
+/// graph.update_edge_data(&1, &0, |kind| *kind = "highway");
+/// assert_eq!(graph.edge_data(&0, &1), Some(&"highway"));
+/// *graph.vertex_data_mut(&0) = 'z';
+/// assert_eq!(graph.vertex_data(&0), &'z');
+/// // the rail is three times as long as the road
+/// let weighted = graph.to_weighted(|kind| if *kind == "rail" { 3 } else { 1 });
+/// assert_eq!(weighted.edges(), vec![(0, 1, 1), (1, 2, 3)]);
+/// ```
+pub struct UndirectedGraph<V = (), E = ()> {
     // implements an adjacency-list graph
     // where vertices have indices 0, ..., nb_objects
-    // and each vertex is associated to its adjacent vertices
-    data: Vec<HashSet<usize>>,
+    // and each vertex is associated to its adjacent vertices,
+    // along with the data of the edges (kept at both ends)
+    data: Vec<HashMap<usize, E>>,
     nb_edges: usize,
     nb_vertices: usize,
+    vertex_data: Vec<V>,
 }
 impl Default for UndirectedGraph {
     fn default() -> Self {
@@ -18,21 +39,73 @@ impl Default for UndirectedGraph {
 }
 impl UndirectedGraph {
     pub fn new() -> Self {
-        Self {
-            data: Vec::new(),
-            nb_edges: 0,
-            nb_vertices: 0,
-        }
+        Self::with_vertices(Vec::new())
     }
 
     pub fn init(nb_objects: usize) -> Self {
-        let mut graph = Self::new();
-        graph.nb_vertices = nb_objects;
-        graph.data = Vec::with_capacity(nb_objects);
-        for _ in 0..nb_objects {
-            graph.data.push(HashSet::<usize>::new());
+        Self::with_vertices(vec![(); nb_objects])
+    }
+}
+impl<V, E: Default> UndirectedGraph<V, E> {
+    pub fn add_edge(&mut self, v: usize, w: usize) {
+        // adds an edge to the graph, with the default edge data
+        // when it is not already in the graph
+        // run time complexity O(1)
+        assert!(self.nb_vertices > std::cmp::max(v, w));
+        if let Entry::Vacant(edge) = self.data[v].entry(w) {
+            edge.insert(E::default());
+            if v != w {
+                // v <--> w is a new undirected edge
+                self.data[w].insert(v, E::default());
+                self.nb_edges += 1;
+            }
+        }
+    }
+}
+impl<V, E: Clone> UndirectedGraph<V, E> {
+    /// Adds a new edge to the graph with its data,
+    /// replacing the data of the edge when it is already in the graph
+    pub fn add_edge_with(&mut self, v: usize, w: usize, data: E) {
+        // run time complexity O(1)
+        assert!(self.nb_vertices > std::cmp::max(v, w));
+        let is_new = self.data[v].insert(w, data.clone()).is_none();
+        if v != w {
+            self.data[w].insert(v, data);
+            if is_new {
+                // v <--> w is a new undirected edge
+                self.nb_edges += 1;
+            }
+        }
+    }
+    /// Modifies the data of the edge between v and w, the change being
+    /// seen from both ends, returns whether the edge is in the graph
+    pub fn update_edge_data<F: FnOnce(&mut E)>(&mut self, v: &usize, w: &usize, update: F) -> bool {
+        let Some(data) = self.data[*v].get_mut(w) else {
+            return false;
+        };
+        update(data);
+        let data = data.clone();
+        if let Some(other) = self.data[*w].get_mut(v) {
+            *other = data;
+        }
+        true
+    }
+}
+impl<V: Default, E> UndirectedGraph<V, E> {
+    pub fn add_vertex(&mut self) {
+        self.add_vertex_with(V::default());
+    }
+}
+impl<V, E> UndirectedGraph<V, E> {
+    /// Creates a new graph without edges, with one vertex per data
+    pub fn with_vertices(vertex_data: Vec<V>) -> Self {
+        let nb_vertices = vertex_data.len();
+        Self {
+            data: (0..nb_vertices).map(|_| HashMap::new()).collect(),
+            nb_edges: 0,
+            nb_vertices,
+            vertex_data,
         }
-        graph
     }
 
     pub fn nb_edges(&self) -> usize {
@@ -43,26 +116,30 @@ impl UndirectedGraph {
         // run time complexity O(1)
         self.nb_vertices
     }
-    fn vertex_edges(&self, v: &usize) -> &HashSet<usize> {
+    fn vertex_edges(&self, v: &usize) -> &HashMap<usize, E> {
         // gets all the vertices linked to a given vertex v,
         // that is the adjacent vertices of v
         // run time complexity O(1)
         &self.data[*v]
     }
-    pub fn add_edge(&mut self, v: usize, w: usize) {
-        // adds an edge to the graph
-        // run time complexity O(1)
-        assert!(self.nb_vertices >= std::cmp::max(v, w));
-        let w_is_in: bool = self.data[v].insert(w);
-        let v_is_in: bool = self.data[w].insert(v);
-        if v_is_in && w_is_in {
-            // v <--> w is a new undirected edge
-            self.nb_edges += 1;
-        }
-    }
-    pub fn add_vertex(&mut self) {
-        self.data.push(HashSet::<usize>::new());
+    /// Adds a new vertex to the graph with its data, returns its index
+    pub fn add_vertex_with(&mut self, data: V) -> usize {
+        self.data.push(HashMap::new());
+        self.vertex_data.push(data);
         self.nb_vertices += 1;
+        self.nb_vertices - 1
+    }
+    /// Gives the data of a vertex
+    pub fn vertex_data(&self, v: &usize) -> &V {
+        &self.vertex_data[*v]
+    }
+    /// Gives a mutable reference to the data of a vertex
+    pub fn vertex_data_mut(&mut self, v: &usize) -> &mut V {
+        &mut self.vertex_data[*v]
+    }
+    /// Gives the data of the edge between v and w, if it is in the graph
+    pub fn edge_data(&self, v: &usize, w: &usize) -> Option<&E> {
+        self.data[*v].get(w)
     }
 
     pub fn degree(&self, v: &usize) -> usize {
@@ -83,16 +160,174 @@ impl UndirectedGraph {
         self.data
             .iter()
             .enumerate()
-            .map(|(v, e)| usize::from(e.contains(&v)))
+            .map(|(v, e)| usize::from(e.contains_key(&v)))
             .sum()
     }
+    /// Creates the weighted graph with the same vertices and edges,
+    /// the weight of each edge being projected from its data, in order
+    /// to run the algorithms on weighted graphs
+    pub fn to_weighted<T: Weight, F: Fn(&E) -> T>(&self, weight: F) -> EdgeWeightedGraph<T> {
+        let mut graph = EdgeWeightedGraph::init(self.nb_vertices);
+        for (v, adj_v) in self.data.iter().enumerate() {
+            // each edge is seen from its smallest end
+            for (w, data) in adj_v.iter().filter(|(w, _)| **w >= v) {
+                graph.add_edge(v, *w, weight(data));
+            }
+        }
+        graph
+    }
 }
-impl VertexInfo for UndirectedGraph {
+impl<V, E> VertexInfo for UndirectedGraph<V, E> {
     fn vertex_edges(&self, v: &usize) -> Vec<&usize> {
         // gets all the vertices linked to a given vertex v,
         // that is the adjacent vertices of v
         // run time complexity O(1)
-        self.data[*v].iter().collect::<Vec<&usize>>()
+        self.data[*v].keys().collect::<Vec<&usize>>()
+    }
+    fn nb_vertices(&self) -> usize {
+        // run time complexity O(1)
+        self.nb_vertices
+    }
+}
+
+#[derive(Eq, Hash, PartialEq, Copy, Clone, Debug)]
+struct WeightedEdge<T>
+where
+    T: Weight,
+{
+    from: usize, // the vertex whose adjacency list contains the edge
+    to: usize,
+    weight: T,
+}
+impl<T: Weight> WeightedEdge<T> {
+    pub fn init(origin: usize, destination: usize, cost: T) -> Self {
+        Self {
+            from: origin,
+            to: destination,
+            weight: cost,
+        }
+    }
+    pub fn to(&self) -> &usize {
+        &self.to
+    }
+    pub fn weight(&self) -> &T {
+        &self.weight
+    }
+}
+
+/// Implementation of an adjacency-list based edge weighted undirected graph
+/// ```
+/// use algods::graph::EdgeWeightedGraph;
+/// let mut graph = EdgeWeightedGraph::init(3);
+/// graph.add_edge(0, 1, 4);
+/// graph.add_edge(1, 2, 2);
+/// assert_eq!(graph.nb_vertices(), 3);
+/// assert_eq!(graph.nb_edges(), 2);
+/// assert_eq!(graph.degree(&1), 2);
+/// assert_eq!(graph.edges(), vec![(0, 1, 4), (1, 2, 2)]);
+/// ```
+pub struct EdgeWeightedGraph<T>
+where
+    T: Weight,
+{
+    // each edge v <--> w is stored in the adjacency lists of both v and w
+    data: Vec<HashSet<WeightedEdge<T>>>,
+    nb_edges: usize,
+    nb_vertices: usize,
+}
+impl<T: Weight> Default for EdgeWeightedGraph<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T: Weight> EdgeWeightedGraph<T> {
+    /// Creates a new empty graph.
+    pub fn new() -> Self {
+        Self {
+            data: Vec::new(),
+            nb_edges: 0,
+            nb_vertices: 0,
+        }
+    }
+    /// Creates a new graph with unconnected `nb_objects` objects
+    pub fn init(nb_objects: usize) -> Self {
+        let mut graph = Self::new();
+        graph.nb_vertices = nb_objects;
+        graph.data = Vec::with_capacity(nb_objects);
+        for _ in 0..nb_objects {
+            graph.data.push(HashSet::new());
+        }
+        graph
+    }
+    /// Gives the number of edges
+    pub fn nb_edges(&self) -> usize {
+        // run time complexity O(1)
+        self.nb_edges
+    }
+    /// Gives the number of vertices
+    pub fn nb_vertices(&self) -> usize {
+        // run time complexity O(1)
+        self.nb_vertices
+    }
+    /// Adds a new edge to the graph
+    pub fn add_edge(&mut self, u: usize, v: usize, w: T) {
+        // adds an edge between u and v to the graph
+        // run time complexity O(1)
+        assert!(self.nb_vertices > std::cmp::max(u, v));
+        let is_new = self.data[u].insert(WeightedEdge::init(u, v, w));
+        self.data[v].insert(WeightedEdge::init(v, u, w));
+        if is_new {
+            // u <--> v is a new undirected edge
+            self.nb_edges += 1;
+        }
+    }
+    /// Adds a new vertex to the graph
+    pub fn add_vertex(&mut self) {
+        self.data.push(HashSet::new());
+        self.nb_vertices += 1;
+    }
+    /// Returns the adjacent vertices of a vertex with the weights of the edges
+    pub fn vertex_edges(&self, v: &usize) -> Vec<(&usize, &T)> {
+        // run time complexity O(degree of v)
+        self.data[*v]
+            .iter()
+            .map(|edge| (edge.to(), edge.weight()))
+            .collect::<Vec<(&usize, &T)>>()
+    }
+    /// Gives the number of edges adjacent to a vertex
+    pub fn degree(&self, v: &usize) -> usize {
+        self.data[*v].len()
+    }
+    /// Returns all the edges (u, v, weight) of the graph with u <= v,
+    /// sorted by end points then weight
+    pub fn edges(&self) -> Vec<(usize, usize, T)> {
+        let mut edges = self
+            .data
+            .iter()
+            .flat_map(|adj| adj.iter())
+            .filter(|edge| edge.from <= edge.to)
+            .map(|edge| (edge.from, edge.to, edge.weight))
+            .collect::<Vec<(usize, usize, T)>>();
+        edges.sort();
+        edges
+    }
+    /// Returns the number of vertices linked to themselves
+    pub fn self_loop_number(&self) -> usize {
+        self.data
+            .iter()
+            .enumerate()
+            .map(|(v, adj)| usize::from(adj.iter().any(|edge| edge.to == v)))
+            .sum()
+    }
+}
+impl<T: Weight> VertexInfo for EdgeWeightedGraph<T> {
+    fn vertex_edges(&self, v: &usize) -> Vec<&usize> {
+        // gets all the vertices linked to a given vertex v,
+        // that is the adjacent vertices of v
+        self.data[*v]
+            .iter()
+            .map(|edge| edge.to())
+            .collect::<Vec<&usize>>()
     }
     fn nb_vertices(&self) -> usize {
         // run time complexity O(1)
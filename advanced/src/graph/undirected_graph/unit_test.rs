@@ -1,7 +1,9 @@
 #[cfg(test)]
 mod tests {
-    use super::super::UndirectedGraph;
-    use crate::graph::processing::{BreadthFirstSearch, ConnectedComponent, DepthFirstSearch};
+    use super::super::{EdgeWeightedGraph, UndirectedGraph};
+    use crate::graph::processing::{
+        BreadthFirstSearch, ConnectedComponent, DepthFirstSearch, DynamicConnectivity,
+    };
 
     #[test]
     fn test_undirected_graph() {
@@ -19,6 +21,27 @@ mod tests {
         assert_eq!(graph.self_loop_number(), 1);
     }
 
+    #[test]
+    fn test_edge_weighted_graph() {
+        let n: usize = 10;
+        let mut graph = EdgeWeightedGraph::<i32>::init(n);
+        assert_eq!(graph.nb_vertices(), n);
+        graph.add_edge(0, 5, 2);
+        graph.add_edge(4, 8, -1);
+        graph.add_edge(7, 4, 3);
+        graph.add_edge(4, 7, 3);
+        assert_eq!(graph.nb_edges(), 3);
+        assert_eq!(graph.degree(&1), 0);
+        assert_eq!(graph.degree(&4), 2);
+        assert_eq!(graph.edges(), vec![(0, 5, 2), (4, 7, 3), (4, 8, -1)]);
+        assert_eq!(graph.self_loop_number(), 0);
+        graph.add_edge(0, 0, 1);
+        assert_eq!(graph.self_loop_number(), 1);
+        assert_eq!(graph.degree(&0), 2);
+        graph.add_vertex();
+        assert_eq!(graph.nb_vertices(), n + 1);
+    }
+
     #[test]
     #[should_panic]
     fn test_undirected_graph_panic1() {
@@ -98,4 +121,93 @@ mod tests {
         assert!(cc.connected(0, 3).unwrap());
         assert!(!cc.connected(5, 9).unwrap());
     }
+
+    #[test]
+    fn test_dynamic_connectivity() {
+        let mut connectivity = DynamicConnectivity::init(13);
+        let edges = [
+            (0, 1),
+            (0, 2),
+            (0, 6),
+            (0, 5),
+            (6, 4),
+            (4, 3),
+            (5, 4),
+            (5, 3),
+            (7, 8),
+            (9, 10),
+            (9, 11),
+            (9, 12),
+            (11, 12),
+        ];
+        for (v, w) in edges {
+            connectivity.add_edge(v, w);
+        }
+        assert_eq!(connectivity.count(), 3);
+        assert!(connectivity.connected(0, 3));
+        assert!(!connectivity.connected(5, 9));
+        assert_eq!(
+            connectivity.history(),
+            &[13, 12, 11, 10, 9, 8, 7, 7, 7, 6, 5, 4, 3, 3]
+        );
+        assert_eq!(connectivity.count_at(7), Some(7));
+        assert_eq!(connectivity.count_at(14), None);
+        assert_eq!(connectivity.graph().nb_edges(), edges.len());
+
+        let v = connectivity.add_vertex();
+        assert_eq!(v, 13);
+        assert_eq!(connectivity.count(), 4);
+        connectivity.add_edge(v, 8);
+        connectivity.add_edge(v, 12);
+        assert_eq!(connectivity.count(), 2);
+        assert!(connectivity.connected(7, 10));
+
+        let mut graph = UndirectedGraph::init(4);
+        graph.add_edge(0, 1);
+        graph.add_edge(2, 3);
+        let mut connectivity = DynamicConnectivity::from_graph(graph);
+        assert_eq!(connectivity.history(), &[2]);
+        assert!(connectivity.connected(3, 2));
+        connectivity.add_edge(1, 2);
+        assert_eq!(connectivity.count(), 1);
+    }
+
+    #[test]
+    fn test_undirected_graph_with_data() {
+        let mut graph = UndirectedGraph::<usize, (u8, char)>::with_vertices(vec![0; 5]);
+        graph.add_edge_with(0, 1, (4, 'a'));
+        graph.add_edge_with(2, 1, (1, 'b'));
+        graph.add_edge_with(0, 2, (2, 'c'));
+        graph.add_edge_with(3, 4, (7, 'd'));
+        // the data does not depend on the order of the ends
+        assert_eq!(graph.edge_data(&1, &2), Some(&(1, 'b')));
+        assert_eq!(graph.edge_data(&2, &1), Some(&(1, 'b')));
+        graph.add_edge_with(1, 0, (3, 'a'));
+        assert_eq!(graph.nb_edges(), 4);
+        assert_eq!(graph.edge_data(&0, &1), Some(&(3, 'a')));
+        assert!(graph.update_edge_data(&4, &3, |data| data.0 = 5));
+        assert!(!graph.update_edge_data(&0, &4, |data| data.0 = 5));
+        assert_eq!(graph.edge_data(&3, &4), Some(&(5, 'd')));
+        assert_eq!(graph.edge_data(&0, &3), None);
+        for v in 0..graph.nb_vertices() {
+            *graph.vertex_data_mut(&v) = graph.degree(&v);
+        }
+        assert_eq!(graph.vertex_data(&0), &2);
+        // the default edge data is only used for new edges
+        graph.add_edge(1, 2);
+        graph.add_edge(1, 3);
+        assert_eq!(graph.edge_data(&1, &2), Some(&(1, 'b')));
+        assert_eq!(graph.edge_data(&3, &1), Some(&(0, '\0')));
+        graph.add_vertex();
+        assert_eq!(graph.vertex_data(&5), &0);
+
+        let mut cc = ConnectedComponent::init(graph.nb_vertices());
+        cc.find_cc(&graph);
+        assert_eq!(cc.count(), 2);
+        let weighted = graph.to_weighted(|(w, _)| *w as i32);
+        assert_eq!(
+            weighted.edges(),
+            vec![(0, 1, 3), (0, 2, 2), (1, 2, 1), (1, 3, 0), (3, 4, 5)]
+        );
+    }
 }
@@ -109,6 +109,26 @@ impl UnionFind {
         self.len() == 0
     }
 
+    /// Adds a new object, connected to no other object, and returns its id.
+    /// # Example
+    /// ```
+    /// use algods::search::{UnionFind, UnionFindAlgorithm};
+    /// let mut uf = UnionFind::with_capacity(2, UnionFindAlgorithm::WeightedQuickUnion);
+    /// assert_eq!(uf.add_object(), 2);
+    /// assert_eq!(uf.len(), 3);
+    /// assert!(!uf.connected(0, 2));
+    /// ```
+    pub fn add_object(&mut self) -> usize {
+        // complexity: O(1) amortized
+        let id = self.nb_objects;
+        self.ids.push(id);
+        if self.algo != UnionFindAlgorithm::QuickFind {
+            self.size.push(1);
+        }
+        self.nb_objects += 1;
+        id
+    }
+
     /// Creates a union find instance from a file with objects ids
     /// separated by a separator like `,` or `;` or `|`, etc.
     /// # Panics
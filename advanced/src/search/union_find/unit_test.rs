@@ -32,4 +32,20 @@ mod tests {
         assert_eq!(uf.connected(1, 2), true);
         assert_eq!(uf.connected(0, 4), false);
     }
+
+    #[test]
+    fn test_add_object() {
+        for algo in [
+            UnionFindAlgorithm::QuickFind,
+            UnionFindAlgorithm::WeightedQuickUnionPathComp,
+        ] {
+            let mut uf = UnionFind::with_capacity(2, algo);
+            uf.union(0, 1);
+            assert_eq!(uf.add_object(), 2);
+            assert_eq!(uf.ids.len(), uf.nb_objects);
+            assert!(!uf.connected(1, 2));
+            uf.union(2, 0);
+            assert!(uf.connected(1, 2));
+        }
+    }
 }